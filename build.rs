@@ -0,0 +1,23 @@
+//! 构建前根据 prisma/schema.prisma 生成 Prisma 客户端 (src/prisma.rs)
+//! 生成的文件不提交到仓库, 修改 schema 后重新构建即可
+
+use std::{env, path::PathBuf, process::Command};
+
+fn main() {
+  println!("cargo:rerun-if-changed=prisma/schema.prisma");
+  println!("cargo:rerun-if-changed=prisma/migrations");
+
+  let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+  // 使用单独的目标目录, 避免与当前构建争用锁
+  // 生成器由 prisma CLI 通过 `cargo prisma` 回调, 同样需要使用该目录
+  let target_dir = root.join("target").join("prisma-cli");
+  let status = Command::new(env::var("CARGO").unwrap())
+    .args(["run", "-p", "prisma-cli", "--", "generate"])
+    .current_dir(&root)
+    .env("CARGO_TARGET_DIR", &target_dir)
+    .status()
+    .expect("无法运行 prisma-cli");
+  if !status.success() {
+    panic!("生成 Prisma 客户端失败: {}", status);
+  }
+}
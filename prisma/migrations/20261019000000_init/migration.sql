@@ -0,0 +1,155 @@
+-- CreateEnum
+CREATE TYPE "UploadableTextures" AS ENUM ('SkinOnly', 'SkinAndCape', 'None');
+
+-- CreateEnum
+CREATE TYPE "SkinType" AS ENUM ('Default', 'Slim');
+
+-- CreateEnum
+CREATE TYPE "Language" AS ENUM ('ZH_CN', 'EN');
+
+-- CreateEnum
+CREATE TYPE "TokenStatus" AS ENUM ('Available', 'NeedRefresh', 'Invalid');
+
+-- CreateTable
+CREATE TABLE "Skin" (
+    "id" BIGSERIAL NOT NULL,
+    "hash" BYTEA NOT NULL,
+    "model" "SkinType" NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "Skin_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "Cape" (
+    "id" BIGSERIAL NOT NULL,
+    "hash" BYTEA NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "Cape_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "Profile" (
+    "id" BIGSERIAL NOT NULL,
+    "uuid" BYTEA NOT NULL,
+    "ownerID" BIGINT NOT NULL,
+    "displayName" TEXT NOT NULL,
+    "skinID" BIGINT,
+    "capeID" BIGINT,
+    "uploadableTextures" "UploadableTextures" NOT NULL DEFAULT 'SkinAndCape',
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "Profile_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "User" (
+    "id" BIGSERIAL NOT NULL,
+    "uuid" BYTEA NOT NULL,
+    "nickname" TEXT NOT NULL,
+    "email" TEXT NOT NULL,
+    "password" TEXT NOT NULL,
+    "language" "Language" NOT NULL DEFAULT 'ZH_CN',
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "User_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "Token" (
+    "id" BIGSERIAL NOT NULL,
+    "accessToken" TEXT NOT NULL,
+    "clientToken" TEXT NOT NULL,
+    "ownerID" BIGINT NOT NULL,
+    "profileID" BIGINT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "status" "TokenStatus" NOT NULL DEFAULT 'Available',
+
+    CONSTRAINT "Token_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "JoinRequest" (
+    "id" BIGSERIAL NOT NULL,
+    "serverID" TEXT NOT NULL,
+    "accessToken" TEXT NOT NULL,
+    "ip" TEXT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "JoinRequest_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "Setting" (
+    "id" BIGSERIAL NOT NULL,
+    "userId" BIGINT NOT NULL,
+    "maxToken" BIGINT NOT NULL DEFAULT 10,
+    "tokenNeedRefreshDuration" BIGINT NOT NULL DEFAULT 1296000,
+    "tokenInvalidDuration" BIGINT NOT NULL DEFAULT 432000,
+
+    CONSTRAINT "Setting_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Skin_id_key" ON "Skin"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Cape_id_key" ON "Cape"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Profile_id_key" ON "Profile"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Profile_uuid_key" ON "Profile"("uuid");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Profile_displayName_key" ON "Profile"("displayName");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "User_id_key" ON "User"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "User_uuid_key" ON "User"("uuid");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "User_email_key" ON "User"("email");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Token_id_key" ON "Token"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Token_accessToken_key" ON "Token"("accessToken");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "JoinRequest_id_key" ON "JoinRequest"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "JoinRequest_serverID_key" ON "JoinRequest"("serverID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Setting_id_key" ON "Setting"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Setting_userId_key" ON "Setting"("userId");
+
+-- AddForeignKey
+ALTER TABLE "Profile" ADD CONSTRAINT "Profile_capeID_fkey" FOREIGN KEY ("capeID") REFERENCES "Cape"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Profile" ADD CONSTRAINT "Profile_ownerID_fkey" FOREIGN KEY ("ownerID") REFERENCES "User"("id") ON DELETE RESTRICT ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Profile" ADD CONSTRAINT "Profile_skinID_fkey" FOREIGN KEY ("skinID") REFERENCES "Skin"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Token" ADD CONSTRAINT "Token_ownerID_fkey" FOREIGN KEY ("ownerID") REFERENCES "User"("id") ON DELETE RESTRICT ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Token" ADD CONSTRAINT "Token_profileID_fkey" FOREIGN KEY ("profileID") REFERENCES "Profile"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "JoinRequest" ADD CONSTRAINT "JoinRequest_accessToken_fkey" FOREIGN KEY ("accessToken") REFERENCES "Token"("accessToken") ON DELETE RESTRICT ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Setting" ADD CONSTRAINT "Setting_userId_fkey" FOREIGN KEY ("userId") REFERENCES "User"("id") ON DELETE RESTRICT ON UPDATE CASCADE;
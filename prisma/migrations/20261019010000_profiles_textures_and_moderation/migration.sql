@@ -0,0 +1,246 @@
+-- CreateEnum
+CREATE TYPE "ExtraTextureType" AS ENUM ('Elytra', 'Ears');
+
+-- CreateEnum
+CREATE TYPE "Role" AS ENUM ('Player', 'Moderator', 'Admin');
+
+-- CreateEnum
+CREATE TYPE "Permission" AS ENUM ('UploadHdSkin', 'UnlimitedProfiles', 'GrantCapes', 'BanUsers', 'AdminApi');
+
+-- DropForeignKey
+ALTER TABLE "Profile" DROP CONSTRAINT "Profile_ownerID_fkey";
+
+-- DropForeignKey
+ALTER TABLE "Token" DROP CONSTRAINT "Token_ownerID_fkey";
+
+-- DropForeignKey
+ALTER TABLE "JoinRequest" DROP CONSTRAINT "JoinRequest_accessToken_fkey";
+
+-- DropForeignKey
+ALTER TABLE "Setting" DROP CONSTRAINT "Setting_userId_fkey";
+
+-- AlterTable
+ALTER TABLE "Cape" ADD COLUMN     "frameRate" INTEGER,
+ADD COLUMN     "name" TEXT,
+ADD COLUMN     "official" BOOLEAN NOT NULL DEFAULT false;
+
+-- AlterTable
+ALTER TABLE "User" ADD COLUMN     "disabled" BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN     "permissions" "Permission"[],
+ADD COLUMN     "profileDeletedAt" TIMESTAMP(3),
+ADD COLUMN     "role" "Role" NOT NULL DEFAULT 'Player';
+
+-- CreateTable
+CREATE TABLE "CapeEntitlement" (
+    "id" BIGSERIAL NOT NULL,
+    "userID" BIGINT NOT NULL,
+    "capeID" BIGINT NOT NULL,
+    "reason" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "CapeEntitlement_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "ProfileKey" (
+    "id" BIGSERIAL NOT NULL,
+    "profileID" BIGINT NOT NULL,
+    "privateKey" TEXT NOT NULL,
+    "publicKey" TEXT NOT NULL,
+    "publicKeySignature" TEXT NOT NULL,
+    "legacyKeySignature" TEXT NOT NULL,
+    "expiresAt" TIMESTAMP(3) NOT NULL,
+    "refreshedAfter" TIMESTAMP(3) NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "ProfileKey_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "NameHistory" (
+    "id" BIGSERIAL NOT NULL,
+    "profileID" BIGINT,
+    "name" TEXT NOT NULL,
+    "changedAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "NameHistory_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "ExtraTexture" (
+    "id" BIGSERIAL NOT NULL,
+    "profileID" BIGINT NOT NULL,
+    "kind" "ExtraTextureType" NOT NULL,
+    "hash" BYTEA NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "ExtraTexture_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "Ban" (
+    "id" BIGSERIAL NOT NULL,
+    "userID" BIGINT,
+    "profileID" BIGINT,
+    "reason" TEXT NOT NULL,
+    "issuerID" BIGINT,
+    "expiresAt" TIMESTAMP(3),
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "Ban_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "UserTexture" (
+    "id" BIGSERIAL NOT NULL,
+    "ownerID" BIGINT NOT NULL,
+    "name" TEXT NOT NULL,
+    "skinID" BIGINT,
+    "capeID" BIGINT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "UserTexture_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "GalleryItem" (
+    "id" BIGSERIAL NOT NULL,
+    "uploaderID" BIGINT NOT NULL,
+    "title" TEXT NOT NULL,
+    "tags" TEXT[],
+    "skinID" BIGINT,
+    "capeID" BIGINT,
+    "likes" BIGINT NOT NULL DEFAULT 0,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "GalleryItem_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "GalleryLike" (
+    "id" BIGSERIAL NOT NULL,
+    "itemID" BIGINT NOT NULL,
+    "userID" BIGINT NOT NULL,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "GalleryLike_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateTable
+CREATE TABLE "BlockedServer" (
+    "id" BIGSERIAL NOT NULL,
+    "hash" TEXT NOT NULL,
+    "pattern" TEXT,
+    "reason" TEXT,
+    "createdAt" TIMESTAMP(3) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "BlockedServer_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "CapeEntitlement_id_key" ON "CapeEntitlement"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "CapeEntitlement_userID_capeID_key" ON "CapeEntitlement"("userID", "capeID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ProfileKey_id_key" ON "ProfileKey"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ProfileKey_profileID_key" ON "ProfileKey"("profileID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "NameHistory_id_key" ON "NameHistory"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ExtraTexture_id_key" ON "ExtraTexture"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "ExtraTexture_profileID_kind_key" ON "ExtraTexture"("profileID", "kind");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Ban_id_key" ON "Ban"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "UserTexture_id_key" ON "UserTexture"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "UserTexture_ownerID_skinID_key" ON "UserTexture"("ownerID", "skinID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "UserTexture_ownerID_capeID_key" ON "UserTexture"("ownerID", "capeID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "GalleryItem_id_key" ON "GalleryItem"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "GalleryLike_id_key" ON "GalleryLike"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "GalleryLike_itemID_userID_key" ON "GalleryLike"("itemID", "userID");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "BlockedServer_id_key" ON "BlockedServer"("id");
+
+-- CreateIndex
+CREATE UNIQUE INDEX "BlockedServer_hash_key" ON "BlockedServer"("hash");
+
+-- AddForeignKey
+ALTER TABLE "CapeEntitlement" ADD CONSTRAINT "CapeEntitlement_userID_fkey" FOREIGN KEY ("userID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "CapeEntitlement" ADD CONSTRAINT "CapeEntitlement_capeID_fkey" FOREIGN KEY ("capeID") REFERENCES "Cape"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Profile" ADD CONSTRAINT "Profile_ownerID_fkey" FOREIGN KEY ("ownerID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "ProfileKey" ADD CONSTRAINT "ProfileKey_profileID_fkey" FOREIGN KEY ("profileID") REFERENCES "Profile"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "NameHistory" ADD CONSTRAINT "NameHistory_profileID_fkey" FOREIGN KEY ("profileID") REFERENCES "Profile"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "ExtraTexture" ADD CONSTRAINT "ExtraTexture_profileID_fkey" FOREIGN KEY ("profileID") REFERENCES "Profile"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Ban" ADD CONSTRAINT "Ban_userID_fkey" FOREIGN KEY ("userID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Ban" ADD CONSTRAINT "Ban_profileID_fkey" FOREIGN KEY ("profileID") REFERENCES "Profile"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Ban" ADD CONSTRAINT "Ban_issuerID_fkey" FOREIGN KEY ("issuerID") REFERENCES "User"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "UserTexture" ADD CONSTRAINT "UserTexture_ownerID_fkey" FOREIGN KEY ("ownerID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "UserTexture" ADD CONSTRAINT "UserTexture_skinID_fkey" FOREIGN KEY ("skinID") REFERENCES "Skin"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "UserTexture" ADD CONSTRAINT "UserTexture_capeID_fkey" FOREIGN KEY ("capeID") REFERENCES "Cape"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "GalleryItem" ADD CONSTRAINT "GalleryItem_uploaderID_fkey" FOREIGN KEY ("uploaderID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "GalleryItem" ADD CONSTRAINT "GalleryItem_skinID_fkey" FOREIGN KEY ("skinID") REFERENCES "Skin"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "GalleryItem" ADD CONSTRAINT "GalleryItem_capeID_fkey" FOREIGN KEY ("capeID") REFERENCES "Cape"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "GalleryLike" ADD CONSTRAINT "GalleryLike_itemID_fkey" FOREIGN KEY ("itemID") REFERENCES "GalleryItem"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "GalleryLike" ADD CONSTRAINT "GalleryLike_userID_fkey" FOREIGN KEY ("userID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Token" ADD CONSTRAINT "Token_ownerID_fkey" FOREIGN KEY ("ownerID") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "JoinRequest" ADD CONSTRAINT "JoinRequest_accessToken_fkey" FOREIGN KEY ("accessToken") REFERENCES "Token"("accessToken") ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Setting" ADD CONSTRAINT "Setting_userId_fkey" FOREIGN KEY ("userId") REFERENCES "User"("id") ON DELETE CASCADE ON UPDATE CASCADE;
@@ -0,0 +1,96 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{BanFilter, BanTarget, ListParams, SortBy},
+  utils::{
+    auth::{Caller, Permitted},
+    permissions::BanUsers,
+  },
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: Permitted<BanUsers>,
+  Query(query): Query<req::BanListQuery>,
+) -> Result<Json<resp::Page<resp::Ban>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let filter = BanFilter { user_id: query.user_id, profile_id: query.profile_id, active: query.active == Some(true) };
+  let params = ListParams { skip, take: page_size, sort: SortBy::Id, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.bans.list(filter, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page { items: items.into_iter().map(resp::Ban::from_query).collect(), page, page_size, total }))
+}
+
+pub async fn get(
+  State(state): State<AppState>,
+  _auth: Permitted<BanUsers>,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::Ban>, error::ErrorResponse> {
+  let ban = state.repos.bans.find_by_id(id).await.map_err(repo_error)?;
+  ban.map(|x| Json(resp::Ban::from_query(x))).ok_or_else(not_found)
+}
+
+/// 封禁后对象的令牌立即失效
+pub async fn create(
+  State(state): State<AppState>,
+  auth: Permitted<BanUsers>,
+  Json(req): Json<req::CreateBanReq>,
+) -> Result<Json<resp::Ban>, error::ErrorResponse> {
+  let target = match (req.user_id, req.profile_id) {
+    (Some(user_id), None) => {
+      if state.repos.users.find_by_id(user_id).await.map_err(repo_error)?.is_none() {
+        return Err(not_found());
+      }
+      BanTarget::User(user_id)
+    },
+    (None, Some(profile_id)) => {
+      if state.repos.profiles.find_by_id(profile_id).await.map_err(repo_error)?.is_none() {
+        return Err(not_found());
+      }
+      BanTarget::Profile(profile_id)
+    },
+    _ => {
+      return Err(error::Error::new_illegal_argument("Exactly one of userId and profileId is required.").to_response());
+    },
+  };
+  if req.reason.trim().is_empty() {
+    return Err(error::Error::new_illegal_argument("Ban reason must not be empty.").to_response());
+  }
+  let expires_at = match req.expires_at {
+    Some(x) => {
+      match chrono::DateTime::from_timestamp_millis(x) {
+        Some(x) if x > chrono::Utc::now() => Some(x.into()),
+        _ => {
+          return Err(error::Error::new_illegal_argument("Invalid expiry time.").to_response());
+        },
+      }
+    },
+    None => None,
+  };
+  let issuer_id = match auth.caller {
+    Caller::User(user) => Some(user.id),
+    Caller::ApiKey => None,
+  };
+  let ban =
+    state.repos.bans.issue(target, req.reason.trim().to_owned(), issuer_id, expires_at).await.map_err(repo_error)?;
+  Ok(Json(resp::Ban::from_query(ban)))
+}
+
+/// 解除封禁, 已失效的令牌不会恢复
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: Permitted<BanUsers>,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.bans.delete(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,65 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+use sha1::{Digest, Sha1};
+
+use super::repo_error;
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{ListParams, SortBy},
+  utils::{self, auth::AdminAuth},
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::BlockedServerListQuery>,
+) -> Result<Json<resp::Page<resp::BlockedServer>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let params = ListParams { skip, take: page_size, sort: SortBy::Id, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.blocked_servers.list(params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page {
+    items: items.into_iter().map(resp::BlockedServer::from_query).collect(),
+    page,
+    page_size,
+    total,
+  }))
+}
+
+pub async fn create(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Json(req): Json<req::CreateBlockedServerReq>,
+) -> Result<Json<resp::BlockedServer>, error::ErrorResponse> {
+  let (hash, pattern) = match (req.pattern, req.hash) {
+    (Some(pattern), None) if !pattern.trim().is_empty() => {
+      let pattern = pattern.trim().to_lowercase();
+      (utils::texture_vec_to_string(Sha1::digest(pattern.as_bytes()).to_vec()), Some(pattern))
+    },
+    (None, Some(hash)) if hash.len() == 40 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+      (hash.to_lowercase(), None)
+    },
+    _ => {
+      return Err(
+        error::Error::new_illegal_argument("Exactly one of pattern and a SHA-1 hex hash is required.").to_response(),
+      );
+    },
+  };
+  let blocked = state.repos.blocked_servers.create(hash, pattern, req.reason).await.map_err(repo_error)?;
+  Ok(Json(resp::BlockedServer::from_query(blocked)))
+}
+
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.blocked_servers.delete(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
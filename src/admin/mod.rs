@@ -0,0 +1,52 @@
+//! 管理 API, 挂载在 `/admin/api` 下, 需要管理员令牌或配置中的 API 密钥
+//! 披风授权与封禁接口只需要 GrantCapes 与 BanUsers 权限, 版主也可以调用
+
+use axum::{routing, Router};
+
+use crate::{app_state::AppState, models::error, repo::RepoError};
+
+mod bans;
+mod blocked_servers;
+mod profiles;
+mod settings;
+mod textures;
+mod tokens;
+mod users;
+
+pub fn router() -> Router<AppState> {
+  Router::new()
+    .route("/users", routing::get(users::list).post(users::create))
+    .route("/users/:id", routing::get(users::get).patch(users::update).delete(users::delete))
+    .route("/profiles", routing::get(profiles::list).post(profiles::create))
+    .route("/profiles/:id", routing::get(profiles::get).patch(profiles::update).delete(profiles::delete))
+    .route("/skins", routing::get(textures::list_skins).post(textures::create_skin))
+    .route("/skins/:id", routing::get(textures::get_skin).patch(textures::update_skin).delete(textures::delete_skin))
+    .route("/capes", routing::get(textures::list_capes).post(textures::create_cape))
+    .route("/capes/:id", routing::get(textures::get_cape).patch(textures::update_cape).delete(textures::delete_cape))
+    .route("/capes/:id/grants", routing::post(textures::grant_cape))
+    .route("/capes/:id/grants/:userId", routing::delete(textures::revoke_cape))
+    .route("/tokens", routing::get(tokens::list).post(tokens::create))
+    .route("/tokens/:id", routing::get(tokens::get).patch(tokens::update).delete(tokens::delete))
+    .route("/settings", routing::get(settings::list))
+    .route("/bans", routing::get(bans::list).post(bans::create))
+    .route("/bans/:id", routing::get(bans::get).delete(bans::delete))
+    .route("/blocked-servers", routing::get(blocked_servers::list).post(blocked_servers::create))
+    .route("/blocked-servers/:id", routing::delete(blocked_servers::delete))
+    .route("/settings/:userId", routing::get(settings::get).put(settings::upsert).delete(settings::delete))
+}
+
+/// 将存储错误转换为响应, 唯一约束冲突返回 409, 记录不存在返回 404
+fn repo_error(err: RepoError) -> error::ErrorResponse {
+  if err.is_conflict() {
+    return error::Error::new_conflict().to_response();
+  }
+  if let RepoError::NotFound = err {
+    return not_found();
+  }
+  tracing::debug!("管理 API 数据库操作失败: {:?}", err);
+  error::Error::new_database_error().to_response()
+}
+
+fn not_found() -> error::ErrorResponse {
+  error::Error::new_not_found().to_response()
+}
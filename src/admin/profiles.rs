@@ -0,0 +1,121 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{ListParams, ProfileFilter, ProfileUpdate, SortBy},
+  utils::{self, auth::AdminAuth},
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::ProfileListQuery>,
+) -> Result<Json<resp::Page<resp::Profile>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let filter = ProfileFilter { owner_id: query.owner_id, name: query.name };
+  let sort = match query.sort.as_deref() {
+    Some("name") => SortBy::Name,
+    Some("createdAt") => SortBy::CreatedAt,
+    _ => SortBy::Id,
+  };
+  let params = ListParams { skip, take: page_size, sort, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.profiles.list(filter, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page { items: items.into_iter().map(resp::Profile::from_query).collect(), page, page_size, total }))
+}
+
+pub async fn get(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::Profile>, error::ErrorResponse> {
+  let profile = state.repos.profiles.find_by_id(id).await.map_err(repo_error)?;
+  profile.map(|x| Json(resp::Profile::from_query(x))).ok_or_else(not_found)
+}
+
+pub async fn create(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Json(req): Json<req::CreateProfileReq>,
+) -> Result<Json<resp::Profile>, error::ErrorResponse> {
+  let uuid = match req.uuid {
+    Some(x) => {
+      match utils::string_to_uuid_vec(x) {
+        x if x.is_empty() => {
+          return Err(error::Error::new_illegal_argument("Invalid UUID.").to_response());
+        },
+        x => x,
+      }
+    },
+    None => utils::profiles::new_profile_uuid(&req.name, &state.settings),
+  };
+  let rules = match utils::profiles::admin_name_rules(&req.name, &state.settings, req.force.unwrap_or(false)) {
+    Ok(x) => x,
+    Err(message) => {
+      return Err(error::Error::new_illegal_argument(&message).to_response());
+    },
+  };
+  let profile = state.repos.profiles.create(uuid, req.name, req.owner_id, &rules, None).await.map_err(repo_error)?;
+  let profile = match req.uploadable_textures {
+    Some(x) => {
+      let update = ProfileUpdate { uploadable_textures: Some(x), ..Default::default() };
+      state.repos.profiles.update(profile.id, update).await.map_err(repo_error)?
+    },
+    None => profile,
+  };
+  Ok(Json(resp::Profile::from_query(profile)))
+}
+
+pub async fn update(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+  Json(req): Json<req::UpdateProfileReq>,
+) -> Result<Json<resp::Profile>, error::ErrorResponse> {
+  let old = match state.repos.profiles.find_by_id(id).await.map_err(repo_error)? {
+    Some(x) => x,
+    None => {
+      return Err(not_found());
+    },
+  };
+  // 管理员改名同样记录到改名记录中, 但不受冷却时间限制; 改名会吊销绑定到角色的令牌
+  if let Some(name) = req.name.filter(|x| *x != old.display_name) {
+    let rules = match utils::profiles::admin_name_rules(&name, &state.settings, req.force.unwrap_or(false)) {
+      Ok(x) => x,
+      Err(message) => {
+        return Err(error::Error::new_illegal_argument(&message).to_response());
+      },
+    };
+    state.repos.profiles.rename(id, name, &rules, None).await.map_err(repo_error)?;
+  }
+  let update = ProfileUpdate {
+    name: None,
+    owner_id: req.owner_id,
+    uploadable_textures: req.uploadable_textures,
+    skin_id: req.skin_id,
+    cape_id: req.cape_id,
+  };
+  let profile = state.repos.profiles.update(id, update).await.map_err(repo_error)?;
+  // 转移角色后, 绑定到角色的令牌全部失效
+  if req.owner_id.is_some() {
+    state.repos.tokens.invalidate_by_profile(id).await.map_err(repo_error)?;
+  }
+  Ok(Json(resp::Profile::from_query(profile)))
+}
+
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.profiles.delete(id, None).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,66 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{ListParams, SettingUpdate, SortBy},
+  utils::auth::AdminAuth,
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::SettingListQuery>,
+) -> Result<Json<resp::Page<resp::Setting>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let params = ListParams { skip, take: page_size, sort: SortBy::Id, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.users.list_settings(query.user_id, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page { items: items.into_iter().map(resp::Setting::from_query).collect(), page, page_size, total }))
+}
+
+pub async fn get(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(user_id): Path<i64>,
+) -> Result<Json<resp::Setting>, error::ErrorResponse> {
+  let setting = state.repos.users.find_setting(user_id).await.map_err(repo_error)?;
+  setting.map(|x| Json(resp::Setting::from_query(x))).ok_or_else(not_found)
+}
+
+/// 用户设置不存在时创建, 未指定的字段使用全局配置
+pub async fn upsert(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(user_id): Path<i64>,
+  Json(req): Json<req::UpsertSettingReq>,
+) -> Result<Json<resp::Setting>, error::ErrorResponse> {
+  if state.repos.users.find_by_id(user_id).await.map_err(repo_error)?.is_none() {
+    return Err(not_found());
+  }
+  let update = SettingUpdate {
+    max_token: req.max_token,
+    token_need_refresh_duration: req.token_need_refresh_duration,
+    token_invalid_duration: req.token_invalid_duration,
+  };
+  let setting =
+    state.repos.users.upsert_setting(user_id, update, &state.settings.token).await.map_err(repo_error)?;
+  Ok(Json(resp::Setting::from_query(setting)))
+}
+
+/// 删除后该用户回到使用全局配置
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(user_id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.users.delete_setting(user_id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
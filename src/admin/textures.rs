@@ -0,0 +1,234 @@
+use axum::{
+  extract::{Multipart, Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  prisma,
+  repo::{CapeUpdate, ListParams, SortBy},
+  utils::{
+    self,
+    auth::{AdminAuth, Permitted},
+    capes::OfficialCapeError,
+    permissions::GrantCapes,
+    textures::TextureType,
+  },
+};
+
+/// 上传的材质文件与其余的文本字段
+struct Upload {
+  data: Vec<u8>,
+  fields: Vec<(String, String)>,
+}
+
+impl Upload {
+  async fn read(mut multipart: Multipart) -> Result<Self, error::ErrorResponse> {
+    let mut data = None;
+    let mut fields = vec![];
+    while let Ok(Some(field)) = multipart.next_field().await {
+      let name = field.name().unwrap_or_default().to_owned();
+      if name == "file" {
+        match field.bytes().await {
+          Ok(x) => data = Some(x.to_vec()),
+          Err(_err) => {
+            return Err(error::Error::new_invalid_texture("Failed to read the texture file.").to_response());
+          },
+        }
+      } else {
+        fields.push((name, field.text().await.unwrap_or_default()));
+      }
+    }
+    match data {
+      Some(data) => Ok(Self { data, fields }),
+      None => Err(error::Error::new_invalid_texture("Missing texture file.").to_response()),
+    }
+  }
+
+  fn field(&self, name: &str) -> Option<&str> {
+    self.fields.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+  }
+}
+
+fn texture_sort(query: &req::TextureListQuery) -> SortBy {
+  match query.sort.as_deref() {
+    Some("createdAt") => SortBy::CreatedAt,
+    _ => SortBy::Id,
+  }
+}
+
+pub async fn list_skins(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::TextureListQuery>,
+) -> Result<Json<resp::Page<resp::Skin>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let params = ListParams { skip, take: page_size, sort: texture_sort(&query), order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.textures.list_skins(query.model, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page {
+    items: items.into_iter().map(|x| resp::Skin::from_query(x, &state.settings)).collect(),
+    page,
+    page_size,
+    total,
+  }))
+}
+
+pub async fn get_skin(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::Skin>, error::ErrorResponse> {
+  let skin = state.repos.textures.find_skin(id).await.map_err(repo_error)?;
+  skin.map(|x| Json(resp::Skin::from_query(x, &state.settings))).ok_or_else(not_found)
+}
+
+/// multipart 表单: file, model (slim 或 default)
+pub async fn create_skin(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  multipart: Multipart,
+) -> Result<Json<resp::Skin>, error::ErrorResponse> {
+  let upload = Upload::read(multipart).await?;
+  if let Err(err) = utils::textures::validate(&upload.data, TextureType::Skin, &state.settings) {
+    return Err(error::Error::new_invalid_texture(err.message()).to_response());
+  }
+  let model = match upload.field("model") {
+    Some("slim") => prisma::SkinType::Slim,
+    _ => prisma::SkinType::Default,
+  };
+  let hash = utils::textures::hash(&upload.data);
+  if let Err(err) =
+    utils::textures::save(&state.settings, &utils::texture_vec_to_string(hash.clone()), &upload.data).await
+  {
+    tracing::error!("保存材质失败: {}", err);
+    return Err(error::Error::new_database_error().to_response());
+  }
+  let skin = state.repos.textures.find_or_create_skin(hash, model).await.map_err(repo_error)?;
+  Ok(Json(resp::Skin::from_query(skin, &state.settings)))
+}
+
+pub async fn update_skin(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+  Json(req): Json<req::UpdateSkinReq>,
+) -> Result<Json<resp::Skin>, error::ErrorResponse> {
+  let skin = state.repos.textures.set_skin_model(id, req.model).await.map_err(repo_error)?;
+  Ok(Json(resp::Skin::from_query(skin, &state.settings)))
+}
+
+/// 使用该皮肤的角色会被清除皮肤, 材质库与公开材质中的条目一并删除
+pub async fn delete_skin(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.textures.delete_skin(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_capes(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::TextureListQuery>,
+) -> Result<Json<resp::Page<resp::Cape>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let params = ListParams { skip, take: page_size, sort: texture_sort(&query), order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.textures.list_capes(query.official, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page {
+    items: items.into_iter().map(|x| resp::Cape::from_query(x, &state.settings)).collect(),
+    page,
+    page_size,
+    total,
+  }))
+}
+
+pub async fn get_cape(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::Cape>, error::ErrorResponse> {
+  let cape = state.repos.textures.find_cape(id).await.map_err(repo_error)?;
+  cape.map(|x| Json(resp::Cape::from_query(x, &state.settings))).ok_or_else(not_found)
+}
+
+/// multipart 表单: file, name, frameRate; 通过管理 API 创建的披风均为官方披风
+pub async fn create_cape(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  multipart: Multipart,
+) -> Result<Json<resp::Cape>, error::ErrorResponse> {
+  let upload = Upload::read(multipart).await?;
+  let name = upload.field("name").unwrap_or_default().trim().to_owned();
+  if name.is_empty() {
+    return Err(error::Error::new_illegal_argument("Cape name must not be empty.").to_response());
+  }
+  let frame_rate = upload.field("frameRate").and_then(|x| x.trim().parse().ok());
+  match utils::capes::create_official_cape(&state.repos, &state.settings, name, &upload.data, frame_rate).await {
+    Ok(cape) => Ok(Json(resp::Cape::from_query(cape, &state.settings))),
+    Err(OfficialCapeError::InvalidTexture(err)) => Err(error::Error::new_invalid_texture(err.message()).to_response()),
+    Err(OfficialCapeError::RepoError(err)) => Err(repo_error(err)),
+    Err(OfficialCapeError::IoError(err)) => {
+      tracing::error!("保存材质失败: {}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+pub async fn update_cape(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+  Json(req): Json<req::UpdateCapeReq>,
+) -> Result<Json<resp::Cape>, error::ErrorResponse> {
+  if req.frame_rate.is_some_and(|x| x.is_some_and(|x| x <= 0 || x as u32 > state.settings.textures.max_frame_rate)) {
+    return Err(error::Error::new_illegal_argument("Invalid frame rate.").to_response());
+  }
+  let update = CapeUpdate { name: req.name, official: req.official, frame_rate: req.frame_rate };
+  let cape = state.repos.textures.update_cape(id, update).await.map_err(repo_error)?;
+  Ok(Json(resp::Cape::from_query(cape, &state.settings)))
+}
+
+/// 使用该披风的角色会被清除披风, 授权、材质库与公开材质中的条目一并删除
+pub async fn delete_cape(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.textures.delete_cape(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
+
+/// 授予用户官方披风, 版主也可以调用
+pub async fn grant_cape(
+  State(state): State<AppState>,
+  _auth: Permitted<GrantCapes>,
+  Path(id): Path<i64>,
+  Json(req): Json<req::GrantCapeReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let cape = state.repos.textures.find_cape(id).await.map_err(repo_error)?;
+  if !cape.is_some_and(|x| x.official) {
+    return Err(not_found());
+  }
+  if state.repos.users.find_by_id(req.user_id).await.map_err(repo_error)?.is_none() {
+    return Err(not_found());
+  }
+  state.repos.entitlements.grant(req.user_id, id, req.reason).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn revoke_cape(
+  State(state): State<AppState>,
+  _auth: Permitted<GrantCapes>,
+  Path((id, user_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  // 用户没有该披风时返回 404
+  state.repos.entitlements.revoke(user_id, id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,88 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{ListParams, SortBy, TokenFilter},
+  utils::{self, auth::AdminAuth},
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::TokenListQuery>,
+) -> Result<Json<resp::Page<resp::Token>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let filter = TokenFilter { owner_id: query.owner_id, profile_id: query.profile_id, status: query.status };
+  let sort = match query.sort.as_deref() {
+    Some("createdAt") => SortBy::CreatedAt,
+    _ => SortBy::Id,
+  };
+  let params = ListParams { skip, take: page_size, sort, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.tokens.list(filter, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page { items: items.into_iter().map(resp::Token::from_query).collect(), page, page_size, total }))
+}
+
+pub async fn get(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::Token>, error::ErrorResponse> {
+  let token = state.repos.tokens.find_by_id(id).await.map_err(repo_error)?;
+  token.map(|x| Json(resp::Token::from_query(x))).ok_or_else(not_found)
+}
+
+/// 为用户签发令牌, 供机器人等无法走登录流程的调用方使用
+pub async fn create(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Json(req): Json<req::CreateTokenReq>,
+) -> Result<Json<resp::Token>, error::ErrorResponse> {
+  if let Some(profile_id) = req.profile_id {
+    match state.repos.profiles.find_by_id(profile_id).await.map_err(repo_error)? {
+      Some(x) if x.owner_id == req.owner_id => {},
+      _ => {
+        return Err(error::Error::new_assign_others_profile().to_response());
+      },
+    }
+  }
+  let token = state
+    .repos
+    .tokens
+    .create(req.owner_id, req.profile_id, utils::gen_access_token(), req.client_token.unwrap_or(utils::gen_uuid()))
+    .await
+    .map_err(repo_error)?;
+  Ok(Json(resp::Token::from_query(token)))
+}
+
+pub async fn update(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+  Json(req): Json<req::UpdateTokenReq>,
+) -> Result<Json<resp::Token>, error::ErrorResponse> {
+  if state.repos.tokens.find_by_id(id).await.map_err(repo_error)?.is_none() {
+    return Err(not_found());
+  }
+  state.repos.tokens.set_status(id, req.status).await.map_err(repo_error)?;
+  let token = state.repos.tokens.find_by_id(id).await.map_err(repo_error)?;
+  token.map(|x| Json(resp::Token::from_query(x))).ok_or_else(not_found)
+}
+
+/// 删除令牌及其加入服务器的记录
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  state.repos.tokens.delete(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,98 @@
+use axum::{
+  extract::{Path, Query, State},
+  http::StatusCode,
+  Json,
+};
+
+use super::{not_found, repo_error};
+use crate::{
+  app_state::AppState,
+  models::{
+    admin::{self, req, resp},
+    error,
+  },
+  repo::{ListParams, SortBy, UserFilter, UserUpdate},
+  utils::auth::AdminAuth,
+};
+
+pub async fn list(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Query(query): Query<req::UserListQuery>,
+) -> Result<Json<resp::Page<resp::User>>, error::ErrorResponse> {
+  let (page, page_size, skip) = admin::page_params(query.page, query.page_size);
+  let filter =
+    UserFilter { email: query.email, nickname: query.nickname, disabled: query.disabled, role: query.role };
+  let sort = match query.sort.as_deref() {
+    Some("email") => SortBy::Email,
+    Some("nickname") => SortBy::Nickname,
+    Some("createdAt") => SortBy::CreatedAt,
+    _ => SortBy::Id,
+  };
+  let params = ListParams { skip, take: page_size, sort, order: admin::sort_order(&query.order) };
+  let (items, total) = state.repos.users.list(filter, params).await.map_err(repo_error)?;
+  Ok(Json(resp::Page { items: items.into_iter().map(resp::User::from_query).collect(), page, page_size, total }))
+}
+
+pub async fn get(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<Json<resp::User>, error::ErrorResponse> {
+  let user = state.repos.users.find_by_id(id).await.map_err(repo_error)?;
+  user.map(|x| Json(resp::User::from_query(x))).ok_or_else(not_found)
+}
+
+pub async fn create(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Json(req): Json<req::CreateUserReq>,
+) -> Result<Json<resp::User>, error::ErrorResponse> {
+  let user = state
+    .repos
+    .users
+    .create(uuid::Uuid::new_v4().as_bytes().to_vec(), req.nickname, req.email, req.password)
+    .await
+    .map_err(repo_error)?;
+  if req.role.is_none() && req.permissions.is_none() && req.language.is_none() {
+    return Ok(Json(resp::User::from_query(user)));
+  }
+  let update =
+    UserUpdate { role: req.role, permissions: req.permissions, language: req.language, ..Default::default() };
+  let user = state.repos.users.update(user.id, update).await.map_err(repo_error)?;
+  Ok(Json(resp::User::from_query(user)))
+}
+
+pub async fn update(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+  Json(req): Json<req::UpdateUserReq>,
+) -> Result<Json<resp::User>, error::ErrorResponse> {
+  // 修改密码或停用用户时, 已有的令牌全部失效
+  let invalidate_tokens = req.password.is_some() || req.disabled == Some(true);
+  let update = UserUpdate {
+    email: req.email,
+    nickname: req.nickname,
+    password: req.password,
+    role: req.role,
+    permissions: req.permissions,
+    language: req.language,
+    disabled: req.disabled,
+  };
+  let user = state.repos.users.update(id, update).await.map_err(repo_error)?;
+  if invalidate_tokens {
+    state.repos.tokens.invalidate_by_owner(user.id).await.map_err(repo_error)?;
+  }
+  Ok(Json(resp::User::from_query(user)))
+}
+
+pub async fn delete(
+  State(state): State<AppState>,
+  _auth: AdminAuth,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  // 角色、令牌、材质库等数据随用户级联删除
+  state.repos.users.delete(id).await.map_err(repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
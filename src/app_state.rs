@@ -1,13 +1,7 @@
-use std::sync::Arc;
-
-use prisma::PrismaClient;
-
-use crate::{prisma, settings::Settings};
-
-type DbState = Arc<PrismaClient>;
+use crate::{repo::Repos, settings::Settings};
 
 #[derive(Clone)]
 pub struct AppState {
-  pub db: DbState,
+  pub repos: Repos,
   pub settings: Settings,
 }
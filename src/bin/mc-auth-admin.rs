@@ -0,0 +1,489 @@
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand};
+use mc_auth::{
+  db, prisma,
+  repo::{ListParams, ProfileFilter, Repos, SortBy, TokenFilter, UserFilter, UserUpdate},
+  settings::{DatabaseBackend, Settings},
+  utils::{self, textures::TextureType},
+};
+use serde::Serialize;
+use tokio::fs;
+
+#[derive(Parser)]
+#[command(name = "mc-auth-admin", version, about = "色麦块认证服务器管理工具")]
+struct Cli {
+  /// 配置文件路径
+  #[arg(long, global = true, default_value = "Settings.toml")]
+  config: String,
+
+  /// 以 JSON 格式输出, 方便脚本处理
+  #[arg(long, global = true)]
+  json: bool,
+
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// 用户管理
+  #[command(subcommand)]
+  User(UserCommand),
+  /// 角色管理
+  #[command(subcommand)]
+  Profile(ProfileCommand),
+  /// 官方披风管理
+  #[command(subcommand)]
+  Cape(CapeCommand),
+  /// 令牌管理
+  #[command(subcommand)]
+  Token(TokenCommand),
+}
+
+#[derive(Subcommand)]
+enum UserCommand {
+  /// 创建用户
+  Create { email: String, nickname: String, password: String },
+  /// 列出全部用户
+  List,
+  /// 停用用户, 并吊销其全部令牌
+  Disable { email: String },
+  /// 重新启用用户
+  Enable { email: String },
+  /// 重置密码, 并吊销其全部令牌
+  ResetPassword { email: String, password: String },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+  /// 为用户创建角色
+  Create {
+    email: String,
+    name: String,
+    /// 指定 UUID, 默认随机生成
+    #[arg(long)]
+    uuid: Option<String>,
+    /// 跳过角色名的格式与保留期检查
+    #[arg(long)]
+    force: bool,
+  },
+  /// 列出角色, 可以只列出某个用户的角色
+  List {
+    #[arg(long)]
+    email: Option<String>,
+  },
+  /// 重命名角色
+  Rename {
+    name: String,
+    new_name: String,
+    /// 跳过新角色名的格式与保留期检查
+    #[arg(long)]
+    force: bool,
+  },
+  /// 删除角色, 并吊销绑定到它的令牌
+  Delete { name: String },
+  /// 为角色设置材质
+  SetTexture {
+    name: String,
+    /// skin, cape, elytra 或 ears
+    texture_type: String,
+    file: String,
+    /// 使用纤细模型 (仅皮肤)
+    #[arg(long)]
+    slim: bool,
+  },
+  /// 清除角色的材质
+  ClearTexture { name: String, texture_type: String },
+}
+
+#[derive(Subcommand)]
+enum CapeCommand {
+  /// 创建官方披风
+  Create {
+    name: String,
+    file: String,
+    /// 动态披风的帧率
+    #[arg(long)]
+    frame_rate: Option<u32>,
+  },
+  /// 列出官方披风
+  List,
+  /// 授予用户官方披风
+  Grant {
+    email: String,
+    cape_id: i64,
+    #[arg(long)]
+    reason: Option<String>,
+  },
+  /// 收回用户的官方披风
+  Revoke { email: String, cape_id: i64 },
+}
+
+#[derive(Subcommand)]
+enum TokenCommand {
+  /// 列出用户的令牌
+  List {
+    email: String,
+    /// 包括已失效的令牌
+    #[arg(long)]
+    all: bool,
+  },
+  /// 吊销令牌
+  Revoke { access_token: String },
+  /// 吊销用户的全部令牌
+  RevokeAll { email: String },
+}
+
+#[derive(Serialize)]
+struct UserInfo {
+  id: i64,
+  uuid: String,
+  nickname: String,
+  email: String,
+  disabled: bool,
+  #[serde(rename = "createdAt")]
+  created_at: i64,
+}
+
+impl UserInfo {
+  fn from_query(data: prisma::user::Data) -> Self {
+    Self {
+      id: data.id,
+      uuid: utils::uuid_vec_to_string(data.uuid),
+      nickname: data.nickname,
+      email: data.email,
+      disabled: data.disabled,
+      created_at: data.created_at.timestamp_millis(),
+    }
+  }
+
+  fn describe(&self) -> String {
+    format!(
+      "#{} {} <{}> {}{}",
+      self.id,
+      self.nickname,
+      self.email,
+      self.uuid,
+      if self.disabled { " (已停用)" } else { "" }
+    )
+  }
+}
+
+#[derive(Serialize)]
+struct ProfileInfo {
+  id: i64,
+  uuid: String,
+  name: String,
+  #[serde(rename = "ownerId")]
+  owner_id: i64,
+  #[serde(rename = "skinId")]
+  skin_id: Option<i64>,
+  #[serde(rename = "capeId")]
+  cape_id: Option<i64>,
+  #[serde(rename = "createdAt")]
+  created_at: i64,
+}
+
+impl ProfileInfo {
+  fn from_query(data: prisma::profile::Data) -> Self {
+    Self {
+      id: data.id,
+      uuid: utils::uuid_vec_to_string(data.uuid),
+      name: data.display_name,
+      owner_id: data.owner_id,
+      skin_id: data.skin_id,
+      cape_id: data.cape_id,
+      created_at: data.created_at.timestamp_millis(),
+    }
+  }
+
+  fn describe(&self) -> String {
+    format!("#{} {} {} (用户 #{})", self.id, self.name, self.uuid, self.owner_id)
+  }
+}
+
+#[derive(Serialize)]
+struct CapeInfo {
+  id: i64,
+  name: Option<String>,
+  hash: String,
+  #[serde(rename = "frameRate")]
+  frame_rate: Option<i32>,
+}
+
+impl CapeInfo {
+  fn from_query(data: prisma::cape::Data) -> Self {
+    Self { id: data.id, name: data.name, hash: utils::texture_vec_to_string(data.hash), frame_rate: data.frame_rate }
+  }
+
+  fn describe(&self) -> String {
+    format!("#{} {} {}", self.id, self.name.clone().unwrap_or_default(), self.hash)
+  }
+}
+
+#[derive(Serialize)]
+struct TokenInfo {
+  id: i64,
+  #[serde(rename = "accessToken")]
+  access_token: String,
+  #[serde(rename = "clientToken")]
+  client_token: String,
+  #[serde(rename = "profileId")]
+  profile_id: Option<i64>,
+  status: prisma::TokenStatus,
+  #[serde(rename = "createdAt")]
+  created_at: i64,
+}
+
+impl TokenInfo {
+  fn from_query(data: prisma::token::Data) -> Self {
+    Self {
+      id: data.id,
+      access_token: data.access_token,
+      client_token: data.client_token,
+      profile_id: data.profile_id,
+      status: data.status,
+      created_at: data.created_at.timestamp_millis(),
+    }
+  }
+
+  fn describe(&self) -> String {
+    format!("#{} {} {:?} {}", self.id, self.access_token, self.status, self.created_at)
+  }
+}
+
+/// 操作结果, 用于没有返回数据的命令
+#[derive(Serialize)]
+struct Done {
+  ok: bool,
+  affected: i64,
+}
+
+struct Output {
+  json: bool,
+}
+
+impl Output {
+  fn one<T: Serialize>(&self, value: &T, text: String) -> anyhow::Result<()> {
+    if self.json {
+      println!("{}", serde_json::to_string(value)?);
+    } else {
+      println!("{}", text);
+    }
+    Ok(())
+  }
+
+  fn many<T: Serialize>(&self, values: &Vec<T>, text: impl Fn(&T) -> String) -> anyhow::Result<()> {
+    if self.json {
+      println!("{}", serde_json::to_string(values)?);
+    } else {
+      for x in values {
+        println!("{}", text(x));
+      }
+    }
+    Ok(())
+  }
+
+  fn done(&self, affected: i64) -> anyhow::Result<()> {
+    self.one(&Done { ok: true, affected }, format!("完成, 影响了 {} 条记录", affected))
+  }
+}
+
+async fn find_user(repos: &Repos, email: &str) -> anyhow::Result<prisma::user::Data> {
+  repos.users.find_by_email(email).await?.ok_or_else(|| anyhow::anyhow!("用户 {} 不存在", email))
+}
+
+async fn find_profile(repos: &Repos, name: &str) -> anyhow::Result<prisma::profile::Data> {
+  repos.profiles.find_by_name(name, false).await?.ok_or_else(|| anyhow::anyhow!("角色 {} 不存在", name))
+}
+
+fn parse_texture_type(x: &str, settings: &Settings) -> anyhow::Result<TextureType> {
+  TextureType::from_path(x).filter(|t| t.enabled(settings)).ok_or_else(|| anyhow::anyhow!("不支持的材质类型 {}", x))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+  let cli = Cli::parse();
+  let settings = Settings::load(&cli.config).await?;
+  let out = Output { json: cli.json };
+  let repos = match settings.database.backend {
+    DatabaseBackend::Postgresql => Repos::postgres(Arc::new(db::connect(&settings.database).await?)),
+    DatabaseBackend::Sqlite => Repos::sqlite(&settings.database.sqlite_path)?,
+  };
+
+  match cli.command {
+    Command::User(cmd) => user_command(&repos, &out, cmd).await,
+    Command::Profile(cmd) => profile_command(&repos, &settings, &out, cmd).await,
+    Command::Cape(cmd) => cape_command(&repos, &settings, &out, cmd).await,
+    Command::Token(cmd) => token_command(&repos, &out, cmd).await,
+  }
+}
+
+async fn user_command(repos: &Repos, out: &Output, cmd: UserCommand) -> anyhow::Result<()> {
+  match cmd {
+    UserCommand::Create { email, nickname, password } => {
+      let uuid = uuid::Uuid::new_v4().as_bytes().to_vec();
+      let user = repos.users.create(uuid, nickname, email, password).await?;
+      let user = UserInfo::from_query(user);
+      out.one(&user, user.describe())
+    },
+    UserCommand::List => {
+      let (users, _) = repos.users.list(UserFilter::default(), ListParams::all()).await?;
+      let users: Vec<UserInfo> = users.into_iter().map(UserInfo::from_query).collect();
+      out.many(&users, UserInfo::describe)
+    },
+    UserCommand::Disable { email } => {
+      let user = find_user(repos, &email).await?;
+      repos.users.update(user.id, UserUpdate { disabled: Some(true), ..Default::default() }).await?;
+      out.done(repos.tokens.invalidate_by_owner(user.id).await?)
+    },
+    UserCommand::Enable { email } => {
+      let user = find_user(repos, &email).await?;
+      if !user.disabled {
+        return out.done(0);
+      }
+      repos.users.update(user.id, UserUpdate { disabled: Some(false), ..Default::default() }).await?;
+      out.done(1)
+    },
+    UserCommand::ResetPassword { email, password } => {
+      let user = find_user(repos, &email).await?;
+      repos.users.update(user.id, UserUpdate { password: Some(password), ..Default::default() }).await?;
+      out.done(repos.tokens.invalidate_by_owner(user.id).await?)
+    },
+  }
+}
+
+async fn profile_command(repos: &Repos, settings: &Settings, out: &Output, cmd: ProfileCommand) -> anyhow::Result<()> {
+  match cmd {
+    ProfileCommand::Create { email, name, uuid, force } => {
+      let user = find_user(repos, &email).await?;
+      let rules = utils::profiles::admin_name_rules(&name, settings, force).map_err(anyhow::Error::msg)?;
+      let uuid = match uuid {
+        Some(x) => uuid::Uuid::parse_str(&x)?.as_bytes().to_vec(),
+        None => utils::profiles::new_profile_uuid(&name, settings),
+      };
+      let profile = repos.profiles.create(uuid, name, user.id, &rules, None).await?;
+      let profile = ProfileInfo::from_query(profile);
+      out.one(&profile, profile.describe())
+    },
+    ProfileCommand::List { email } => {
+      let owner_id = match email {
+        Some(email) => Some(find_user(repos, &email).await?.id),
+        None => None,
+      };
+      let (profiles, _) = repos.profiles.list(ProfileFilter { owner_id, name: None }, ListParams::all()).await?;
+      let profiles: Vec<ProfileInfo> = profiles.into_iter().map(ProfileInfo::from_query).collect();
+      out.many(&profiles, ProfileInfo::describe)
+    },
+    ProfileCommand::Rename { name, new_name, force } => {
+      // 与玩家改名相同, 记录旧名称并吊销令牌, 但不检查冷却时间
+      let profile = find_profile(repos, &name).await?;
+      let rules = utils::profiles::admin_name_rules(&new_name, settings, force).map_err(anyhow::Error::msg)?;
+      let profile = repos.profiles.rename(profile.id, new_name, &rules, None).await?;
+      let profile = ProfileInfo::from_query(profile);
+      out.one(&profile, profile.describe())
+    },
+    ProfileCommand::Delete { name } => {
+      let profile = find_profile(repos, &name).await?;
+      repos.profiles.delete(profile.id, None).await?;
+      out.done(1)
+    },
+    ProfileCommand::SetTexture { name, texture_type, file, slim } => {
+      let profile = find_profile(repos, &name).await?;
+      let texture_type = parse_texture_type(&texture_type, settings)?;
+      let data = fs::read(&file).await?;
+      let (width, height) = utils::textures::validate(&data, texture_type, settings)?;
+      let hash = utils::textures::hash(&data);
+      utils::textures::save(settings, &utils::texture_vec_to_string(hash.clone()), &data).await?;
+      match texture_type {
+        TextureType::Skin => {
+          let model = if slim { prisma::SkinType::Slim } else { prisma::SkinType::Default };
+          let skin = repos.textures.find_or_create_skin(hash, model).await?;
+          repos.profiles.set_skin(profile.id, Some(skin.id)).await?;
+        },
+        TextureType::Cape => {
+          let frames = utils::textures::cape_frames(width, height).unwrap_or(1);
+          let frame_rate = utils::textures::validate_frame_rate(frames, None, settings)?;
+          let cape = repos.textures.find_or_create_cape(hash, frame_rate).await?;
+          repos.profiles.set_cape(profile.id, Some(cape.id)).await?;
+        },
+        TextureType::Elytra | TextureType::Ears => {
+          repos.textures.set_extra(profile.id, texture_type.extra().unwrap(), hash).await?;
+        },
+      }
+      out.done(1)
+    },
+    ProfileCommand::ClearTexture { name, texture_type } => {
+      let profile = find_profile(repos, &name).await?;
+      let texture_type = parse_texture_type(&texture_type, settings)?;
+      let affected = match texture_type {
+        TextureType::Skin if profile.skin_id.is_some() => {
+          repos.profiles.set_skin(profile.id, None).await?;
+          1
+        },
+        TextureType::Cape if profile.cape_id.is_some() => {
+          repos.profiles.set_cape(profile.id, None).await?;
+          1
+        },
+        TextureType::Skin | TextureType::Cape => 0,
+        TextureType::Elytra | TextureType::Ears => {
+          repos.textures.clear_extra(profile.id, texture_type.extra().unwrap()).await?
+        },
+      };
+      out.done(affected)
+    },
+  }
+}
+
+async fn cape_command(repos: &Repos, settings: &Settings, out: &Output, cmd: CapeCommand) -> anyhow::Result<()> {
+  match cmd {
+    CapeCommand::Create { name, file, frame_rate } => {
+      let data = fs::read(&file).await?;
+      let cape = utils::capes::create_official_cape(repos, settings, name, &data, frame_rate).await?;
+      let cape = CapeInfo::from_query(cape);
+      out.one(&cape, cape.describe())
+    },
+    CapeCommand::List => {
+      let (capes, _) = repos.textures.list_capes(Some(true), ListParams::all()).await?;
+      let capes: Vec<CapeInfo> = capes.into_iter().map(CapeInfo::from_query).collect();
+      out.many(&capes, CapeInfo::describe)
+    },
+    CapeCommand::Grant { email, cape_id, reason } => {
+      let user = find_user(repos, &email).await?;
+      // 只能授予官方披风
+      if !repos.textures.find_cape(cape_id).await?.is_some_and(|x| x.official) {
+        anyhow::bail!("官方披风 {} 不存在", cape_id);
+      }
+      out.done(repos.entitlements.grant(user.id, cape_id, reason).await?)
+    },
+    CapeCommand::Revoke { email, cape_id } => {
+      let user = find_user(repos, &email).await?;
+      // 没有授予过时失败
+      repos.entitlements.revoke(user.id, cape_id).await?;
+      out.done(1)
+    },
+  }
+}
+
+async fn token_command(repos: &Repos, out: &Output, cmd: TokenCommand) -> anyhow::Result<()> {
+  match cmd {
+    TokenCommand::List { email, all } => {
+      let user = find_user(repos, &email).await?;
+      let params = ListParams { sort: SortBy::CreatedAt, order: prisma::SortOrder::Desc, ..ListParams::all() };
+      let (tokens, _) =
+        repos.tokens.list(TokenFilter { owner_id: Some(user.id), ..Default::default() }, params).await?;
+      let tokens: Vec<TokenInfo> = tokens
+        .into_iter()
+        .filter(|x| all || x.status != prisma::TokenStatus::Invalid)
+        .map(TokenInfo::from_query)
+        .collect();
+      out.many(&tokens, TokenInfo::describe)
+    },
+    TokenCommand::Revoke { access_token } => out.done(repos.tokens.invalidate(&access_token).await?),
+    TokenCommand::RevokeAll { email } => {
+      let user = find_user(repos, &email).await?;
+      out.done(repos.tokens.invalidate_by_owner(user.id).await?)
+    },
+  }
+}
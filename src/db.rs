@@ -0,0 +1,94 @@
+//! PostgreSQL 连接与迁移
+//! 表结构的变更通过 prisma/migrations 下的迁移应用, 不再使用 db push
+
+use std::collections::HashSet;
+
+use prisma_client_rust::{
+  migrations::{MigrateDeployError, MigrateResolveError},
+  raw, NewClientError, QueryError,
+};
+use serde::Deserialize;
+
+use crate::{
+  prisma::{self, PrismaClient},
+  settings,
+};
+
+/// 第一个迁移, 与改用迁移之前通过 db push 创建的表结构相同
+pub const BASELINE_MIGRATION: &str = "20261019000000_init";
+
+#[derive(thiserror::Error, Debug)]
+pub enum DbError {
+  #[error("未配置数据库连接地址, 请设置 [database] url 或 DATABASE_URL 环境变量")]
+  MissingUrl,
+  #[error("无法连接到数据库: {0}")]
+  Connect(#[from] NewClientError),
+  #[error("数据库错误: {0}")]
+  Query(#[from] QueryError),
+  #[error("应用迁移失败: {0}")]
+  Deploy(#[from] MigrateDeployError),
+  #[error("标记迁移失败: {0}")]
+  Resolve(#[from] MigrateResolveError),
+}
+
+#[derive(Deserialize)]
+struct Tables {
+  migrations: bool,
+  pushed: bool,
+}
+
+#[derive(Deserialize)]
+struct AppliedMigration {
+  migration_name: String,
+}
+
+pub async fn connect(sett: &settings::Database) -> Result<PrismaClient, DbError> {
+  let url = sett.connection_url().ok_or(DbError::MissingUrl)?;
+  Ok(PrismaClient::_builder().with_url(url).build().await?)
+}
+
+/// 程序内嵌的全部迁移, 按名称 (即时间) 排序
+pub fn embedded_migrations() -> Vec<String> {
+  let mut names: Vec<String> =
+    prisma::MIGRATIONS_DIR.dirs().filter_map(|x| x.path().file_name()?.to_str().map(|x| x.to_owned())).collect();
+  names.sort();
+  names
+}
+
+async fn tables(db: &PrismaClient) -> Result<Tables, DbError> {
+  let rows: Vec<Tables> = db
+    ._query_raw(raw!(
+      r#"SELECT to_regclass('"_prisma_migrations"') IS NOT NULL AS "migrations", to_regclass('"User"') IS NOT NULL AS "pushed""#
+    ))
+    .exec()
+    .await?;
+  Ok(rows.into_iter().next().unwrap_or(Tables { migrations: false, pushed: false }))
+}
+
+/// 尚未应用到数据库的迁移
+pub async fn pending_migrations(db: &PrismaClient) -> Result<Vec<String>, DbError> {
+  let embedded = embedded_migrations();
+  if !tables(db).await?.migrations {
+    return Ok(embedded);
+  }
+  let applied: Vec<AppliedMigration> = db
+    ._query_raw(raw!(
+      r#"SELECT "migration_name" FROM "_prisma_migrations" WHERE "finished_at" IS NOT NULL AND "rolled_back_at" IS NULL"#
+    ))
+    .exec()
+    .await?;
+  let applied: HashSet<String> = applied.into_iter().map(|x| x.migration_name).collect();
+  Ok(embedded.into_iter().filter(|x| !applied.contains(x)).collect())
+}
+
+/// 应用全部未应用的迁移
+/// 之前通过 db push 创建的数据库没有迁移记录, 先将第一个迁移标记为已应用
+pub async fn migrate(db: &PrismaClient) -> Result<(), DbError> {
+  let tables = tables(db).await?;
+  if !tables.migrations && tables.pushed {
+    tracing::info!("数据库由旧版本创建, 将 {} 标记为已应用", BASELINE_MIGRATION);
+    db._migrate_resolve(BASELINE_MIGRATION).await?;
+  }
+  db._migrate_deploy().await?;
+  Ok(())
+}
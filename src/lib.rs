@@ -1,6 +1,10 @@
+pub mod admin;
 pub mod app_state;
+pub mod db;
 pub mod models;
 #[allow(warnings, unused)]
 pub mod prisma;
+pub mod repo;
+pub mod routes;
 pub mod settings;
 pub mod utils;
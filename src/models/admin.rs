@@ -0,0 +1,664 @@
+/// 每页默认条目数
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+/// 每页最大条目数
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+/// 返回 (页码, 每页条目数, 跳过的条目数)
+pub fn page_params(page: Option<i64>, page_size: Option<i64>) -> (i64, i64, i64) {
+  let page = page.unwrap_or(1).max(1);
+  let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+  (page, page_size, (page - 1) * page_size)
+}
+
+/// 排序方向, 默认为降序
+pub fn sort_order(order: &Option<String>) -> crate::prisma::SortOrder {
+  match order.as_deref() {
+    Some("asc") => crate::prisma::SortOrder::Asc,
+    _ => crate::prisma::SortOrder::Desc,
+  }
+}
+
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  use crate::prisma;
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UserListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    /// id, email, nickname 或 createdAt
+    #[serde(rename = "sort")]
+    pub sort: Option<String>,
+
+    /// asc 或 desc
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+
+    #[serde(rename = "nickname")]
+    pub nickname: Option<String>,
+
+    #[serde(rename = "disabled")]
+    pub disabled: Option<bool>,
+
+    #[serde(rename = "role")]
+    pub role: Option<prisma::Role>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateUserReq {
+    #[serde(rename = "email")]
+    pub email: String,
+
+    #[serde(rename = "nickname")]
+    pub nickname: String,
+
+    #[serde(rename = "password")]
+    pub password: String,
+
+    #[serde(rename = "role")]
+    pub role: Option<prisma::Role>,
+
+    #[serde(rename = "permissions")]
+    pub permissions: Option<Vec<prisma::Permission>>,
+
+    #[serde(rename = "language")]
+    pub language: Option<prisma::Language>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpdateUserReq {
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+
+    #[serde(rename = "nickname")]
+    pub nickname: Option<String>,
+
+    #[serde(rename = "password")]
+    pub password: Option<String>,
+
+    #[serde(rename = "role")]
+    pub role: Option<prisma::Role>,
+
+    #[serde(rename = "permissions")]
+    pub permissions: Option<Vec<prisma::Permission>>,
+
+    #[serde(rename = "language")]
+    pub language: Option<prisma::Language>,
+
+    #[serde(rename = "disabled")]
+    pub disabled: Option<bool>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct ProfileListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    /// id, name 或 createdAt
+    #[serde(rename = "sort")]
+    pub sort: Option<String>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<i64>,
+
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateProfileReq {
+    #[serde(rename = "ownerId")]
+    pub owner_id: i64,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// 不指定时随机生成
+    #[serde(rename = "uuid")]
+    pub uuid: Option<String>,
+
+    #[serde(rename = "uploadableTextures")]
+    pub uploadable_textures: Option<prisma::UploadableTextures>,
+
+    /// 跳过角色名的格式与保留期检查
+    #[serde(rename = "force")]
+    pub force: Option<bool>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpdateProfileReq {
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+
+    /// 跳过新角色名的格式与保留期检查
+    #[serde(rename = "force")]
+    pub force: Option<bool>,
+
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<i64>,
+
+    #[serde(rename = "uploadableTextures")]
+    pub uploadable_textures: Option<prisma::UploadableTextures>,
+
+    /// null 表示清除, 不传表示不修改
+    #[serde(rename = "skinId", default, with = "::prisma_client_rust::serde::double_option")]
+    pub skin_id: Option<Option<i64>>,
+
+    #[serde(rename = "capeId", default, with = "::prisma_client_rust::serde::double_option")]
+    pub cape_id: Option<Option<i64>>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct TextureListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    /// id 或 createdAt
+    #[serde(rename = "sort")]
+    pub sort: Option<String>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    /// 仅用于皮肤
+    #[serde(rename = "model")]
+    pub model: Option<prisma::SkinType>,
+
+    /// 仅用于披风
+    #[serde(rename = "official")]
+    pub official: Option<bool>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpdateSkinReq {
+    #[serde(rename = "model")]
+    pub model: prisma::SkinType,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpdateCapeReq {
+    #[serde(rename = "name", default, with = "::prisma_client_rust::serde::double_option")]
+    pub name: Option<Option<String>>,
+
+    #[serde(rename = "official")]
+    pub official: Option<bool>,
+
+    #[serde(rename = "frameRate", default, with = "::prisma_client_rust::serde::double_option")]
+    pub frame_rate: Option<Option<i32>>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct GrantCapeReq {
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct TokenListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    /// id 或 createdAt
+    #[serde(rename = "sort")]
+    pub sort: Option<String>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<i64>,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    #[serde(rename = "status")]
+    pub status: Option<prisma::TokenStatus>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateTokenReq {
+    #[serde(rename = "ownerId")]
+    pub owner_id: i64,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    #[serde(rename = "clientToken")]
+    pub client_token: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpdateTokenReq {
+    #[serde(rename = "status")]
+    pub status: prisma::TokenStatus,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct SettingListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    #[serde(rename = "userId")]
+    pub user_id: Option<i64>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct BanListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+
+    #[serde(rename = "userId")]
+    pub user_id: Option<i64>,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    /// 只列出仍在生效的封禁
+    #[serde(rename = "active")]
+    pub active: Option<bool>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct BlockedServerListQuery {
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+
+    #[serde(rename = "order")]
+    pub order: Option<String>,
+  }
+
+  /// pattern 与 hash 必须且只能指定一个, 指定 pattern 时由服务器计算哈希
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateBlockedServerReq {
+    #[serde(rename = "pattern")]
+    pub pattern: Option<String>,
+
+    #[serde(rename = "hash")]
+    pub hash: Option<String>,
+
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+  }
+
+  /// userId 与 profileId 必须且只能指定一个
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateBanReq {
+    #[serde(rename = "userId")]
+    pub user_id: Option<i64>,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    #[serde(rename = "reason")]
+    pub reason: String,
+
+    /// 毫秒时间戳, 不指定则永久封禁
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<i64>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct UpsertSettingReq {
+    #[serde(rename = "maxToken")]
+    pub max_token: Option<i64>,
+
+    #[serde(rename = "tokenNeedRefreshDuration")]
+    pub token_need_refresh_duration: Option<i64>,
+
+    #[serde(rename = "tokenInvalidDuration")]
+    pub token_invalid_duration: Option<i64>,
+  }
+}
+
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Page<T> {
+    #[serde(rename = "items")]
+    pub items: Vec<T>,
+
+    #[serde(rename = "page")]
+    pub page: i64,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: i64,
+
+    #[serde(rename = "total")]
+    pub total: i64,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct User {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+
+    #[serde(rename = "nickname")]
+    pub nickname: String,
+
+    #[serde(rename = "email")]
+    pub email: String,
+
+    #[serde(rename = "language")]
+    pub language: prisma::Language,
+
+    #[serde(rename = "role")]
+    pub role: prisma::Role,
+
+    #[serde(rename = "permissions")]
+    pub permissions: Vec<prisma::Permission>,
+
+    #[serde(rename = "disabled")]
+    pub disabled: bool,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl User {
+    pub fn from_query(data: prisma::user::Data) -> Self {
+      Self {
+        id: data.id,
+        uuid: utils::uuid_vec_to_string(data.uuid),
+        nickname: data.nickname,
+        email: data.email,
+        language: data.language,
+        role: data.role,
+        permissions: data.permissions,
+        disabled: data.disabled,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Profile {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "ownerId")]
+    pub owner_id: i64,
+
+    #[serde(rename = "skinId")]
+    pub skin_id: Option<i64>,
+
+    #[serde(rename = "capeId")]
+    pub cape_id: Option<i64>,
+
+    #[serde(rename = "uploadableTextures")]
+    pub uploadable_textures: prisma::UploadableTextures,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl Profile {
+    pub fn from_query(data: prisma::profile::Data) -> Self {
+      Self {
+        id: data.id,
+        uuid: utils::uuid_vec_to_string(data.uuid),
+        name: data.display_name,
+        owner_id: data.owner_id,
+        skin_id: data.skin_id,
+        cape_id: data.cape_id,
+        uploadable_textures: data.uploadable_textures,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Skin {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "hash")]
+    pub hash: String,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "model")]
+    pub model: prisma::SkinType,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl Skin {
+    pub fn from_query(data: prisma::skin::Data, sett: &Settings) -> Self {
+      let hash = utils::texture_vec_to_string(data.hash);
+      Self {
+        id: data.id,
+        url: sett.textures.base.to_owned() + &hash,
+        hash,
+        model: data.model,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Cape {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "hash")]
+    pub hash: String,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+
+    #[serde(rename = "official")]
+    pub official: bool,
+
+    #[serde(rename = "frameRate")]
+    pub frame_rate: Option<i32>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl Cape {
+    pub fn from_query(data: prisma::cape::Data, sett: &Settings) -> Self {
+      let hash = utils::texture_vec_to_string(data.hash);
+      Self {
+        id: data.id,
+        url: sett.textures.base.to_owned() + &hash,
+        hash,
+        name: data.name,
+        official: data.official,
+        frame_rate: data.frame_rate,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Token {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    #[serde(rename = "clientToken")]
+    pub client_token: String,
+
+    #[serde(rename = "ownerId")]
+    pub owner_id: i64,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    #[serde(rename = "status")]
+    pub status: prisma::TokenStatus,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl Token {
+    pub fn from_query(data: prisma::token::Data) -> Self {
+      Self {
+        id: data.id,
+        access_token: data.access_token,
+        client_token: data.client_token,
+        owner_id: data.owner_id,
+        profile_id: data.profile_id,
+        status: data.status,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Setting {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+
+    #[serde(rename = "maxToken")]
+    pub max_token: i64,
+
+    #[serde(rename = "tokenNeedRefreshDuration")]
+    pub token_need_refresh_duration: i64,
+
+    #[serde(rename = "tokenInvalidDuration")]
+    pub token_invalid_duration: i64,
+  }
+
+  impl Setting {
+    pub fn from_query(data: prisma::setting::Data) -> Self {
+      Self {
+        id: data.id,
+        user_id: data.user_id,
+        max_token: data.max_token,
+        token_need_refresh_duration: data.token_need_refresh_duration,
+        token_invalid_duration: data.token_invalid_duration,
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Ban {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "userId")]
+    pub user_id: Option<i64>,
+
+    #[serde(rename = "profileId")]
+    pub profile_id: Option<i64>,
+
+    #[serde(rename = "reason")]
+    pub reason: String,
+
+    #[serde(rename = "issuerId")]
+    pub issuer_id: Option<i64>,
+
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<i64>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl Ban {
+    pub fn from_query(data: prisma::ban::Data) -> Self {
+      Self {
+        id: data.id,
+        user_id: data.user_id,
+        profile_id: data.profile_id,
+        reason: data.reason,
+        issuer_id: data.issuer_id,
+        expires_at: data.expires_at.map(|x| x.timestamp_millis()),
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct BlockedServer {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "hash")]
+    pub hash: String,
+
+    #[serde(rename = "pattern")]
+    pub pattern: Option<String>,
+
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl BlockedServer {
+    pub fn from_query(data: prisma::blocked_server::Data) -> Self {
+      Self {
+        id: data.id,
+        hash: data.hash,
+        pattern: data.pattern,
+        reason: data.reason,
+        created_at: data.created_at.timestamp_millis(),
+      }
+    }
+  }
+}
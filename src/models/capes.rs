@@ -0,0 +1,43 @@
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct EntitledCape {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "reason")]
+    pub reason: Option<String>,
+
+    #[serde(rename = "grantedAt")]
+    pub granted_at: i64,
+  }
+
+  impl EntitledCape {
+    /// 需要同时查询 cape
+    pub fn from_query(data: prisma::cape_entitlement::Data, sett: &Settings) -> Option<Self> {
+      let cape = data.cape().ok()?;
+      Some(Self {
+        id: cape.id,
+        name: cape.name.clone(),
+        url: sett.textures.base.to_owned() + &utils::texture_vec_to_string(cape.hash.clone()),
+        reason: data.reason.clone(),
+        granted_at: data.created_at.timestamp_millis(),
+      })
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct EntitledCapesResp {
+    #[serde(rename = "capes")]
+    pub capes: Vec<EntitledCape>,
+  }
+}
@@ -0,0 +1,77 @@
+pub mod resp {
+  use base64::Engine;
+  use rsa::pkcs8::EncodePublicKey;
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct KeyPair {
+    #[serde(rename = "privateKey")]
+    pub private_key: String,
+
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CertificatesResp {
+    #[serde(rename = "keyPair")]
+    pub key_pair: KeyPair,
+
+    #[serde(rename = "publicKeySignature")]
+    pub public_key_signature: String,
+
+    #[serde(rename = "publicKeySignatureV2")]
+    pub public_key_signature_v2: String,
+
+    #[serde(rename = "expiresAt")]
+    pub expires_at: String,
+
+    #[serde(rename = "refreshedAfter")]
+    pub refreshed_after: String,
+  }
+
+  impl CertificatesResp {
+    pub fn from_query(data: prisma::profile_key::Data) -> Self {
+      Self {
+        key_pair: KeyPair { private_key: data.private_key, public_key: data.public_key },
+        public_key_signature: data.legacy_key_signature,
+        public_key_signature_v2: data.public_key_signature,
+        expires_at: data.expires_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        refreshed_after: data.refreshed_after.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+      }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct PublicKey {
+    /// DER 格式公钥的 Base64 编码
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct PublicKeysResp {
+    #[serde(rename = "profilePropertyKeys")]
+    pub profile_property_keys: Vec<PublicKey>,
+
+    #[serde(rename = "playerCertificateKeys")]
+    pub player_certificate_keys: Vec<PublicKey>,
+  }
+
+  impl PublicKeysResp {
+    /// 角色属性与玩家证书都使用同一个签名密钥, 未配置私钥时返回空列表
+    pub fn from_settings(sett: &Settings) -> Self {
+      let keys: Vec<PublicKey> = sett
+        .signature
+        .pubkey_obj
+        .as_ref()
+        .and_then(|x| x.to_public_key_der().ok())
+        .map(|x| PublicKey { public_key: utils::base64().encode(x.as_bytes()) })
+        .into_iter()
+        .collect();
+      Self { profile_property_keys: keys.clone(), player_certificate_keys: keys }
+    }
+  }
+}
@@ -78,6 +78,114 @@ impl Error {
     }
   }
 
+  /// 未提供令牌, 或令牌无效
+  pub fn new_unauthorized() -> Self {
+    Self {
+      cause: None,
+      error: "Unauthorized".to_owned(),
+      error_message: "Invalid or missing access token.".to_owned(),
+      status_code: axum::http::StatusCode::UNAUTHORIZED,
+    }
+  }
+
+  /// 已认证, 但没有权限进行该操作 (非标准)
+  pub fn new_permission_denied() -> Self {
+    Self {
+      cause: None,
+      error: "ForbiddenOperationException".to_owned(),
+      error_message: "Permission denied.".to_owned(),
+      status_code: axum::http::StatusCode::FORBIDDEN,
+    }
+  }
+
+  /// 材质格式不正确, 或尺寸、大小超出限制
+  pub fn new_invalid_texture(message: &str) -> Self {
+    Self {
+      cause: None,
+      error: "IllegalArgumentException".to_owned(),
+      error_message: message.to_owned(),
+      status_code: axum::http::StatusCode::BAD_REQUEST,
+    }
+  }
+
+  /// 请求参数不正确
+  pub fn new_illegal_argument(message: &str) -> Self {
+    Self {
+      cause: None,
+      error: "IllegalArgumentException".to_owned(),
+      error_message: message.to_owned(),
+      status_code: axum::http::StatusCode::BAD_REQUEST,
+    }
+  }
+
+  /// 角色不允许上传该类型的材质
+  pub fn new_texture_not_uploadable() -> Self {
+    Self {
+      cause: None,
+      error: "ForbiddenOperationException".to_owned(),
+      error_message: "This texture type is not uploadable for the profile.".to_owned(),
+      status_code: axum::http::StatusCode::FORBIDDEN,
+    }
+  }
+
+  /// 与已有的数据冲突, 例如邮箱或角色名已被占用 (非标准)
+  pub fn new_conflict() -> Self {
+    Self {
+      cause: None,
+      error: "IllegalArgumentException".to_owned(),
+      error_message: "The resource conflicts with an existing one.".to_owned(),
+      status_code: axum::http::StatusCode::CONFLICT,
+    }
+  }
+
+  /// 请求的资源不存在 (非标准)
+  pub fn new_not_found() -> Self {
+    Self {
+      cause: None,
+      error: "Not Found".to_owned(),
+      error_message: "The requested resource was not found.".to_owned(),
+      status_code: axum::http::StatusCode::NOT_FOUND,
+    }
+  }
+
+  /// 操作过于频繁, 需要等待冷却时间结束 (非标准)
+  pub fn new_cooldown(remaining_seconds: i64) -> Self {
+    Self {
+      cause: None,
+      error: "ForbiddenOperationException".to_owned(),
+      error_message: format!("This operation is on cooldown. Try again in {} seconds.", remaining_seconds),
+      status_code: axum::http::StatusCode::FORBIDDEN,
+    }
+  }
+
+  /// 用户拥有的角色数量已达到上限 (非标准)
+  pub fn new_profile_limit_reached(limit: i64) -> Self {
+    Self {
+      cause: None,
+      error: "ForbiddenOperationException".to_owned(),
+      error_message: format!("You can have at most {} profiles.", limit),
+      status_code: axum::http::StatusCode::FORBIDDEN,
+    }
+  }
+
+  /// 用户或角色被封禁 (非标准)
+  pub fn new_banned(ban: &crate::prisma::ban::Data) -> Self {
+    let target = match ban.profile_id {
+      Some(_) => "This profile has been suspended",
+      None => "This account has been banned",
+    };
+    let expiry = match ban.expires_at {
+      Some(x) => format!("until {}", x.to_rfc3339()),
+      None => "permanently".to_owned(),
+    };
+    Self {
+      cause: None,
+      error: "ForbiddenOperationException".to_owned(),
+      error_message: format!("{} {}. Reason: {}", target, expiry, ban.reason),
+      status_code: axum::http::StatusCode::FORBIDDEN,
+    }
+  }
+
   pub fn to_response(self) -> ErrorResponse {
     (self.status_code, axum::Json::from(self))
   }
@@ -0,0 +1,144 @@
+/// 每页默认条目数
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+/// 每页最大条目数
+pub const MAX_PAGE_SIZE: i64 = 100;
+/// 每个材质最多的标签数
+pub const MAX_TAGS: usize = 10;
+
+/// 标签统一为小写, 去除空白与重复项
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+  let mut result: Vec<String> = vec![];
+  for tag in tags.into_iter().map(|x| x.trim().to_lowercase()) {
+    if !tag.is_empty() && !result.contains(&tag) {
+      result.push(tag);
+    }
+  }
+  result.truncate(MAX_TAGS);
+  result
+}
+
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct PublishReq {
+    /// 材质库中的材质
+    #[serde(rename = "textureId")]
+    pub texture_id: i64,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "tags", default)]
+    pub tags: Vec<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct SearchQuery {
+    /// 按标题搜索
+    #[serde(rename = "q")]
+    pub q: Option<String>,
+
+    #[serde(rename = "tag")]
+    pub tag: Option<String>,
+
+    /// skin 或 cape
+    #[serde(rename = "type")]
+    pub texture_type: Option<String>,
+
+    /// latest 或 popular
+    #[serde(rename = "sort")]
+    pub sort: Option<String>,
+
+    #[serde(rename = "page")]
+    pub page: Option<i64>,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+  }
+}
+
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct GalleryItem {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "tags")]
+    pub tags: Vec<String>,
+
+    #[serde(rename = "type")]
+    pub texture_type: String,
+
+    #[serde(rename = "model")]
+    pub model: Option<String>,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "uploader")]
+    pub uploader: Option<String>,
+
+    #[serde(rename = "likes")]
+    pub likes: i64,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+  }
+
+  impl GalleryItem {
+    /// 需要同时查询 skin 与 cape, 查询 uploader 时附带上传者昵称
+    pub fn from_query(data: prisma::gallery_item::Data, sett: &Settings) -> Option<Self> {
+      let (texture_type, model, hash) = if let Ok(Some(skin)) = data.skin() {
+        (
+          "skin",
+          Some(
+            match skin.model {
+              prisma::SkinType::Default => "default",
+              prisma::SkinType::Slim => "slim",
+            }
+            .to_owned(),
+          ),
+          utils::texture_vec_to_string(skin.hash.clone()),
+        )
+      } else if let Ok(Some(cape)) = data.cape() {
+        ("cape", None, utils::texture_vec_to_string(cape.hash.clone()))
+      } else {
+        return None;
+      };
+      Some(Self {
+        id: data.id,
+        uploader: data.uploader().ok().map(|x| x.nickname.clone()),
+        title: data.title,
+        tags: data.tags,
+        texture_type: texture_type.to_owned(),
+        model,
+        url: sett.textures.base.to_owned() + &hash,
+        likes: data.likes,
+        created_at: data.created_at.timestamp_millis(),
+      })
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct SearchResp {
+    #[serde(rename = "items")]
+    pub items: Vec<GalleryItem>,
+
+    #[serde(rename = "page")]
+    pub page: i64,
+
+    #[serde(rename = "pageSize")]
+    pub page_size: i64,
+
+    #[serde(rename = "total")]
+    pub total: i64,
+  }
+}
@@ -56,9 +56,11 @@ pub mod resp {
 #[derive(thiserror::Error, Debug)]
 pub enum LoginTransactionError {
   #[error("数据库错误: {0}")]
-  QueryError(#[from] prisma_client_rust::QueryError),
+  RepoError(#[from] crate::repo::RepoError),
   #[error("用户不存在")]
   InvalidUser,
   #[error("密码不正确")]
   WrongPassword,
+  #[error("用户或角色已被封禁")]
+  Banned(crate::prisma::ban::Data),
 }
@@ -16,6 +16,18 @@ pub mod meta_resp {
     #[serde(rename = "implementationVersion")]
     pub implementation_version: String,
     pub links: MetaLinks,
+    #[serde(rename = "feature.non_email_login")]
+    pub feature_non_email_login: bool,
+    #[serde(rename = "feature.legacy_skin_api")]
+    pub feature_legacy_skin_api: bool,
+    #[serde(rename = "feature.no_mojang_namespace")]
+    pub feature_no_mojang_namespace: bool,
+    #[serde(rename = "feature.enable_mojang_anti_features")]
+    pub feature_enable_mojang_anti_features: bool,
+    #[serde(rename = "feature.enable_profile_key")]
+    pub feature_enable_profile_key: bool,
+    #[serde(rename = "feature.username_check")]
+    pub feature_username_check: bool,
   }
 
   #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1,7 +1,15 @@
+pub mod admin;
+pub mod capes;
+pub mod certificates;
 pub mod error;
+pub mod gallery;
 pub mod login;
 pub mod meta;
 pub mod profile;
 pub mod refresh;
+pub mod services;
+pub mod session;
 pub mod textures;
+pub mod token;
 pub mod user;
+pub mod wardrobe;
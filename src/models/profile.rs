@@ -7,14 +7,21 @@ use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 
 use super::textures::ProfileTextures;
-use crate::{prisma, settings::Settings, utils};
+use crate::{
+  prisma,
+  settings::Settings,
+  utils::{self, textures::TextureType},
+};
+
+/// 按名称批量查询角色时, 单次请求最多的名称数量
+pub const BATCH_LOOKUP_LIMIT: usize = 10;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Properties {
   #[serde(rename = "name")]
   pub name: String,
 
-  #[serde(rename = "signature")]
+  #[serde(rename = "signature", skip_serializing_if = "Option::is_none")]
   pub signature: Option<String>,
 
   #[serde(rename = "value")]
@@ -29,7 +36,7 @@ pub struct Profile {
   #[serde(rename = "name")]
   pub name: String,
 
-  #[serde(rename = "properties")]
+  #[serde(rename = "properties", default)]
   pub properties: Vec<Properties>,
 }
 
@@ -51,6 +58,27 @@ impl Profile {
     }
   }
 
+  /// 允许上传披风的角色也可以上传配置中启用的额外材质
+  fn with_extra_uploadable_textures(self: Self, sett: &Settings) -> Self {
+    let extra_types: Vec<&str> =
+      [TextureType::Elytra, TextureType::Ears].iter().filter(|x| x.enabled(sett)).map(|x| x.name()).collect();
+    let x: Vec<Properties> = self
+      .properties
+      .iter()
+      .map(|prop| {
+        let mut p = prop.clone();
+        if p.name == "uploadableTextures" && p.value.split(',').any(|x| x == "cape") {
+          for extra_type in extra_types.iter() {
+            p.value.push(',');
+            p.value.push_str(extra_type);
+          }
+        }
+        p
+      })
+      .collect();
+    Profile { properties: x, ..self }
+  }
+
   pub fn with_textures(self: Self, textures: ProfileTextures) -> Self {
     let mut x = self.clone();
     x.properties.push(Properties { name: "textures".to_owned(), signature: None, value: textures.to_base64() });
@@ -58,15 +86,16 @@ impl Profile {
   }
 
   pub fn with_settings(self: Self, sett: Settings) -> Self {
+    let profile = self.with_extra_uploadable_textures(&sett);
     let prikey = if let Some(prikey) = sett.signature.prikey_obj.clone() {
       prikey
     } else {
-      return self;
+      return profile;
     };
     let sign_key = SigningKey::<Sha1>::new(prikey);
     let mut rng = rand::thread_rng();
     let be = utils::base64();
-    let x: Vec<Properties> = self
+    let x: Vec<Properties> = profile
       .properties
       .iter()
       .map(|prop| {
@@ -76,6 +105,82 @@ impl Profile {
         p
       })
       .collect();
+    Profile { properties: x, ..profile }
+  }
+
+  /// 去掉属性签名, 用于 unsigned=true 的查询
+  pub fn without_signatures(self: Self) -> Self {
+    let x: Vec<Properties> =
+      self.properties.iter().map(|prop| Properties { signature: None, ..prop.clone() }).collect();
     Profile { properties: x, ..self }
   }
 }
+
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct CreateProfileReq {
+    #[serde(rename = "name")]
+    pub name: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct RenameProfileReq {
+    #[serde(rename = "name")]
+    pub name: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct NameLookupQuery {
+    /// 秒级时间戳, 查询在该时间使用此名称的角色
+    #[serde(rename = "at")]
+    pub at: Option<i64>,
+  }
+}
+
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, utils};
+
+  /// 批量查询角色的结果, 不包含角色属性
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct ProfileName {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+  }
+
+  impl ProfileName {
+    pub fn from_query(data: prisma::profile::Data) -> Self {
+      Self { id: utils::uuid_vec_to_string(data.uuid), name: data.display_name }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct NameHistoryEntry {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// 改为该名称的时间 (毫秒时间戳), 最初的名称没有该字段
+    #[serde(rename = "changedToAt", skip_serializing_if = "Option::is_none")]
+    pub changed_to_at: Option<i64>,
+  }
+
+  impl NameHistoryEntry {
+    /// history 需要按改名时间升序排列
+    pub fn from_query(history: Vec<prisma::name_history::Data>, current_name: String) -> Vec<Self> {
+      let mut changed_to_at = None;
+      let mut entries = vec![];
+      for x in history {
+        entries.push(Self { name: x.name, changed_to_at });
+        changed_to_at = Some(x.changed_at.timestamp_millis());
+      }
+      entries.push(Self { name: current_name, changed_to_at });
+      entries
+    }
+  }
+}
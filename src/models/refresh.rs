@@ -43,9 +43,13 @@ pub mod resp {
 #[derive(thiserror::Error, Debug)]
 pub enum RefreshTransactionError {
   #[error("数据库错误: {0}")]
-  QueryError(#[from] prisma_client_rust::QueryError),
+  RepoError(#[from] crate::repo::RepoError),
   #[error("令牌不存在")]
   InvalidToken,
   #[error("角色被重新绑定")]
   ReassignProfile,
+  #[error("角色不属于该用户")]
+  AssignOthersProfile,
+  #[error("用户或角色已被封禁")]
+  Banned(crate::prisma::ban::Data),
 }
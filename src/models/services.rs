@@ -0,0 +1,117 @@
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Skin {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// ACTIVE
+    #[serde(rename = "state")]
+    pub state: String,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    /// CLASSIC 或 SLIM
+    #[serde(rename = "variant")]
+    pub variant: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct Cape {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// ACTIVE 或 INACTIVE
+    #[serde(rename = "state")]
+    pub state: String,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "alias")]
+    pub alias: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct ProfileResp {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "skins")]
+    pub skins: Vec<Skin>,
+
+    #[serde(rename = "capes")]
+    pub capes: Vec<Cape>,
+  }
+
+  impl ProfileResp {
+    /// 需要同时查询 skin 与 cape, entitlements 需要同时查询 cape
+    pub fn from_query(
+      data: prisma::profile::Data,
+      entitlements: Vec<prisma::cape_entitlement::Data>,
+      sett: &Settings,
+    ) -> Self {
+      let url = |hash: &Vec<u8>| sett.textures.base.to_owned() + &utils::texture_vec_to_string(hash.clone());
+      let skins = match data.skin().ok().flatten() {
+        Some(x) => {
+          vec![Skin {
+            id: x.id.to_string(),
+            state: "ACTIVE".to_owned(),
+            url: url(&x.hash),
+            variant: match x.model {
+              prisma::SkinType::Default => "CLASSIC",
+              prisma::SkinType::Slim => "SLIM",
+            }
+            .to_owned(),
+          }]
+        },
+        None => vec![],
+      };
+      // 正在使用的披风与其余已获得授权的官方披风
+      let active = data.cape().ok().flatten();
+      let mut capes: Vec<Cape> = active
+        .iter()
+        .map(|x| Cape { id: x.id.to_string(), state: "ACTIVE".to_owned(), url: url(&x.hash), alias: x.name.clone() })
+        .collect();
+      for cape in entitlements.iter().filter_map(|x| x.cape().ok()) {
+        if active.is_some_and(|x| x.id == cape.id) {
+          continue;
+        }
+        capes.push(Cape {
+          id: cape.id.to_string(),
+          state: "INACTIVE".to_owned(),
+          url: url(&cape.hash),
+          alias: cape.name.clone(),
+        });
+      }
+      Self { id: utils::uuid_vec_to_string(data.uuid), name: data.display_name, skins, capes }
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct NameAvailabilityResp {
+    /// AVAILABLE, DUPLICATE 或 NOT_ALLOWED
+    #[serde(rename = "status")]
+    pub status: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct NameChangeResp {
+    /// 上次改名的时间, 从未改名时为空
+    #[serde(rename = "changedAt")]
+    pub changed_at: Option<String>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+
+    #[serde(rename = "nameChangeAllowed")]
+    pub name_change_allowed: bool,
+  }
+}
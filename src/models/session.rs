@@ -0,0 +1,37 @@
+/// 客户端加入服务器后, 服务端需要在该时间 (秒) 内完成验证
+pub const JOIN_EXPIRE_SECONDS: i64 = 30;
+
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct JoinReq {
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    #[serde(rename = "selectedProfile")]
+    pub selected_profile: String,
+
+    #[serde(rename = "serverId")]
+    pub server_id: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct HasJoinedQuery {
+    #[serde(rename = "username")]
+    pub username: String,
+
+    #[serde(rename = "serverId")]
+    pub server_id: String,
+
+    #[serde(rename = "ip")]
+    pub ip: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct ProfileQuery {
+    /// 为 false 时返回属性签名, 默认不签名
+    #[serde(rename = "unsigned")]
+    pub unsigned: Option<bool>,
+  }
+}
@@ -1,12 +1,20 @@
 use base64::Engine as _;
 use serde::{Deserialize, Serialize};
 
-use crate::{prisma, settings::Settings, utils};
+use crate::{
+  prisma,
+  settings::Settings,
+  utils::{self, textures::TextureType},
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Metadata {
   #[serde(rename = "model")]
   pub model: Option<String>,
+
+  /// 动态披风的帧率, 供播放动态披风的模组使用
+  #[serde(rename = "frameRate", skip_serializing_if = "Option::is_none")]
+  pub frame_rate: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -20,11 +28,17 @@ pub struct Texture {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Textures {
-  #[serde(rename = "cape")]
+  #[serde(rename = "CAPE", skip_serializing_if = "Option::is_none")]
   pub cape: Option<Texture>,
 
-  #[serde(rename = "skin")]
+  #[serde(rename = "SKIN", skip_serializing_if = "Option::is_none")]
   pub skin: Option<Texture>,
+
+  #[serde(rename = "ELYTRA", skip_serializing_if = "Option::is_none")]
+  pub elytra: Option<Texture>,
+
+  #[serde(rename = "EARS", skip_serializing_if = "Option::is_none")]
+  pub ears: Option<Texture>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -47,6 +61,19 @@ impl ProfileTextures {
     let cloned_data = data.clone();
     let skin_data = cloned_data.skin();
     let cape_data = cloned_data.cape();
+    // 额外材质只在查询了 ExtraTexture 时返回
+    let extra_texture = |kind: prisma::ExtraTextureType| {
+      cloned_data
+        .extra_texture()
+        .ok()
+        .and_then(|x| x.iter().find(|t| t.kind == kind))
+        .map(|t| {
+          Texture {
+            metadata: Metadata { model: None, frame_rate: None },
+            url: utils::texture_vec_to_string(t.hash.clone()),
+          }
+        })
+    };
     let now = chrono::Utc::now().timestamp_millis() as u64;
     Self {
       timestamp: now,
@@ -54,7 +81,10 @@ impl ProfileTextures {
       profile_name: data.display_name,
       textures: Textures {
         cape: if let Ok(Some(texture)) = cape_data {
-          Some(Texture { metadata: Metadata { model: None }, url: utils::texture_vec_to_string(texture.hash.clone()) })
+          Some(Texture {
+            metadata: Metadata { model: None, frame_rate: texture.frame_rate },
+            url: utils::texture_vec_to_string(texture.hash.clone()),
+          })
         } else {
           None
         },
@@ -68,33 +98,38 @@ impl ProfileTextures {
                 }
                 .to_owned(),
               ),
+              frame_rate: None,
             },
             url: utils::texture_vec_to_string(texture.hash.clone()),
           })
         } else {
           None
         },
+        elytra: extra_texture(prisma::ExtraTextureType::Elytra),
+        ears: extra_texture(prisma::ExtraTextureType::Ears),
       },
     }
   }
 
   pub fn with_settings(self: Self, sett: Settings) -> Self {
     let base_url = &sett.textures.base;
-    let skin = if let Some(ref skin) = self.textures.skin {
-      let mut skin = skin.clone();
-      skin.url = base_url.to_owned() + &skin.url;
-      Some(skin)
-    } else {
-      None
-    };
-    let cape = if let Some(ref cape) = self.textures.cape {
-      let mut cape = cape.clone();
-      cape.url = base_url.to_owned() + &cape.url;
-      Some(cape)
-    } else {
-      None
+    let with_base_url = |texture: &Option<Texture>| {
+      texture.clone().map(|mut x| {
+        x.url = base_url.to_owned() + &x.url;
+        x
+      })
     };
-    Self { textures: Textures { cape, skin }, ..self }
+    let enabled = |texture_type: TextureType| texture_type.enabled(&sett);
+    Self {
+      textures: Textures {
+        cape: with_base_url(&self.textures.cape),
+        skin: with_base_url(&self.textures.skin),
+        // 未启用的额外材质不会出现在 textures 属性中
+        elytra: with_base_url(&self.textures.elytra).filter(|_| enabled(TextureType::Elytra)),
+        ears: with_base_url(&self.textures.ears).filter(|_| enabled(TextureType::Ears)),
+      },
+      ..self
+    }
   }
 
   pub fn to_base64(self: Self) -> String {
@@ -0,0 +1,22 @@
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  /// 验证令牌与吊销令牌共用的请求体
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct TokenReq {
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    #[serde(rename = "clientToken")]
+    pub client_token: Option<String>,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct SignoutReq {
+    #[serde(rename = "username")]
+    pub username: String,
+
+    #[serde(rename = "password")]
+    pub password: String,
+  }
+}
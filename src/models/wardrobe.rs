@@ -0,0 +1,83 @@
+pub mod req {
+  use serde::{Deserialize, Serialize};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct RenameTextureReq {
+    #[serde(rename = "name")]
+    pub name: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct ApplyTextureReq {
+    #[serde(rename = "textureId")]
+    pub texture_id: i64,
+  }
+}
+
+pub mod resp {
+  use serde::{Deserialize, Serialize};
+
+  use crate::{prisma, settings::Settings, utils};
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct LibraryTexture {
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub texture_type: String,
+
+    #[serde(rename = "model")]
+    pub model: Option<String>,
+
+    #[serde(rename = "hash")]
+    pub hash: String,
+
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "uploadedAt")]
+    pub uploaded_at: i64,
+  }
+
+  impl LibraryTexture {
+    /// 需要同时查询 skin 与 cape, 两者都不存在时返回 None
+    pub fn from_query(data: prisma::user_texture::Data, sett: &Settings) -> Option<Self> {
+      let (texture_type, model, hash) = if let Ok(Some(skin)) = data.skin() {
+        (
+          "skin",
+          Some(
+            match skin.model {
+              prisma::SkinType::Default => "default",
+              prisma::SkinType::Slim => "slim",
+            }
+            .to_owned(),
+          ),
+          utils::texture_vec_to_string(skin.hash.clone()),
+        )
+      } else if let Ok(Some(cape)) = data.cape() {
+        ("cape", None, utils::texture_vec_to_string(cape.hash.clone()))
+      } else {
+        return None;
+      };
+      Some(Self {
+        id: data.id,
+        name: data.name,
+        texture_type: texture_type.to_owned(),
+        model,
+        url: sett.textures.base.to_owned() + &hash,
+        hash,
+        uploaded_at: data.created_at.timestamp_millis(),
+      })
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone)]
+  pub struct LibraryResp {
+    #[serde(rename = "textures")]
+    pub textures: Vec<LibraryTexture>,
+  }
+}
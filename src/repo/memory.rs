@@ -0,0 +1,1281 @@
+//! 内存存储, 用于测试
+//! 外键的级联删除与置空在删除记录时手动处理
+
+use std::{cmp::Ordering, sync::Mutex};
+
+use axum::async_trait;
+use chrono::{DateTime, FixedOffset};
+
+use super::{
+  BanFilter, BanRepo, BanTarget, BlockedServerRepo, CapeUpdate, EntitlementRepo, GalleryFilter, GalleryRepo, JoinRepo,
+  ListParams, NameRules, ProfileFilter, ProfileKeyPair, ProfileRepo, ProfileUpdate, RepoError, RepoResult,
+  SettingUpdate, SortBy, TextureRepo, TokenFilter, TokenRepo, UserFilter, UserRepo, UserUpdate,
+};
+use crate::{prisma, settings, utils::textures::TextureType};
+
+/// 各表只保存记录本身, 关联数据在查询时按需组装
+#[derive(Default)]
+struct Tables {
+  last_id: i64,
+  users: Vec<prisma::user::Data>,
+  settings: Vec<prisma::setting::Data>,
+  profiles: Vec<prisma::profile::Data>,
+  name_history: Vec<prisma::name_history::Data>,
+  profile_keys: Vec<prisma::profile_key::Data>,
+  tokens: Vec<prisma::token::Data>,
+  skins: Vec<prisma::skin::Data>,
+  capes: Vec<prisma::cape::Data>,
+  extra_textures: Vec<prisma::extra_texture::Data>,
+  user_textures: Vec<prisma::user_texture::Data>,
+  gallery_items: Vec<prisma::gallery_item::Data>,
+  gallery_likes: Vec<prisma::gallery_like::Data>,
+  entitlements: Vec<prisma::cape_entitlement::Data>,
+  bans: Vec<prisma::ban::Data>,
+  blocked_servers: Vec<prisma::blocked_server::Data>,
+  join_requests: Vec<prisma::join_request::Data>,
+}
+
+impl Tables {
+  fn next_id(&mut self) -> i64 {
+    self.last_id += 1;
+    self.last_id
+  }
+
+  fn with_textures(&self, profile: &prisma::profile::Data) -> prisma::profile::Data {
+    let mut x = profile.clone();
+    x.skin = Some(profile.skin_id.and_then(|id| self.skins.iter().find(|s| s.id == id)).cloned().map(Box::new));
+    x.cape = Some(profile.cape_id.and_then(|id| self.capes.iter().find(|c| c.id == id)).cloned().map(Box::new));
+    x.extra_texture = Some(self.extra_textures.iter().filter(|t| t.profile_id == profile.id).cloned().collect());
+    x
+  }
+
+  fn with_profiles(&self, user: &prisma::user::Data) -> prisma::user::Data {
+    let mut x = user.clone();
+    x.profile = Some(self.profiles.iter().filter(|p| p.owner_id == user.id).map(|p| self.with_textures(p)).collect());
+    x
+  }
+
+  fn with_owner_and_profile(&self, token: &prisma::token::Data) -> prisma::token::Data {
+    let mut x = token.clone();
+    x.owner = self.users.iter().find(|u| u.id == token.owner_id).cloned().map(Box::new);
+    x.profile = Some(
+      token
+        .profile_id
+        .and_then(|id| self.profiles.iter().find(|p| p.id == id))
+        .map(|p| Box::new(self.with_textures(p))),
+    );
+    x
+  }
+
+  fn with_skin_and_cape(&self, texture: &prisma::user_texture::Data) -> prisma::user_texture::Data {
+    let mut x = texture.clone();
+    x.skin = Some(texture.skin_id.and_then(|id| self.skins.iter().find(|s| s.id == id)).cloned().map(Box::new));
+    x.cape = Some(texture.cape_id.and_then(|id| self.capes.iter().find(|c| c.id == id)).cloned().map(Box::new));
+    x
+  }
+
+  fn with_uploader(&self, item: &prisma::gallery_item::Data) -> prisma::gallery_item::Data {
+    let mut x = item.clone();
+    x.uploader = self.users.iter().find(|u| u.id == item.uploader_id).cloned().map(Box::new);
+    x.skin = Some(item.skin_id.and_then(|id| self.skins.iter().find(|s| s.id == id)).cloned().map(Box::new));
+    x.cape = Some(item.cape_id.and_then(|id| self.capes.iter().find(|c| c.id == id)).cloned().map(Box::new));
+    x
+  }
+
+  fn with_cape(&self, entitlement: &prisma::cape_entitlement::Data) -> prisma::cape_entitlement::Data {
+    let mut x = entitlement.clone();
+    x.cape = self.capes.iter().find(|c| c.id == entitlement.cape_id).cloned().map(Box::new);
+    x
+  }
+
+  fn name_available(&self, name: &str, rules: &NameRules, except_profile: Option<i64>) -> bool {
+    let taken = self
+      .profiles
+      .iter()
+      .any(|x| Some(x.id) != except_profile && name_matches(&x.display_name, name, rules.case_insensitive));
+    // 保留期内只有原角色可以改回该名称
+    let reserved = rules.reserved_since.is_some_and(|since| {
+      // 已删除角色的名称 (profileID 为空) 对所有角色保留
+      self.name_history.iter().any(|x| {
+        (x.profile_id.is_none() || x.profile_id != except_profile)
+          && x.changed_at > since
+          && name_matches(&x.name, name, rules.case_insensitive)
+      })
+    });
+    !taken && !reserved
+  }
+
+  fn last_renamed_at(&self, profile_id: i64) -> Option<DateTime<FixedOffset>> {
+    self.name_history.iter().filter(|x| x.profile_id == Some(profile_id)).map(|x| x.changed_at).max()
+  }
+
+  fn invalidate_tokens(&mut self, f: impl Fn(&prisma::token::Data) -> bool) -> i64 {
+    let mut count = 0;
+    for x in self.tokens.iter_mut().filter(|x| x.status != prisma::TokenStatus::Invalid && f(x)) {
+      x.status = prisma::TokenStatus::Invalid;
+      count += 1;
+    }
+    count
+  }
+
+  fn remove_tokens(&mut self, f: impl Fn(&prisma::token::Data) -> bool) {
+    let (removed, kept) = std::mem::take(&mut self.tokens).into_iter().partition::<Vec<_>, _>(f);
+    self.tokens = kept;
+    self.join_requests.retain(|x| !removed.iter().any(|t| t.access_token == x.access_token));
+  }
+
+  fn remove_gallery_items(&mut self, f: impl Fn(&prisma::gallery_item::Data) -> bool) {
+    let (removed, kept) = std::mem::take(&mut self.gallery_items).into_iter().partition::<Vec<_>, _>(f);
+    self.gallery_items = kept;
+    self.gallery_likes.retain(|x| !removed.iter().any(|i| i.id == x.item_id));
+  }
+
+  fn remove_profiles(&mut self, f: impl Fn(&prisma::profile::Data) -> bool) {
+    let (removed, kept) = std::mem::take(&mut self.profiles).into_iter().partition::<Vec<_>, _>(f);
+    self.profiles = kept;
+    let removed = |id: i64| removed.iter().any(|p| p.id == id);
+    for x in self.name_history.iter_mut().filter(|x| x.profile_id.is_some_and(removed)) {
+      x.profile_id = None;
+    }
+    self.profile_keys.retain(|x| !removed(x.profile_id));
+    self.extra_textures.retain(|x| !removed(x.profile_id));
+    self.bans.retain(|x| !x.profile_id.is_some_and(removed));
+    for x in self.tokens.iter_mut().filter(|x| x.profile_id.is_some_and(removed)) {
+      x.profile_id = None;
+    }
+  }
+}
+
+fn name_matches(a: &str, b: &str, case_insensitive: bool) -> bool {
+  match case_insensitive {
+    true => a.to_lowercase() == b.to_lowercase(),
+    false => a == b,
+  }
+}
+
+/// 排序后按分页参数截取, 返回 (当前页, 总数)
+fn page<T>(mut items: Vec<T>, params: &ListParams, cmp: impl Fn(&T, &T) -> Ordering) -> (Vec<T>, i64) {
+  let total = items.len() as i64;
+  items.sort_by(|a, b| {
+    match params.order {
+      prisma::SortOrder::Asc => cmp(a, b),
+      prisma::SortOrder::Desc => cmp(b, a),
+    }
+  });
+  let items = items.into_iter().skip(params.skip.max(0) as usize).take(params.take.max(0) as usize).collect();
+  (items, total)
+}
+
+/// 冷却中时返回剩余秒数
+fn remaining_cooldown(since: Option<DateTime<FixedOffset>>, cooldown: Option<i64>) -> Option<i64> {
+  let elapsed = chrono::Utc::now().timestamp() - since?.timestamp();
+  cooldown.filter(|x| elapsed < *x).map(|x| x - elapsed)
+}
+
+fn ban_active(ban: &prisma::ban::Data) -> bool {
+  ban.expires_at.map_or(true, |x| x > chrono::Utc::now())
+}
+
+#[derive(Default)]
+pub struct MemoryRepo {
+  tables: Mutex<Tables>,
+}
+
+#[async_trait]
+impl UserRepo for MemoryRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::user::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.users.iter().find(|x| x.id == id).map(|x| t.with_profiles(x)))
+  }
+
+  async fn find_by_email(&self, email: &str) -> RepoResult<Option<prisma::user::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.users.iter().find(|x| x.email == email).map(|x| t.with_profiles(x)))
+  }
+
+  async fn list(&self, filter: UserFilter, params: ListParams) -> RepoResult<(Vec<prisma::user::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let users = t
+      .users
+      .iter()
+      .filter(|x| {
+        filter.email.as_ref().map_or(true, |e| x.email.contains(e.as_str()))
+          && filter.nickname.as_ref().map_or(true, |n| x.nickname.contains(n.as_str()))
+          && filter.disabled.map_or(true, |d| x.disabled == d)
+          && filter.role.map_or(true, |r| x.role == r)
+      })
+      .cloned()
+      .collect();
+    Ok(page(users, &params, |a, b| {
+      match params.sort {
+        SortBy::Email => a.email.cmp(&b.email),
+        SortBy::Nickname => a.nickname.cmp(&b.nickname),
+        SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+        _ => a.id.cmp(&b.id),
+      }
+    }))
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    nickname: String,
+    email: String,
+    password: String,
+  ) -> RepoResult<prisma::user::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if t.users.iter().any(|x| x.email == email) {
+      return Err(RepoError::Conflict("email"));
+    }
+    if t.users.iter().any(|x| x.uuid == uuid) {
+      return Err(RepoError::Conflict("uuid"));
+    }
+    let user = prisma::user::Data {
+      id: t.next_id(),
+      uuid,
+      nickname,
+      email,
+      password,
+      language: prisma::Language::ZhCn,
+      disabled: false,
+      role: prisma::Role::Player,
+      permissions: vec![],
+      profile_deleted_at: None,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+      setting: None,
+      token: None,
+      user_texture: None,
+      gallery_item: None,
+      gallery_like: None,
+      cape_entitlement: None,
+      ban: None,
+      issued_ban: None,
+    };
+    t.users.push(user.clone());
+    Ok(t.with_profiles(&user))
+  }
+
+  async fn update(&self, id: i64, update: UserUpdate) -> RepoResult<prisma::user::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if let Some(email) = &update.email {
+      if t.users.iter().any(|x| x.id != id && x.email == *email) {
+        return Err(RepoError::Conflict("email"));
+      }
+    }
+    let user = t.users.iter_mut().find(|x| x.id == id).ok_or(RepoError::NotFound)?;
+    if let Some(email) = update.email {
+      user.email = email;
+    }
+    if let Some(nickname) = update.nickname {
+      user.nickname = nickname;
+    }
+    if let Some(password) = update.password {
+      user.password = password;
+    }
+    if let Some(role) = update.role {
+      user.role = role;
+    }
+    if let Some(permissions) = update.permissions {
+      user.permissions = permissions;
+    }
+    if let Some(language) = update.language {
+      user.language = language;
+    }
+    if let Some(disabled) = update.disabled {
+      user.disabled = disabled;
+    }
+    let user = user.clone();
+    Ok(t.with_profiles(&user))
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.users.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.users.retain(|x| x.id != id);
+    t.remove_profiles(|x| x.owner_id == id);
+    t.remove_tokens(|x| x.owner_id == id);
+    t.remove_gallery_items(|x| x.uploader_id == id);
+    t.settings.retain(|x| x.user_id != id);
+    t.user_textures.retain(|x| x.owner_id != id);
+    t.gallery_likes.retain(|x| x.user_id != id);
+    t.entitlements.retain(|x| x.user_id != id);
+    t.bans.retain(|x| x.user_id != Some(id));
+    for x in t.bans.iter_mut().filter(|x| x.issuer_id == Some(id)) {
+      x.issuer_id = None;
+    }
+    Ok(())
+  }
+
+  async fn find_setting(&self, user_id: i64) -> RepoResult<Option<prisma::setting::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.settings.iter().find(|x| x.user_id == user_id).cloned())
+  }
+
+  async fn list_settings(
+    &self,
+    user_id: Option<i64>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::setting::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let settings = t.settings.iter().filter(|x| user_id.map_or(true, |u| x.user_id == u)).cloned().collect();
+    Ok(page(settings, &params, |a, b| a.id.cmp(&b.id)))
+  }
+
+  async fn upsert_setting(
+    &self,
+    user_id: i64,
+    update: SettingUpdate,
+    defaults: &settings::Token,
+  ) -> RepoResult<prisma::setting::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.users.iter().any(|x| x.id == user_id) {
+      return Err(RepoError::NotFound);
+    }
+    if let Some(setting) = t.settings.iter_mut().find(|x| x.user_id == user_id) {
+      if let Some(x) = update.max_token {
+        setting.max_token = x;
+      }
+      if let Some(x) = update.token_need_refresh_duration {
+        setting.token_need_refresh_duration = x;
+      }
+      if let Some(x) = update.token_invalid_duration {
+        setting.token_invalid_duration = x;
+      }
+      return Ok(setting.clone());
+    }
+    let setting = prisma::setting::Data {
+      id: t.next_id(),
+      user_id,
+      max_token: update.max_token.unwrap_or(defaults.max),
+      token_need_refresh_duration: update.token_need_refresh_duration.unwrap_or(defaults.refresh_duration),
+      token_invalid_duration: update.token_invalid_duration.unwrap_or(defaults.invalid_duration),
+      user: None,
+    };
+    t.settings.push(setting.clone());
+    Ok(setting)
+  }
+
+  async fn delete_setting(&self, user_id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let count = t.settings.len();
+    t.settings.retain(|x| x.user_id != user_id);
+    match t.settings.len() < count {
+      true => Ok(()),
+      false => Err(RepoError::NotFound),
+    }
+  }
+
+  async fn active_ban(&self, user_id: i64, profile_id: Option<i64>) -> RepoResult<Option<prisma::ban::Data>> {
+    let t = self.tables.lock().unwrap();
+    let mut bans: Vec<&prisma::ban::Data> = t
+      .bans
+      .iter()
+      .filter(|x| ban_active(x) && (x.user_id == Some(user_id) || (profile_id.is_some() && x.profile_id == profile_id)))
+      .collect();
+    // 优先返回用户封禁
+    bans.sort_by_key(|x| x.user_id.is_none());
+    Ok(bans.first().map(|x| (*x).clone()))
+  }
+}
+
+#[async_trait]
+impl ProfileRepo for MemoryRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::profile::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.profiles.iter().find(|x| x.id == id).map(|x| t.with_textures(x)))
+  }
+
+  async fn find_by_uuid(&self, uuid: &[u8]) -> RepoResult<Option<prisma::profile::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.profiles.iter().find(|x| x.uuid == uuid).map(|x| t.with_textures(x)))
+  }
+
+  async fn find_by_name(&self, name: &str, case_insensitive: bool) -> RepoResult<Option<prisma::profile::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.profiles.iter().find(|x| name_matches(&x.display_name, name, case_insensitive)).map(|x| t.with_textures(x)))
+  }
+
+  async fn find_by_names(&self, names: &[String], case_insensitive: bool) -> RepoResult<Vec<prisma::profile::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(
+      t.profiles
+        .iter()
+        .filter(|x| names.iter().any(|name| name_matches(&x.display_name, name, case_insensitive)))
+        .map(|x| t.with_textures(x))
+        .collect(),
+    )
+  }
+
+  async fn find_by_name_at(
+    &self,
+    name: &str,
+    at: DateTime<FixedOffset>,
+    case_insensitive: bool,
+  ) -> RepoResult<Option<prisma::profile::Data>> {
+    let t = self.tables.lock().unwrap();
+    // 在该时间之后才改掉这个名称的角色: 改名记录需要是该时间之后的第一次改名
+    let first_change = |profile_id: i64| {
+      t.name_history
+        .iter()
+        .filter(|x| x.profile_id == Some(profile_id) && x.changed_at > at)
+        .min_by(|a, b| a.changed_at.cmp(&b.changed_at).then(a.id.cmp(&b.id)))
+    };
+    let mut released: Vec<&prisma::name_history::Data> =
+      t.name_history.iter().filter(|x| x.changed_at > at && name_matches(&x.name, name, case_insensitive)).collect();
+    released.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then(a.id.cmp(&b.id)));
+    // 已删除角色的记录不对应任何角色
+    for (x, profile_id) in released.into_iter().filter_map(|x| x.profile_id.map(|id| (x, id))) {
+      let profile = t.profiles.iter().find(|p| p.id == profile_id);
+      if first_change(profile_id).is_some_and(|y| y.id == x.id) && profile.is_some_and(|p| p.created_at <= at) {
+        return Ok(profile.cloned());
+      }
+    }
+    // 当前使用该名称, 且该时间之后没有改过名的角色
+    let current =
+      t.profiles.iter().find(|x| x.created_at <= at && name_matches(&x.display_name, name, case_insensitive));
+    Ok(current.filter(|x| first_change(x.id).is_none()).cloned())
+  }
+
+  async fn list(&self, filter: ProfileFilter, params: ListParams) -> RepoResult<(Vec<prisma::profile::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let profiles = t
+      .profiles
+      .iter()
+      .filter(|x| {
+        filter.owner_id.map_or(true, |o| x.owner_id == o)
+          && filter.name.as_ref().map_or(true, |n| x.display_name.contains(n.as_str()))
+      })
+      .cloned()
+      .collect();
+    Ok(page(profiles, &params, |a, b| {
+      match params.sort {
+        SortBy::Name => a.display_name.cmp(&b.display_name),
+        SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+        _ => a.id.cmp(&b.id),
+      }
+    }))
+  }
+
+  async fn name_available(&self, name: &str, rules: &NameRules, except_profile: Option<i64>) -> RepoResult<bool> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.name_available(name, rules, except_profile))
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    name: String,
+    owner_id: i64,
+    rules: &NameRules,
+    limit: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.users.iter().any(|x| x.id == owner_id) {
+      return Err(RepoError::NotFound);
+    }
+    if let Some(limit) = limit {
+      if t.profiles.iter().filter(|x| x.owner_id == owner_id).count() as i64 >= limit {
+        return Err(RepoError::LimitReached(limit));
+      }
+    }
+    if !t.name_available(&name, rules, None) {
+      return Err(RepoError::Conflict("displayName"));
+    }
+    if t.profiles.iter().any(|x| x.uuid == uuid) {
+      return Err(RepoError::Conflict("uuid"));
+    }
+    let profile = prisma::profile::Data {
+      id: t.next_id(),
+      uuid,
+      owner_id,
+      display_name: name,
+      skin_id: None,
+      cape_id: None,
+      uploadable_textures: prisma::UploadableTextures::SkinAndCape,
+      created_at: chrono::Utc::now().into(),
+      cape: None,
+      owner: None,
+      skin: None,
+      token: None,
+      extra_texture: None,
+      ban: None,
+      name_history: None,
+      profile_key: None,
+    };
+    t.profiles.push(profile.clone());
+    Ok(t.with_textures(&profile))
+  }
+
+  async fn rename(
+    &self,
+    profile_id: i64,
+    name: String,
+    rules: &NameRules,
+    cooldown: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let old_name = match t.profiles.iter().find(|x| x.id == profile_id) {
+      Some(x) => x.display_name.clone(),
+      None => {
+        return Err(RepoError::NotFound);
+      },
+    };
+    if let Some(remaining) = remaining_cooldown(t.last_renamed_at(profile_id), cooldown) {
+      return Err(RepoError::Cooldown(remaining));
+    }
+    if !t.name_available(&name, rules, Some(profile_id)) {
+      return Err(RepoError::Conflict("displayName"));
+    }
+    let history = prisma::name_history::Data {
+      id: t.next_id(),
+      profile_id: Some(profile_id),
+      name: old_name,
+      changed_at: chrono::Utc::now().into(),
+      profile: None,
+    };
+    t.name_history.push(history);
+    let profile = t.profiles.iter_mut().find(|x| x.id == profile_id).ok_or(RepoError::NotFound)?;
+    profile.display_name = name;
+    let profile = profile.clone();
+    t.invalidate_tokens(|x| x.profile_id == Some(profile_id));
+    Ok(t.with_textures(&profile))
+  }
+
+  async fn update(&self, profile_id: i64, update: ProfileUpdate) -> RepoResult<prisma::profile::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if let Some(name) = &update.name {
+      if t.profiles.iter().any(|x| x.id != profile_id && x.display_name == *name) {
+        return Err(RepoError::Conflict("displayName"));
+      }
+    }
+    if update.owner_id.is_some_and(|o| !t.users.iter().any(|x| x.id == o)) {
+      return Err(RepoError::NotFound);
+    }
+    let profile = t.profiles.iter_mut().find(|x| x.id == profile_id).ok_or(RepoError::NotFound)?;
+    if let Some(name) = update.name {
+      profile.display_name = name;
+    }
+    if let Some(owner_id) = update.owner_id {
+      profile.owner_id = owner_id;
+    }
+    if let Some(uploadable_textures) = update.uploadable_textures {
+      profile.uploadable_textures = uploadable_textures;
+    }
+    if let Some(skin_id) = update.skin_id {
+      profile.skin_id = skin_id;
+    }
+    if let Some(cape_id) = update.cape_id {
+      profile.cape_id = cape_id;
+    }
+    let profile = profile.clone();
+    Ok(t.with_textures(&profile))
+  }
+
+  async fn delete(&self, profile_id: i64, cooldown: Option<i64>) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let (owner_id, name) = match t.profiles.iter().find(|x| x.id == profile_id) {
+      Some(x) => (x.owner_id, x.display_name.clone()),
+      None => {
+        return Err(RepoError::NotFound);
+      },
+    };
+    if cooldown.is_some() {
+      let deleted_at = t.users.iter().find(|x| x.id == owner_id).and_then(|x| x.profile_deleted_at);
+      if let Some(remaining) = remaining_cooldown(deleted_at, cooldown) {
+        return Err(RepoError::Cooldown(remaining));
+      }
+    }
+    t.invalidate_tokens(|x| x.profile_id == Some(profile_id));
+    // 删除后名称同样在保留期内不能被其他角色使用
+    let history = prisma::name_history::Data {
+      id: t.next_id(),
+      profile_id: Some(profile_id),
+      name,
+      changed_at: chrono::Utc::now().into(),
+      profile: None,
+    };
+    t.name_history.push(history);
+    t.remove_profiles(|x| x.id == profile_id);
+    if cooldown.is_some() {
+      if let Some(owner) = t.users.iter_mut().find(|x| x.id == owner_id) {
+        owner.profile_deleted_at = Some(chrono::Utc::now().into());
+      }
+    }
+    Ok(())
+  }
+
+  async fn set_skin(&self, profile_id: i64, skin_id: Option<i64>) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let profile = t.profiles.iter_mut().find(|x| x.id == profile_id).ok_or(RepoError::NotFound)?;
+    profile.skin_id = skin_id;
+    Ok(())
+  }
+
+  async fn set_cape(&self, profile_id: i64, cape_id: Option<i64>) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let profile = t.profiles.iter_mut().find(|x| x.id == profile_id).ok_or(RepoError::NotFound)?;
+    profile.cape_id = cape_id;
+    Ok(())
+  }
+
+  async fn name_history(&self, profile_id: i64) -> RepoResult<Vec<prisma::name_history::Data>> {
+    let t = self.tables.lock().unwrap();
+    let mut history: Vec<prisma::name_history::Data> =
+      t.name_history.iter().filter(|x| x.profile_id == Some(profile_id)).cloned().collect();
+    history.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then(a.id.cmp(&b.id)));
+    Ok(history)
+  }
+
+  async fn last_renamed_at(&self, profile_id: i64) -> RepoResult<Option<DateTime<FixedOffset>>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.last_renamed_at(profile_id))
+  }
+
+  async fn find_key(&self, profile_id: i64) -> RepoResult<Option<prisma::profile_key::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.profile_keys.iter().find(|x| x.profile_id == profile_id).cloned())
+  }
+
+  async fn save_key(&self, profile_id: i64, key: ProfileKeyPair) -> RepoResult<prisma::profile_key::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.profiles.iter().any(|x| x.id == profile_id) {
+      return Err(RepoError::NotFound);
+    }
+    t.profile_keys.retain(|x| x.profile_id != profile_id);
+    let profile_key = prisma::profile_key::Data {
+      id: t.next_id(),
+      profile_id,
+      private_key: key.private_key,
+      public_key: key.public_key,
+      public_key_signature: key.public_key_signature,
+      legacy_key_signature: key.legacy_key_signature,
+      expires_at: key.expires_at,
+      refreshed_after: key.refreshed_after,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+    };
+    t.profile_keys.push(profile_key.clone());
+    Ok(profile_key)
+  }
+}
+
+#[async_trait]
+impl TokenRepo for MemoryRepo {
+  async fn create(
+    &self,
+    owner_id: i64,
+    profile_id: Option<i64>,
+    access_token: String,
+    client_token: String,
+  ) -> RepoResult<prisma::token::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if t.tokens.iter().any(|x| x.access_token == access_token) {
+      return Err(RepoError::Conflict("accessToken"));
+    }
+    let token = prisma::token::Data {
+      id: t.next_id(),
+      access_token,
+      client_token,
+      owner_id,
+      profile_id,
+      created_at: chrono::Utc::now().into(),
+      status: prisma::TokenStatus::Available,
+      join_request: None,
+      owner: None,
+      profile: None,
+    };
+    t.tokens.push(token.clone());
+    Ok(token)
+  }
+
+  async fn find(&self, access_token: &str, client_token: Option<&str>) -> RepoResult<Option<prisma::token::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(
+      t.tokens
+        .iter()
+        .find(|x| {
+          x.access_token == access_token
+            && x.status != prisma::TokenStatus::Invalid
+            && client_token.map_or(true, |c| x.client_token == c)
+        })
+        .map(|x| t.with_owner_and_profile(x)),
+    )
+  }
+
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::token::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.tokens.iter().find(|x| x.id == id).cloned())
+  }
+
+  async fn list(&self, filter: TokenFilter, params: ListParams) -> RepoResult<(Vec<prisma::token::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let tokens = t
+      .tokens
+      .iter()
+      .filter(|x| {
+        filter.owner_id.map_or(true, |o| x.owner_id == o)
+          && filter.profile_id.map_or(true, |p| x.profile_id == Some(p))
+          && filter.status.map_or(true, |s| x.status == s)
+      })
+      .cloned()
+      .collect();
+    Ok(page(tokens, &params, |a, b| {
+      match params.sort {
+        SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+        _ => a.id.cmp(&b.id),
+      }
+    }))
+  }
+
+  async fn list_by_owner(&self, owner_id: i64, status: prisma::TokenStatus) -> RepoResult<Vec<prisma::token::Data>> {
+    let t = self.tables.lock().unwrap();
+    let mut tokens: Vec<prisma::token::Data> =
+      t.tokens.iter().filter(|x| x.owner_id == owner_id && x.status == status).cloned().collect();
+    tokens.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+    Ok(tokens)
+  }
+
+  async fn set_status(&self, id: i64, status: prisma::TokenStatus) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let token = t.tokens.iter_mut().find(|x| x.id == id).ok_or(RepoError::NotFound)?;
+    token.status = status;
+    Ok(())
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.tokens.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.remove_tokens(|x| x.id == id);
+    Ok(())
+  }
+
+  async fn invalidate(&self, access_token: &str) -> RepoResult<i64> {
+    let mut t = self.tables.lock().unwrap();
+    Ok(t.invalidate_tokens(|x| x.access_token == access_token))
+  }
+
+  async fn invalidate_by_owner(&self, owner_id: i64) -> RepoResult<i64> {
+    let mut t = self.tables.lock().unwrap();
+    Ok(t.invalidate_tokens(|x| x.owner_id == owner_id))
+  }
+
+  async fn invalidate_by_profile(&self, profile_id: i64) -> RepoResult<i64> {
+    let mut t = self.tables.lock().unwrap();
+    Ok(t.invalidate_tokens(|x| x.profile_id == Some(profile_id)))
+  }
+}
+
+#[async_trait]
+impl TextureRepo for MemoryRepo {
+  async fn find_or_create_skin(&self, hash: Vec<u8>, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if let Some(x) = t.skins.iter().find(|x| x.hash == hash && x.model == model) {
+      return Ok(x.clone());
+    }
+    let skin = prisma::skin::Data {
+      id: t.next_id(),
+      hash,
+      model,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+      user_texture: None,
+      gallery_item: None,
+    };
+    t.skins.push(skin.clone());
+    Ok(skin)
+  }
+
+  async fn find_or_create_cape(&self, hash: Vec<u8>, frame_rate: Option<i32>) -> RepoResult<prisma::cape::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if let Some(x) = t.capes.iter().find(|x| x.hash == hash && x.frame_rate == frame_rate && !x.official) {
+      return Ok(x.clone());
+    }
+    let cape = prisma::cape::Data {
+      id: t.next_id(),
+      hash,
+      official: false,
+      name: None,
+      frame_rate,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+      user_texture: None,
+      gallery_item: None,
+      cape_entitlement: None,
+    };
+    t.capes.push(cape.clone());
+    Ok(cape)
+  }
+
+  async fn find_skin(&self, id: i64) -> RepoResult<Option<prisma::skin::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.skins.iter().find(|x| x.id == id).cloned())
+  }
+
+  async fn list_skins(
+    &self,
+    model: Option<prisma::SkinType>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::skin::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let skins = t.skins.iter().filter(|x| model.map_or(true, |m| x.model == m)).cloned().collect();
+    Ok(page(skins, &params, |a, b| {
+      match params.sort {
+        SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+        _ => a.id.cmp(&b.id),
+      }
+    }))
+  }
+
+  async fn set_skin_model(&self, id: i64, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let skin = t.skins.iter_mut().find(|x| x.id == id).ok_or(RepoError::NotFound)?;
+    skin.model = model;
+    Ok(skin.clone())
+  }
+
+  async fn delete_skin(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.skins.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.skins.retain(|x| x.id != id);
+    for x in t.profiles.iter_mut().filter(|x| x.skin_id == Some(id)) {
+      x.skin_id = None;
+    }
+    t.user_textures.retain(|x| x.skin_id != Some(id));
+    t.remove_gallery_items(|x| x.skin_id == Some(id));
+    Ok(())
+  }
+
+  async fn find_cape(&self, id: i64) -> RepoResult<Option<prisma::cape::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.capes.iter().find(|x| x.id == id).cloned())
+  }
+
+  async fn list_capes(&self, official: Option<bool>, params: ListParams) -> RepoResult<(Vec<prisma::cape::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let capes = t.capes.iter().filter(|x| official.map_or(true, |o| x.official == o)).cloned().collect();
+    Ok(page(capes, &params, |a, b| {
+      match params.sort {
+        SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
+        _ => a.id.cmp(&b.id),
+      }
+    }))
+  }
+
+  async fn create_official_cape(
+    &self,
+    hash: Vec<u8>,
+    name: String,
+    frame_rate: Option<i32>,
+  ) -> RepoResult<prisma::cape::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let cape = prisma::cape::Data {
+      id: t.next_id(),
+      hash,
+      official: true,
+      name: Some(name),
+      frame_rate,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+      user_texture: None,
+      gallery_item: None,
+      cape_entitlement: None,
+    };
+    t.capes.push(cape.clone());
+    Ok(cape)
+  }
+
+  async fn update_cape(&self, id: i64, update: CapeUpdate) -> RepoResult<prisma::cape::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let cape = t.capes.iter_mut().find(|x| x.id == id).ok_or(RepoError::NotFound)?;
+    if let Some(name) = update.name {
+      cape.name = name;
+    }
+    if let Some(official) = update.official {
+      cape.official = official;
+    }
+    if let Some(frame_rate) = update.frame_rate {
+      cape.frame_rate = frame_rate;
+    }
+    Ok(cape.clone())
+  }
+
+  async fn delete_cape(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.capes.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.capes.retain(|x| x.id != id);
+    for x in t.profiles.iter_mut().filter(|x| x.cape_id == Some(id)) {
+      x.cape_id = None;
+    }
+    t.user_textures.retain(|x| x.cape_id != Some(id));
+    t.entitlements.retain(|x| x.cape_id != id);
+    t.remove_gallery_items(|x| x.cape_id == Some(id));
+    Ok(())
+  }
+
+  async fn set_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType, hash: Vec<u8>) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    t.extra_textures.retain(|x| !(x.profile_id == profile_id && x.kind == kind));
+    let texture = prisma::extra_texture::Data {
+      id: t.next_id(),
+      profile_id,
+      kind,
+      hash,
+      created_at: chrono::Utc::now().into(),
+      profile: None,
+    };
+    t.extra_textures.push(texture);
+    Ok(())
+  }
+
+  async fn clear_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType) -> RepoResult<i64> {
+    let mut t = self.tables.lock().unwrap();
+    let count = t.extra_textures.len();
+    t.extra_textures.retain(|x| !(x.profile_id == profile_id && x.kind == kind));
+    Ok((count - t.extra_textures.len()) as i64)
+  }
+
+  async fn add_to_library(
+    &self,
+    owner_id: i64,
+    name: String,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    // 已在材质库中的材质只更新名称与时间
+    if let Some(texture) =
+      t.user_textures.iter_mut().find(|x| x.owner_id == owner_id && x.skin_id == skin_id && x.cape_id == cape_id)
+    {
+      texture.name = name;
+      texture.created_at = chrono::Utc::now().into();
+      return Ok(());
+    }
+    let texture = prisma::user_texture::Data {
+      id: t.next_id(),
+      owner_id,
+      name,
+      skin_id,
+      cape_id,
+      created_at: chrono::Utc::now().into(),
+      owner: None,
+      skin: None,
+      cape: None,
+    };
+    t.user_textures.push(texture);
+    Ok(())
+  }
+
+  async fn list_library(&self, owner_id: i64) -> RepoResult<Vec<prisma::user_texture::Data>> {
+    let t = self.tables.lock().unwrap();
+    let mut textures: Vec<prisma::user_texture::Data> =
+      t.user_textures.iter().filter(|x| x.owner_id == owner_id).map(|x| t.with_skin_and_cape(x)).collect();
+    textures.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+    Ok(textures)
+  }
+
+  async fn find_library_texture(&self, owner_id: i64, id: i64) -> RepoResult<Option<prisma::user_texture::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.user_textures.iter().find(|x| x.id == id && x.owner_id == owner_id).map(|x| t.with_skin_and_cape(x)))
+  }
+
+  async fn rename_library_texture(&self, id: i64, name: String) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let texture = t.user_textures.iter_mut().find(|x| x.id == id).ok_or(RepoError::NotFound)?;
+    texture.name = name;
+    Ok(())
+  }
+
+  async fn remove_library_texture(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    t.user_textures.retain(|x| x.id != id);
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl GalleryRepo for MemoryRepo {
+  async fn search(
+    &self,
+    filter: GalleryFilter,
+    skip: i64,
+    take: i64,
+  ) -> RepoResult<(Vec<prisma::gallery_item::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let title = filter.title.map(|x| x.to_lowercase());
+    let items = t
+      .gallery_items
+      .iter()
+      .filter(|x| {
+        title.as_ref().map_or(true, |q| x.title.to_lowercase().contains(q))
+          && filter.tag.as_ref().map_or(true, |tag| x.tags.contains(tag))
+          && match filter.texture_type {
+            Some(TextureType::Skin) => x.skin_id.is_some(),
+            Some(TextureType::Cape) => x.cape_id.is_some(),
+            _ => true,
+          }
+      })
+      .map(|x| t.with_uploader(x))
+      .collect();
+    let params = ListParams { skip, take, sort: SortBy::Id, order: prisma::SortOrder::Desc };
+    Ok(page(items, &params, |a, b| {
+      match filter.popular {
+        true => a.likes.cmp(&b.likes).then(a.id.cmp(&b.id)),
+        false => a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)),
+      }
+    }))
+  }
+
+  async fn find(&self, id: i64) -> RepoResult<Option<prisma::gallery_item::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.gallery_items.iter().find(|x| x.id == id).map(|x| t.with_uploader(x)))
+  }
+
+  async fn publish(
+    &self,
+    uploader_id: i64,
+    title: String,
+    tags: Vec<String>,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<prisma::gallery_item::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let item = prisma::gallery_item::Data {
+      id: t.next_id(),
+      uploader_id,
+      title,
+      tags,
+      skin_id,
+      cape_id,
+      likes: 0,
+      created_at: chrono::Utc::now().into(),
+      uploader: None,
+      skin: None,
+      cape: None,
+      gallery_like: None,
+    };
+    t.gallery_items.push(item.clone());
+    Ok(t.with_uploader(&item))
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    t.remove_gallery_items(|x| x.id == id);
+    Ok(())
+  }
+
+  async fn like(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if t.gallery_likes.iter().any(|x| x.item_id == item_id && x.user_id == user_id) {
+      return Ok(());
+    }
+    let like = prisma::gallery_like::Data {
+      id: t.next_id(),
+      item_id,
+      user_id,
+      created_at: chrono::Utc::now().into(),
+      item: None,
+      user: None,
+    };
+    let item = t.gallery_items.iter_mut().find(|x| x.id == item_id).ok_or(RepoError::NotFound)?;
+    item.likes += 1;
+    t.gallery_likes.push(like);
+    Ok(())
+  }
+
+  async fn unlike(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    let count = t.gallery_likes.len();
+    t.gallery_likes.retain(|x| !(x.item_id == item_id && x.user_id == user_id));
+    let deleted = (count - t.gallery_likes.len()) as i64;
+    if let Some(item) = t.gallery_items.iter_mut().find(|x| x.id == item_id) {
+      item.likes -= deleted;
+    }
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl EntitlementRepo for MemoryRepo {
+  async fn list(&self, user_id: i64) -> RepoResult<Vec<prisma::cape_entitlement::Data>> {
+    let t = self.tables.lock().unwrap();
+    let mut entitlements: Vec<prisma::cape_entitlement::Data> =
+      t.entitlements.iter().filter(|x| x.user_id == user_id).map(|x| t.with_cape(x)).collect();
+    entitlements.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+    Ok(entitlements)
+  }
+
+  async fn find(&self, user_id: i64, cape_id: i64) -> RepoResult<Option<prisma::cape_entitlement::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.entitlements.iter().find(|x| x.user_id == user_id && x.cape_id == cape_id).map(|x| t.with_cape(x)))
+  }
+
+  async fn grant(&self, user_id: i64, cape_id: i64, reason: Option<String>) -> RepoResult<i64> {
+    let mut t = self.tables.lock().unwrap();
+    if t.entitlements.iter().any(|x| x.user_id == user_id && x.cape_id == cape_id) {
+      return Ok(0);
+    }
+    if !t.users.iter().any(|x| x.id == user_id) || !t.capes.iter().any(|x| x.id == cape_id) {
+      return Err(RepoError::NotFound);
+    }
+    let entitlement = prisma::cape_entitlement::Data {
+      id: t.next_id(),
+      user_id,
+      cape_id,
+      reason,
+      created_at: chrono::Utc::now().into(),
+      user: None,
+      cape: None,
+    };
+    t.entitlements.push(entitlement);
+    Ok(1)
+  }
+
+  async fn revoke(&self, user_id: i64, cape_id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.entitlements.iter().any(|x| x.user_id == user_id && x.cape_id == cape_id) {
+      return Err(RepoError::NotFound);
+    }
+    t.entitlements.retain(|x| !(x.user_id == user_id && x.cape_id == cape_id));
+    for x in t.profiles.iter_mut().filter(|x| x.owner_id == user_id && x.cape_id == Some(cape_id)) {
+      x.cape_id = None;
+    }
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl BanRepo for MemoryRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::ban::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.bans.iter().find(|x| x.id == id).cloned())
+  }
+
+  async fn list(&self, filter: BanFilter, params: ListParams) -> RepoResult<(Vec<prisma::ban::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    let bans = t
+      .bans
+      .iter()
+      .filter(|x| {
+        filter.user_id.map_or(true, |u| x.user_id == Some(u))
+          && filter.profile_id.map_or(true, |p| x.profile_id == Some(p))
+          && (!filter.active || ban_active(x))
+      })
+      .cloned()
+      .collect();
+    Ok(page(bans, &params, |a, b| a.id.cmp(&b.id)))
+  }
+
+  async fn issue(
+    &self,
+    target: BanTarget,
+    reason: String,
+    issuer_id: Option<i64>,
+    expires_at: Option<DateTime<FixedOffset>>,
+  ) -> RepoResult<prisma::ban::Data> {
+    let mut t = self.tables.lock().unwrap();
+    let (user_id, profile_id) = match target {
+      BanTarget::User(user_id) => (Some(user_id), None),
+      BanTarget::Profile(profile_id) => (None, Some(profile_id)),
+    };
+    let ban = prisma::ban::Data {
+      id: t.next_id(),
+      user_id,
+      profile_id,
+      reason,
+      issuer_id,
+      expires_at,
+      created_at: chrono::Utc::now().into(),
+      user: None,
+      profile: None,
+      issuer: None,
+    };
+    t.bans.push(ban.clone());
+    match target {
+      BanTarget::User(user_id) => t.invalidate_tokens(|x| x.owner_id == user_id),
+      BanTarget::Profile(profile_id) => t.invalidate_tokens(|x| x.profile_id == Some(profile_id)),
+    };
+    Ok(ban)
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.bans.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.bans.retain(|x| x.id != id);
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl BlockedServerRepo for MemoryRepo {
+  async fn list(&self, params: ListParams) -> RepoResult<(Vec<prisma::blocked_server::Data>, i64)> {
+    let t = self.tables.lock().unwrap();
+    Ok(page(t.blocked_servers.clone(), &params, |a, b| a.id.cmp(&b.id)))
+  }
+
+  async fn create(
+    &self,
+    hash: String,
+    pattern: Option<String>,
+    reason: Option<String>,
+  ) -> RepoResult<prisma::blocked_server::Data> {
+    let mut t = self.tables.lock().unwrap();
+    if t.blocked_servers.iter().any(|x| x.hash == hash) {
+      return Err(RepoError::Conflict("hash"));
+    }
+    let blocked =
+      prisma::blocked_server::Data { id: t.next_id(), hash, pattern, reason, created_at: chrono::Utc::now().into() };
+    t.blocked_servers.push(blocked.clone());
+    Ok(blocked)
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.blocked_servers.iter().any(|x| x.id == id) {
+      return Err(RepoError::NotFound);
+    }
+    t.blocked_servers.retain(|x| x.id != id);
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl JoinRepo for MemoryRepo {
+  async fn upsert(&self, server_id: String, access_token: String, ip: String) -> RepoResult<()> {
+    let mut t = self.tables.lock().unwrap();
+    if !t.tokens.iter().any(|x| x.access_token == access_token) {
+      return Err(RepoError::NotFound);
+    }
+    t.join_requests.retain(|x| x.server_id != server_id);
+    let join_request = prisma::join_request::Data {
+      id: t.next_id(),
+      server_id,
+      access_token,
+      ip,
+      created_at: chrono::Utc::now().into(),
+      token: None,
+    };
+    t.join_requests.push(join_request);
+    Ok(())
+  }
+
+  async fn find(&self, server_id: &str) -> RepoResult<Option<prisma::join_request::Data>> {
+    let t = self.tables.lock().unwrap();
+    Ok(t.join_requests.iter().find(|x| x.server_id == server_id).map(|x| {
+      let mut join_request = x.clone();
+      join_request.token =
+        t.tokens.iter().find(|tk| tk.access_token == x.access_token).map(|tk| Box::new(t.with_owner_and_profile(tk)));
+      join_request
+    }))
+  }
+}
@@ -0,0 +1,575 @@
+//! 存储层接口
+//! 全部接口只通过这里的仓库访问数据, 便于替换存储后端与编写测试
+//! 仓库直接返回 Prisma 生成的数据结构, 查询结果中按各方法的说明加载关联数据
+
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, FixedOffset};
+
+use crate::{
+  prisma::{self, PrismaClient},
+  settings::{self, Settings},
+  utils::textures::TextureType,
+};
+
+pub mod memory;
+pub mod postgres;
+pub mod sqlite;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RepoError {
+  #[error("数据库错误: {0}")]
+  Query(#[from] prisma_client_rust::QueryError),
+  #[error("SQLite 错误: {0}")]
+  Sqlite(#[from] rusqlite::Error),
+  #[error("违反唯一约束: {0}")]
+  Conflict(&'static str),
+  #[error("记录不存在")]
+  NotFound,
+  #[error("数量已达到上限 {0}")]
+  LimitReached(i64),
+  #[error("操作冷却中, 剩余 {0} 秒")]
+  Cooldown(i64),
+  #[error("数据库版本 {0} 比程序支持的版本新")]
+  UnknownVersion(i64),
+}
+
+impl RepoError {
+  /// 是否为唯一约束冲突, 包括数据库直接报告的冲突
+  pub fn is_conflict(&self) -> bool {
+    match self {
+      Self::Conflict(_) => true,
+      Self::Query(err) => err.is_prisma_error::<prisma_client_rust::prisma_errors::query_engine::UniqueKeyViolation>(),
+      Self::Sqlite(rusqlite::Error::SqliteFailure(err, _)) => {
+        err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE
+          || err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+      },
+      _ => false,
+    }
+  }
+}
+
+pub type RepoResult<T> = Result<T, RepoError>;
+
+/// 列表的排序字段, 表中没有的字段按 Id 排序
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+  Id,
+  CreatedAt,
+  Email,
+  Nickname,
+  Name,
+}
+
+/// 分页查询的参数
+#[derive(Debug, Clone)]
+pub struct ListParams {
+  pub skip: i64,
+  pub take: i64,
+  pub sort: SortBy,
+  pub order: prisma::SortOrder,
+}
+
+impl ListParams {
+  /// 按 Id 升序列出全部记录
+  pub fn all() -> Self {
+    Self { skip: 0, take: i64::MAX, sort: SortBy::Id, order: prisma::SortOrder::Asc }
+  }
+}
+
+/// 角色名的占用规则
+#[derive(Debug, Clone, Copy)]
+pub struct NameRules {
+  /// 比较角色名时忽略大小写
+  pub case_insensitive: bool,
+  /// 在该时间之后被改掉的名称仍为原角色保留, 为空时不检查改名记录
+  pub reserved_since: Option<DateTime<FixedOffset>>,
+}
+
+impl NameRules {
+  pub fn new(sett: &Settings) -> Self {
+    let since = chrono::Utc::now() - chrono::Duration::seconds(sett.profiles.name_reserve_duration);
+    Self { case_insensitive: sett.names.case_insensitive, reserved_since: Some(since.into()) }
+  }
+
+  /// 只要求名称与其他角色不完全相同
+  pub fn exact() -> Self {
+    Self { case_insensitive: false, reserved_since: None }
+  }
+}
+
+/// 封禁的对象
+#[derive(Debug, Clone, Copy)]
+pub enum BanTarget {
+  User(i64),
+  Profile(i64),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+  /// 邮箱包含该字符串
+  pub email: Option<String>,
+  /// 昵称包含该字符串
+  pub nickname: Option<String>,
+  pub disabled: Option<bool>,
+  pub role: Option<prisma::Role>,
+}
+
+/// 为空的字段不修改
+#[derive(Debug, Clone, Default)]
+pub struct UserUpdate {
+  pub email: Option<String>,
+  pub nickname: Option<String>,
+  pub password: Option<String>,
+  pub role: Option<prisma::Role>,
+  pub permissions: Option<Vec<prisma::Permission>>,
+  pub language: Option<prisma::Language>,
+  pub disabled: Option<bool>,
+}
+
+/// 为空的字段不修改, 新建时使用配置文件中的值
+#[derive(Debug, Clone, Default)]
+pub struct SettingUpdate {
+  pub max_token: Option<i64>,
+  pub token_need_refresh_duration: Option<i64>,
+  pub token_invalid_duration: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProfileFilter {
+  pub owner_id: Option<i64>,
+  /// 角色名包含该字符串
+  pub name: Option<String>,
+}
+
+/// 为空的字段不修改, 材质为 Some(None) 时清除
+#[derive(Debug, Clone, Default)]
+pub struct ProfileUpdate {
+  pub name: Option<String>,
+  pub owner_id: Option<i64>,
+  pub uploadable_textures: Option<prisma::UploadableTextures>,
+  pub skin_id: Option<Option<i64>>,
+  pub cape_id: Option<Option<i64>>,
+}
+
+/// 角色的聊天签名密钥对
+#[derive(Debug, Clone)]
+pub struct ProfileKeyPair {
+  pub private_key: String,
+  pub public_key: String,
+  pub public_key_signature: String,
+  pub legacy_key_signature: String,
+  pub expires_at: DateTime<FixedOffset>,
+  pub refreshed_after: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenFilter {
+  pub owner_id: Option<i64>,
+  pub profile_id: Option<i64>,
+  pub status: Option<prisma::TokenStatus>,
+}
+
+/// 为空的字段不修改
+#[derive(Debug, Clone, Default)]
+pub struct CapeUpdate {
+  pub name: Option<Option<String>>,
+  pub official: Option<bool>,
+  pub frame_rate: Option<Option<i32>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GalleryFilter {
+  /// 标题包含该字符串, 忽略大小写
+  pub title: Option<String>,
+  /// 小写的标签
+  pub tag: Option<String>,
+  /// 只列出皮肤或披风
+  pub texture_type: Option<TextureType>,
+  /// 按点赞数排序, 否则按发布时间排序
+  pub popular: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BanFilter {
+  pub user_id: Option<i64>,
+  pub profile_id: Option<i64>,
+  /// 只列出仍在生效的封禁
+  pub active: bool,
+}
+
+/// 返回的用户加载了全部角色, 角色加载了皮肤、披风与额外材质; 列表不加载关联数据
+#[async_trait]
+pub trait UserRepo: Send + Sync {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::user::Data>>;
+
+  async fn find_by_email(&self, email: &str) -> RepoResult<Option<prisma::user::Data>>;
+
+  /// 返回 (当前页, 总数)
+  async fn list(&self, filter: UserFilter, params: ListParams) -> RepoResult<(Vec<prisma::user::Data>, i64)>;
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    nickname: String,
+    email: String,
+    password: String,
+  ) -> RepoResult<prisma::user::Data>;
+
+  async fn update(&self, id: i64, update: UserUpdate) -> RepoResult<prisma::user::Data>;
+
+  /// 角色、令牌、材质库等数据随用户一并删除
+  async fn delete(&self, id: i64) -> RepoResult<()>;
+
+  /// 用户单独设置的令牌数量与有效期
+  async fn find_setting(&self, user_id: i64) -> RepoResult<Option<prisma::setting::Data>>;
+
+  async fn list_settings(
+    &self,
+    user_id: Option<i64>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::setting::Data>, i64)>;
+
+  async fn upsert_setting(
+    &self,
+    user_id: i64,
+    update: SettingUpdate,
+    defaults: &settings::Token,
+  ) -> RepoResult<prisma::setting::Data>;
+
+  async fn delete_setting(&self, user_id: i64) -> RepoResult<()>;
+
+  /// 查询当前生效的封禁, 同时检查用户与其正在使用的角色
+  async fn active_ban(&self, user_id: i64, profile_id: Option<i64>) -> RepoResult<Option<prisma::ban::Data>>;
+}
+
+/// 返回的角色加载了皮肤、披风与额外材质; 列表与按时间查询的结果不加载关联数据
+#[async_trait]
+pub trait ProfileRepo: Send + Sync {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::profile::Data>>;
+
+  async fn find_by_uuid(&self, uuid: &[u8]) -> RepoResult<Option<prisma::profile::Data>>;
+
+  async fn find_by_name(&self, name: &str, case_insensitive: bool) -> RepoResult<Option<prisma::profile::Data>>;
+
+  async fn find_by_names(&self, names: &[String], case_insensitive: bool) -> RepoResult<Vec<prisma::profile::Data>>;
+
+  /// 查询在指定时间使用该名称的角色
+  async fn find_by_name_at(
+    &self,
+    name: &str,
+    at: DateTime<FixedOffset>,
+    case_insensitive: bool,
+  ) -> RepoResult<Option<prisma::profile::Data>>;
+
+  /// 返回 (当前页, 总数)
+  async fn list(&self, filter: ProfileFilter, params: ListParams) -> RepoResult<(Vec<prisma::profile::Data>, i64)>;
+
+  /// 角色名未被占用, 且不在其他角色的保留期内
+  async fn name_available(&self, name: &str, rules: &NameRules, except_profile: Option<i64>) -> RepoResult<bool>;
+
+  /// 在同一事务中检查角色数量上限与角色名, 超过上限时返回 LimitReached, 名称不可用时返回 Conflict
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    name: String,
+    owner_id: i64,
+    rules: &NameRules,
+    limit: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data>;
+
+  /// 修改角色名, 记录旧名称并吊销绑定到该角色的令牌
+  /// 指定冷却时间 (秒) 时, 距上次改名不足该时间返回 Cooldown
+  async fn rename(
+    &self,
+    profile_id: i64,
+    name: String,
+    rules: &NameRules,
+    cooldown: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data>;
+
+  async fn update(&self, profile_id: i64, update: ProfileUpdate) -> RepoResult<prisma::profile::Data>;
+
+  /// 删除角色并吊销绑定到它的令牌, 名称记入改名记录, 在保留期内不能被其他角色使用
+  /// 指定冷却时间 (秒) 时, 距所属用户上次删除角色不足该时间返回 Cooldown, 删除后记录删除时间
+  async fn delete(&self, profile_id: i64, cooldown: Option<i64>) -> RepoResult<()>;
+
+  async fn set_skin(&self, profile_id: i64, skin_id: Option<i64>) -> RepoResult<()>;
+
+  async fn set_cape(&self, profile_id: i64, cape_id: Option<i64>) -> RepoResult<()>;
+
+  /// 按改名时间升序排列
+  async fn name_history(&self, profile_id: i64) -> RepoResult<Vec<prisma::name_history::Data>>;
+
+  async fn last_renamed_at(&self, profile_id: i64) -> RepoResult<Option<DateTime<FixedOffset>>>;
+
+  async fn find_key(&self, profile_id: i64) -> RepoResult<Option<prisma::profile_key::Data>>;
+
+  /// 每个角色只保留一对密钥, 已存在时覆盖
+  async fn save_key(&self, profile_id: i64, key: ProfileKeyPair) -> RepoResult<prisma::profile_key::Data>;
+}
+
+#[async_trait]
+pub trait TokenRepo: Send + Sync {
+  async fn create(
+    &self,
+    owner_id: i64,
+    profile_id: Option<i64>,
+    access_token: String,
+    client_token: String,
+  ) -> RepoResult<prisma::token::Data>;
+
+  /// 查询未失效的令牌, 未提供 clientToken 时只按 accessToken 匹配
+  /// 返回的令牌加载了所属用户 (不含角色) 与绑定的角色
+  async fn find(&self, access_token: &str, client_token: Option<&str>) -> RepoResult<Option<prisma::token::Data>>;
+
+  /// 按 id 查询任意状态的令牌, 不加载关联数据
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::token::Data>>;
+
+  /// 返回 (当前页, 总数), 不加载关联数据
+  async fn list(&self, filter: TokenFilter, params: ListParams) -> RepoResult<(Vec<prisma::token::Data>, i64)>;
+
+  /// 按创建时间倒序列出用户处于某个状态的令牌, 不加载关联数据
+  async fn list_by_owner(&self, owner_id: i64, status: prisma::TokenStatus) -> RepoResult<Vec<prisma::token::Data>>;
+
+  async fn set_status(&self, id: i64, status: prisma::TokenStatus) -> RepoResult<()>;
+
+  /// 删除令牌及其加入服务器的记录
+  async fn delete(&self, id: i64) -> RepoResult<()>;
+
+  /// 吊销令牌, 令牌不存在时不报错, 返回吊销的数量
+  async fn invalidate(&self, access_token: &str) -> RepoResult<i64>;
+
+  /// 吊销用户的全部令牌, 返回吊销的数量
+  async fn invalidate_by_owner(&self, owner_id: i64) -> RepoResult<i64>;
+
+  /// 吊销绑定到角色的全部令牌, 返回吊销的数量
+  async fn invalidate_by_profile(&self, profile_id: i64) -> RepoResult<i64>;
+}
+
+/// 皮肤、披风与用户的材质库
+#[async_trait]
+pub trait TextureRepo: Send + Sync {
+  /// 相同哈希与模型的皮肤只保存一份
+  async fn find_or_create_skin(&self, hash: Vec<u8>, model: prisma::SkinType) -> RepoResult<prisma::skin::Data>;
+
+  /// 相同哈希与帧率的披风只保存一份, 官方披风不参与复用
+  async fn find_or_create_cape(&self, hash: Vec<u8>, frame_rate: Option<i32>) -> RepoResult<prisma::cape::Data>;
+
+  async fn find_skin(&self, id: i64) -> RepoResult<Option<prisma::skin::Data>>;
+
+  async fn list_skins(
+    &self,
+    model: Option<prisma::SkinType>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::skin::Data>, i64)>;
+
+  async fn set_skin_model(&self, id: i64, model: prisma::SkinType) -> RepoResult<prisma::skin::Data>;
+
+  /// 使用该皮肤的角色会被清除皮肤, 材质库与公开材质中的条目一并删除
+  async fn delete_skin(&self, id: i64) -> RepoResult<()>;
+
+  async fn find_cape(&self, id: i64) -> RepoResult<Option<prisma::cape::Data>>;
+
+  async fn list_capes(&self, official: Option<bool>, params: ListParams) -> RepoResult<(Vec<prisma::cape::Data>, i64)>;
+
+  async fn create_official_cape(
+    &self,
+    hash: Vec<u8>,
+    name: String,
+    frame_rate: Option<i32>,
+  ) -> RepoResult<prisma::cape::Data>;
+
+  async fn update_cape(&self, id: i64, update: CapeUpdate) -> RepoResult<prisma::cape::Data>;
+
+  /// 使用该披风的角色会被清除披风, 授权、材质库与公开材质中的条目一并删除
+  async fn delete_cape(&self, id: i64) -> RepoResult<()>;
+
+  /// 每个角色的每种额外材质只保留一份
+  async fn set_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType, hash: Vec<u8>) -> RepoResult<()>;
+
+  /// 返回删除的数量
+  async fn clear_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType) -> RepoResult<i64>;
+
+  /// 将上传的材质加入用户的材质库, 同一材质只保留一条记录
+  async fn add_to_library(
+    &self,
+    owner_id: i64,
+    name: String,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<()>;
+
+  /// 按加入时间倒序排列, 加载了皮肤与披风
+  async fn list_library(&self, owner_id: i64) -> RepoResult<Vec<prisma::user_texture::Data>>;
+
+  /// 只返回属于该用户的材质, 加载了皮肤与披风
+  async fn find_library_texture(&self, owner_id: i64, id: i64) -> RepoResult<Option<prisma::user_texture::Data>>;
+
+  async fn rename_library_texture(&self, id: i64, name: String) -> RepoResult<()>;
+
+  /// 只从材质库中移除, 正在使用该材质的角色不受影响
+  async fn remove_library_texture(&self, id: i64) -> RepoResult<()>;
+}
+
+/// 返回的公开材质加载了上传者、皮肤与披风
+#[async_trait]
+pub trait GalleryRepo: Send + Sync {
+  /// 返回 (当前页, 总数)
+  async fn search(
+    &self,
+    filter: GalleryFilter,
+    skip: i64,
+    take: i64,
+  ) -> RepoResult<(Vec<prisma::gallery_item::Data>, i64)>;
+
+  async fn find(&self, id: i64) -> RepoResult<Option<prisma::gallery_item::Data>>;
+
+  /// skinId 与 capeId 只设置其中一个
+  async fn publish(
+    &self,
+    uploader_id: i64,
+    title: String,
+    tags: Vec<String>,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<prisma::gallery_item::Data>;
+
+  async fn delete(&self, id: i64) -> RepoResult<()>;
+
+  /// 重复点赞不会重复计数
+  async fn like(&self, item_id: i64, user_id: i64) -> RepoResult<()>;
+
+  async fn unlike(&self, item_id: i64, user_id: i64) -> RepoResult<()>;
+}
+
+/// 官方披风的授权, 返回的授权加载了披风
+#[async_trait]
+pub trait EntitlementRepo: Send + Sync {
+  /// 按授权时间倒序排列
+  async fn list(&self, user_id: i64) -> RepoResult<Vec<prisma::cape_entitlement::Data>>;
+
+  async fn find(&self, user_id: i64, cape_id: i64) -> RepoResult<Option<prisma::cape_entitlement::Data>>;
+
+  /// 已授予时不会重复创建, 返回新授予的数量
+  async fn grant(&self, user_id: i64, cape_id: i64, reason: Option<String>) -> RepoResult<i64>;
+
+  /// 收回官方披风, 并从正在使用它的角色上卸下
+  async fn revoke(&self, user_id: i64, cape_id: i64) -> RepoResult<()>;
+}
+
+#[async_trait]
+pub trait BanRepo: Send + Sync {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::ban::Data>>;
+
+  /// 返回 (当前页, 总数), 按 Id 排序
+  async fn list(&self, filter: BanFilter, params: ListParams) -> RepoResult<(Vec<prisma::ban::Data>, i64)>;
+
+  /// 创建封禁并立即吊销对象的全部令牌, 签发者为空表示通过 API 密钥封禁
+  async fn issue(
+    &self,
+    target: BanTarget,
+    reason: String,
+    issuer_id: Option<i64>,
+    expires_at: Option<DateTime<FixedOffset>>,
+  ) -> RepoResult<prisma::ban::Data>;
+
+  /// 解除封禁, 已失效的令牌不会恢复
+  async fn delete(&self, id: i64) -> RepoResult<()>;
+}
+
+#[async_trait]
+pub trait BlockedServerRepo: Send + Sync {
+  /// 返回 (当前页, 总数), 按 Id 排序
+  async fn list(&self, params: ListParams) -> RepoResult<(Vec<prisma::blocked_server::Data>, i64)>;
+
+  async fn create(
+    &self,
+    hash: String,
+    pattern: Option<String>,
+    reason: Option<String>,
+  ) -> RepoResult<prisma::blocked_server::Data>;
+
+  async fn delete(&self, id: i64) -> RepoResult<()>;
+}
+
+/// 返回的加入请求加载了令牌, 令牌的加载方式与 TokenRepo::find 相同
+#[async_trait]
+pub trait JoinRepo: Send + Sync {
+  /// 同一个 serverId 只保留最新的请求
+  async fn upsert(&self, server_id: String, access_token: String, ip: String) -> RepoResult<()>;
+
+  async fn find(&self, server_id: &str) -> RepoResult<Option<prisma::join_request::Data>>;
+}
+
+/// 同时实现全部仓库的存储后端
+pub trait Backend:
+  UserRepo
+  + ProfileRepo
+  + TokenRepo
+  + TextureRepo
+  + GalleryRepo
+  + EntitlementRepo
+  + BanRepo
+  + BlockedServerRepo
+  + JoinRepo
+  + 'static
+{
+}
+
+impl<T> Backend for T where
+  T: UserRepo
+    + ProfileRepo
+    + TokenRepo
+    + TextureRepo
+    + GalleryRepo
+    + EntitlementRepo
+    + BanRepo
+    + BlockedServerRepo
+    + JoinRepo
+    + 'static
+{
+}
+
+#[derive(Clone)]
+pub struct Repos {
+  pub users: Arc<dyn UserRepo>,
+  pub profiles: Arc<dyn ProfileRepo>,
+  pub tokens: Arc<dyn TokenRepo>,
+  pub textures: Arc<dyn TextureRepo>,
+  pub gallery: Arc<dyn GalleryRepo>,
+  pub entitlements: Arc<dyn EntitlementRepo>,
+  pub bans: Arc<dyn BanRepo>,
+  pub blocked_servers: Arc<dyn BlockedServerRepo>,
+  pub joins: Arc<dyn JoinRepo>,
+}
+
+impl Repos {
+  pub fn from_backend<B: Backend>(backend: B) -> Self {
+    let backend = Arc::new(backend);
+    Self {
+      users: backend.clone(),
+      profiles: backend.clone(),
+      tokens: backend.clone(),
+      textures: backend.clone(),
+      gallery: backend.clone(),
+      entitlements: backend.clone(),
+      bans: backend.clone(),
+      blocked_servers: backend.clone(),
+      joins: backend,
+    }
+  }
+
+  pub fn postgres(db: Arc<PrismaClient>) -> Self {
+    Self::from_backend(postgres::PrismaRepo::new(db))
+  }
+
+  pub fn sqlite(path: &str) -> RepoResult<Self> {
+    Ok(Self::from_backend(sqlite::SqliteRepo::open(path)?))
+  }
+
+  pub fn memory() -> Self {
+    Self::from_backend(memory::MemoryRepo::default())
+  }
+}
@@ -0,0 +1,1427 @@
+//! 基于 Prisma 的 PostgreSQL 存储
+
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::{DateTime, FixedOffset};
+
+use super::{
+  BanFilter, BanRepo, BanTarget, BlockedServerRepo, CapeUpdate, EntitlementRepo, GalleryFilter, GalleryRepo, JoinRepo,
+  ListParams, NameRules, ProfileFilter, ProfileKeyPair, ProfileRepo, ProfileUpdate, RepoError, RepoResult,
+  SettingUpdate, SortBy, TextureRepo, TokenFilter, TokenRepo, UserFilter, UserRepo, UserUpdate,
+};
+use crate::{
+  prisma::{self, PrismaClient},
+  settings,
+  utils::textures::TextureType,
+};
+
+pub struct PrismaRepo {
+  db: Arc<PrismaClient>,
+}
+
+impl PrismaRepo {
+  pub fn new(db: Arc<PrismaClient>) -> Self {
+    Self { db }
+  }
+}
+
+fn user_profiles() -> prisma::user::profile::Fetch {
+  prisma::user::profile::fetch(vec![])
+    .with(prisma::profile::skin::fetch())
+    .with(prisma::profile::cape::fetch())
+    .with(prisma::profile::extra_texture::fetch(vec![]))
+}
+
+fn token_profile() -> prisma::token::profile::Fetch {
+  prisma::token::profile::fetch()
+    .with(prisma::profile::skin::fetch())
+    .with(prisma::profile::cape::fetch())
+    .with(prisma::profile::extra_texture::fetch(vec![]))
+}
+
+/// 仍在生效的封禁: 永久封禁或尚未到期
+fn ban_active() -> prisma::ban::WhereParam {
+  prisma::ban::WhereParam::Or(vec![
+    prisma::ban::expires_at::equals(None),
+    prisma::ban::expires_at::gt(chrono::Utc::now().into()),
+  ])
+}
+
+/// 冷却中时返回剩余秒数
+fn remaining_cooldown(since: Option<DateTime<FixedOffset>>, cooldown: Option<i64>) -> Option<i64> {
+  let elapsed = chrono::Utc::now().timestamp() - since?.timestamp();
+  cooldown.filter(|x| elapsed < *x).map(|x| x - elapsed)
+}
+
+async fn invalidate_owner_tokens(cli: &PrismaClient, owner_id: i64) -> RepoResult<i64> {
+  Ok(
+    cli
+      .token()
+      .update_many(
+        vec![prisma::token::owner_id::equals(owner_id), prisma::token::status::not(prisma::TokenStatus::Invalid)],
+        vec![prisma::token::status::set(prisma::TokenStatus::Invalid)],
+      )
+      .exec()
+      .await?,
+  )
+}
+
+async fn invalidate_profile_tokens(cli: &PrismaClient, profile_id: i64) -> RepoResult<i64> {
+  Ok(
+    cli
+      .token()
+      .update_many(
+        vec![
+          prisma::token::profile_id::equals(Some(profile_id)),
+          prisma::token::status::not(prisma::TokenStatus::Invalid),
+        ],
+        vec![prisma::token::status::set(prisma::TokenStatus::Invalid)],
+      )
+      .exec()
+      .await?,
+  )
+}
+
+async fn name_available(
+  cli: &PrismaClient,
+  name: &str,
+  rules: &NameRules,
+  except_profile: Option<i64>,
+) -> RepoResult<bool> {
+  let mut filters = vec![prisma::profile::display_name::equals(name.to_owned())];
+  if rules.case_insensitive {
+    filters.push(prisma::profile::display_name::mode(prisma::QueryMode::Insensitive));
+  }
+  if let Some(profile_id) = except_profile {
+    filters.push(prisma::profile::id::not(profile_id));
+  }
+  if cli.profile().count(filters).exec().await? > 0 {
+    return Ok(false);
+  }
+  // 保留期内只有原角色可以改回该名称
+  let since = match rules.reserved_since {
+    Some(x) => x,
+    None => {
+      return Ok(true);
+    },
+  };
+  let mut filters =
+    vec![prisma::name_history::name::equals(name.to_owned()), prisma::name_history::changed_at::gt(since)];
+  if rules.case_insensitive {
+    filters.push(prisma::name_history::name::mode(prisma::QueryMode::Insensitive));
+  }
+  // 已删除角色的名称 (profileID 为空) 对所有角色保留
+  if let Some(profile_id) = except_profile {
+    filters.push(prisma::name_history::WhereParam::Or(vec![
+      prisma::name_history::profile_id::equals(None),
+      prisma::name_history::profile_id::not(Some(profile_id)),
+    ]));
+  }
+  Ok(cli.name_history().count(filters).exec().await? == 0)
+}
+
+async fn last_renamed_at(cli: &PrismaClient, profile_id: i64) -> RepoResult<Option<DateTime<FixedOffset>>> {
+  let last = cli
+    .name_history()
+    .find_first(vec![prisma::name_history::profile_id::equals(Some(profile_id))])
+    .order_by(prisma::name_history::changed_at::order(prisma::SortOrder::Desc))
+    .exec()
+    .await?;
+  Ok(last.map(|x| x.changed_at))
+}
+
+async fn find_profile(cli: &PrismaClient, id: i64) -> RepoResult<Option<prisma::profile::Data>> {
+  Ok(
+    cli
+      .profile()
+      .find_unique(prisma::profile::id::equals(id))
+      .with(prisma::profile::skin::fetch())
+      .with(prisma::profile::cape::fetch())
+      .with(prisma::profile::extra_texture::fetch(vec![]))
+      .exec()
+      .await?,
+  )
+}
+
+#[async_trait]
+impl UserRepo for PrismaRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::user::Data>> {
+    Ok(self.db.user().find_unique(prisma::user::id::equals(id)).with(user_profiles()).exec().await?)
+  }
+
+  async fn find_by_email(&self, email: &str) -> RepoResult<Option<prisma::user::Data>> {
+    Ok(self.db.user().find_unique(prisma::user::email::equals(email.to_owned())).with(user_profiles()).exec().await?)
+  }
+
+  async fn list(&self, filter: UserFilter, params: ListParams) -> RepoResult<(Vec<prisma::user::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(email) = filter.email {
+      filters.push(prisma::user::email::contains(email));
+    }
+    if let Some(nickname) = filter.nickname {
+      filters.push(prisma::user::nickname::contains(nickname));
+    }
+    if let Some(disabled) = filter.disabled {
+      filters.push(prisma::user::disabled::equals(disabled));
+    }
+    if let Some(role) = filter.role {
+      filters.push(prisma::user::role::equals(role));
+    }
+    let order = match params.sort {
+      SortBy::Email => prisma::user::email::order(params.order),
+      SortBy::Nickname => prisma::user::nickname::order(params.order),
+      SortBy::CreatedAt => prisma::user::created_at::order(params.order),
+      _ => prisma::user::id::order(params.order),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.user().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.user().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    nickname: String,
+    email: String,
+    password: String,
+  ) -> RepoResult<prisma::user::Data> {
+    Ok(self.db.user().create(uuid, nickname, email, password, vec![]).with(user_profiles()).exec().await?)
+  }
+
+  async fn update(&self, id: i64, update: UserUpdate) -> RepoResult<prisma::user::Data> {
+    let mut params = vec![];
+    if let Some(email) = update.email {
+      params.push(prisma::user::email::set(email));
+    }
+    if let Some(nickname) = update.nickname {
+      params.push(prisma::user::nickname::set(nickname));
+    }
+    if let Some(password) = update.password {
+      params.push(prisma::user::password::set(password));
+    }
+    if let Some(role) = update.role {
+      params.push(prisma::user::role::set(role));
+    }
+    if let Some(permissions) = update.permissions {
+      params.push(prisma::user::permissions::set(permissions));
+    }
+    if let Some(language) = update.language {
+      params.push(prisma::user::language::set(language));
+    }
+    if let Some(disabled) = update.disabled {
+      params.push(prisma::user::disabled::set(disabled));
+    }
+    if self.db.user().find_unique(prisma::user::id::equals(id)).exec().await?.is_none() {
+      return Err(RepoError::NotFound);
+    }
+    Ok(self.db.user().update(prisma::user::id::equals(id), params).with(user_profiles()).exec().await?)
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    // 角色、令牌、材质库等数据由外键级联删除
+    match self.db.user().delete_many(vec![prisma::user::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+
+  async fn find_setting(&self, user_id: i64) -> RepoResult<Option<prisma::setting::Data>> {
+    Ok(self.db.setting().find_unique(prisma::setting::user_id::equals(user_id)).exec().await?)
+  }
+
+  async fn list_settings(
+    &self,
+    user_id: Option<i64>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::setting::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(user_id) = user_id {
+      filters.push(prisma::setting::user_id::equals(user_id));
+    }
+    let order = prisma::setting::id::order(params.order);
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.setting().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.setting().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn upsert_setting(
+    &self,
+    user_id: i64,
+    update: SettingUpdate,
+    defaults: &settings::Token,
+  ) -> RepoResult<prisma::setting::Data> {
+    let create = vec![
+      prisma::setting::max_token::set(update.max_token.unwrap_or(defaults.max)),
+      prisma::setting::token_need_refresh_duration::set(
+        update.token_need_refresh_duration.unwrap_or(defaults.refresh_duration),
+      ),
+      prisma::setting::token_invalid_duration::set(update.token_invalid_duration.unwrap_or(defaults.invalid_duration)),
+    ];
+    let mut params = vec![];
+    if let Some(x) = update.max_token {
+      params.push(prisma::setting::max_token::set(x));
+    }
+    if let Some(x) = update.token_need_refresh_duration {
+      params.push(prisma::setting::token_need_refresh_duration::set(x));
+    }
+    if let Some(x) = update.token_invalid_duration {
+      params.push(prisma::setting::token_invalid_duration::set(x));
+    }
+    Ok(
+      self
+        .db
+        .setting()
+        .upsert(
+          prisma::setting::user_id::equals(user_id),
+          prisma::setting::create(prisma::user::id::equals(user_id), create),
+          params,
+        )
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn delete_setting(&self, user_id: i64) -> RepoResult<()> {
+    match self.db.setting().delete_many(vec![prisma::setting::user_id::equals(user_id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+
+  async fn active_ban(&self, user_id: i64, profile_id: Option<i64>) -> RepoResult<Option<prisma::ban::Data>> {
+    let mut targets = vec![prisma::ban::user_id::equals(Some(user_id))];
+    if let Some(profile_id) = profile_id {
+      targets.push(prisma::ban::profile_id::equals(Some(profile_id)));
+    }
+    Ok(
+      self
+        .db
+        .ban()
+        .find_first(vec![prisma::ban::WhereParam::Or(targets), ban_active()])
+        // 优先返回用户封禁
+        .order_by(prisma::ban::user_id::order(prisma::SortOrder::Asc))
+        .exec()
+        .await?,
+    )
+  }
+}
+
+#[async_trait]
+impl ProfileRepo for PrismaRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::profile::Data>> {
+    find_profile(&self.db, id).await
+  }
+
+  async fn find_by_uuid(&self, uuid: &[u8]) -> RepoResult<Option<prisma::profile::Data>> {
+    Ok(
+      self
+        .db
+        .profile()
+        .find_unique(prisma::profile::uuid::equals(uuid.to_vec()))
+        .with(prisma::profile::skin::fetch())
+        .with(prisma::profile::cape::fetch())
+        .with(prisma::profile::extra_texture::fetch(vec![]))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn find_by_name(&self, name: &str, case_insensitive: bool) -> RepoResult<Option<prisma::profile::Data>> {
+    let mut filters = vec![prisma::profile::display_name::equals(name.to_owned())];
+    if case_insensitive {
+      filters.push(prisma::profile::display_name::mode(prisma::QueryMode::Insensitive));
+    }
+    Ok(
+      self
+        .db
+        .profile()
+        .find_first(filters)
+        .with(prisma::profile::skin::fetch())
+        .with(prisma::profile::cape::fetch())
+        .with(prisma::profile::extra_texture::fetch(vec![]))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn find_by_names(&self, names: &[String], case_insensitive: bool) -> RepoResult<Vec<prisma::profile::Data>> {
+    let mut filters = vec![prisma::profile::display_name::in_vec(names.to_vec())];
+    if case_insensitive {
+      filters.push(prisma::profile::display_name::mode(prisma::QueryMode::Insensitive));
+    }
+    Ok(
+      self
+        .db
+        .profile()
+        .find_many(filters)
+        .with(prisma::profile::skin::fetch())
+        .with(prisma::profile::cape::fetch())
+        .with(prisma::profile::extra_texture::fetch(vec![]))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn find_by_name_at(
+    &self,
+    name: &str,
+    at: DateTime<FixedOffset>,
+    case_insensitive: bool,
+  ) -> RepoResult<Option<prisma::profile::Data>> {
+    // 在该时间之后才改掉这个名称的角色: 改名记录需要是该时间之后的第一次改名
+    let mut filters =
+      vec![prisma::name_history::name::equals(name.to_owned()), prisma::name_history::changed_at::gt(at)];
+    if case_insensitive {
+      filters.push(prisma::name_history::name::mode(prisma::QueryMode::Insensitive));
+    }
+    let released = self
+      .db
+      .name_history()
+      .find_many(filters)
+      .with(prisma::name_history::profile::fetch())
+      .order_by(prisma::name_history::changed_at::order(prisma::SortOrder::Asc))
+      .exec()
+      .await?;
+    for x in released {
+      // 已删除角色的记录不对应任何角色
+      if x.profile_id.is_none() {
+        continue;
+      }
+      let first_change = self
+        .db
+        .name_history()
+        .find_first(vec![
+          prisma::name_history::profile_id::equals(x.profile_id),
+          prisma::name_history::changed_at::gt(at),
+        ])
+        .order_by(prisma::name_history::changed_at::order(prisma::SortOrder::Asc))
+        .exec()
+        .await?;
+      let profile = x.profile().ok().flatten().cloned();
+      if first_change.is_some_and(|y| y.id == x.id) && profile.as_ref().is_some_and(|y| y.created_at <= at) {
+        return Ok(profile);
+      }
+    }
+    // 当前使用该名称, 且该时间之后没有改过名的角色
+    let mut filters =
+      vec![prisma::profile::display_name::equals(name.to_owned()), prisma::profile::created_at::lte(at)];
+    if case_insensitive {
+      filters.push(prisma::profile::display_name::mode(prisma::QueryMode::Insensitive));
+    }
+    match self.db.profile().find_first(filters).exec().await? {
+      Some(x) => {
+        let renamed_after = self
+          .db
+          .name_history()
+          .count(vec![prisma::name_history::profile_id::equals(Some(x.id)), prisma::name_history::changed_at::gt(at)])
+          .exec()
+          .await?;
+        Ok((renamed_after == 0).then_some(x))
+      },
+      None => Ok(None),
+    }
+  }
+
+  async fn list(&self, filter: ProfileFilter, params: ListParams) -> RepoResult<(Vec<prisma::profile::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(owner_id) = filter.owner_id {
+      filters.push(prisma::profile::owner_id::equals(owner_id));
+    }
+    if let Some(name) = filter.name {
+      filters.push(prisma::profile::display_name::contains(name));
+    }
+    let order = match params.sort {
+      SortBy::Name => prisma::profile::display_name::order(params.order),
+      SortBy::CreatedAt => prisma::profile::created_at::order(params.order),
+      _ => prisma::profile::id::order(params.order),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.profile().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.profile().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn name_available(&self, name: &str, rules: &NameRules, except_profile: Option<i64>) -> RepoResult<bool> {
+    name_available(&self.db, name, rules, except_profile).await
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    name: String,
+    owner_id: i64,
+    rules: &NameRules,
+    limit: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let rules = *rules;
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          if cli.user().find_unique(prisma::user::id::equals(owner_id)).exec().await?.is_none() {
+            return Err(RepoError::NotFound);
+          }
+          if let Some(limit) = limit {
+            let count = cli.profile().count(vec![prisma::profile::owner_id::equals(owner_id)]).exec().await?;
+            if count >= limit {
+              return Err(RepoError::LimitReached(limit));
+            }
+          }
+          if !name_available(&cli, &name, &rules, None).await? {
+            return Err(RepoError::Conflict("displayName"));
+          }
+          Ok(
+            cli
+              .profile()
+              .create(uuid, name, prisma::user::id::equals(owner_id), vec![])
+              .with(prisma::profile::skin::fetch())
+              .with(prisma::profile::cape::fetch())
+              .with(prisma::profile::extra_texture::fetch(vec![]))
+              .exec()
+              .await?,
+          )
+        }
+      })
+      .await
+  }
+
+  async fn rename(
+    &self,
+    profile_id: i64,
+    name: String,
+    rules: &NameRules,
+    cooldown: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let rules = *rules;
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let profile = find_profile(&cli, profile_id).await?.ok_or(RepoError::NotFound)?;
+          if let Some(remaining) = remaining_cooldown(last_renamed_at(&cli, profile_id).await?, cooldown) {
+            return Err(RepoError::Cooldown(remaining));
+          }
+          if !name_available(&cli, &name, &rules, Some(profile_id)).await? {
+            return Err(RepoError::Conflict("displayName"));
+          }
+          cli
+            .name_history()
+            .create(
+              profile.display_name,
+              vec![prisma::name_history::profile::connect(prisma::profile::id::equals(profile_id))],
+            )
+            .exec()
+            .await?;
+          cli
+            .profile()
+            .update(prisma::profile::id::equals(profile_id), vec![prisma::profile::display_name::set(name)])
+            .exec()
+            .await?;
+          invalidate_profile_tokens(&cli, profile_id).await?;
+          find_profile(&cli, profile_id).await?.ok_or(RepoError::NotFound)
+        }
+      })
+      .await
+  }
+
+  async fn update(&self, profile_id: i64, update: ProfileUpdate) -> RepoResult<prisma::profile::Data> {
+    let mut params = vec![];
+    if let Some(name) = update.name {
+      params.push(prisma::profile::display_name::set(name));
+    }
+    if let Some(owner_id) = update.owner_id {
+      params.push(prisma::profile::owner::connect(prisma::user::id::equals(owner_id)));
+    }
+    if let Some(uploadable_textures) = update.uploadable_textures {
+      params.push(prisma::profile::uploadable_textures::set(uploadable_textures));
+    }
+    match update.skin_id {
+      Some(Some(skin_id)) => params.push(prisma::profile::skin::connect(prisma::skin::id::equals(skin_id))),
+      Some(None) => params.push(prisma::profile::skin::disconnect()),
+      None => {},
+    }
+    match update.cape_id {
+      Some(Some(cape_id)) => params.push(prisma::profile::cape::connect(prisma::cape::id::equals(cape_id))),
+      Some(None) => params.push(prisma::profile::cape::disconnect()),
+      None => {},
+    }
+    if self.db.profile().find_unique(prisma::profile::id::equals(profile_id)).exec().await?.is_none() {
+      return Err(RepoError::NotFound);
+    }
+    Ok(
+      self
+        .db
+        .profile()
+        .update(prisma::profile::id::equals(profile_id), params)
+        .with(prisma::profile::skin::fetch())
+        .with(prisma::profile::cape::fetch())
+        .with(prisma::profile::extra_texture::fetch(vec![]))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn delete(&self, profile_id: i64, cooldown: Option<i64>) -> RepoResult<()> {
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let profile = cli
+            .profile()
+            .find_unique(prisma::profile::id::equals(profile_id))
+            .with(prisma::profile::owner::fetch())
+            .exec()
+            .await?
+            .ok_or(RepoError::NotFound)?;
+          if cooldown.is_some() {
+            let deleted_at = profile.owner().ok().and_then(|x| x.profile_deleted_at);
+            if let Some(remaining) = remaining_cooldown(deleted_at, cooldown) {
+              return Err(RepoError::Cooldown(remaining));
+            }
+          }
+          invalidate_profile_tokens(&cli, profile_id).await?;
+          // 删除后名称同样在保留期内不能被其他角色使用
+          cli
+            .name_history()
+            .create(
+              profile.display_name.clone(),
+              vec![prisma::name_history::profile::connect(prisma::profile::id::equals(profile_id))],
+            )
+            .exec()
+            .await?;
+          cli.profile().delete(prisma::profile::id::equals(profile_id)).exec().await?;
+          if cooldown.is_some() {
+            cli
+              .user()
+              .update(prisma::user::id::equals(profile.owner_id), vec![prisma::user::profile_deleted_at::set(Some(
+                chrono::Utc::now().into(),
+              ))])
+              .exec()
+              .await?;
+          }
+          Ok(())
+        }
+      })
+      .await
+  }
+
+  async fn set_skin(&self, profile_id: i64, skin_id: Option<i64>) -> RepoResult<()> {
+    let param = match skin_id {
+      Some(x) => prisma::profile::skin::connect(prisma::skin::id::equals(x)),
+      None => prisma::profile::skin::disconnect(),
+    };
+    self.db.profile().update(prisma::profile::id::equals(profile_id), vec![param]).exec().await?;
+    Ok(())
+  }
+
+  async fn set_cape(&self, profile_id: i64, cape_id: Option<i64>) -> RepoResult<()> {
+    let param = match cape_id {
+      Some(x) => prisma::profile::cape::connect(prisma::cape::id::equals(x)),
+      None => prisma::profile::cape::disconnect(),
+    };
+    self.db.profile().update(prisma::profile::id::equals(profile_id), vec![param]).exec().await?;
+    Ok(())
+  }
+
+  async fn name_history(&self, profile_id: i64) -> RepoResult<Vec<prisma::name_history::Data>> {
+    Ok(
+      self
+        .db
+        .name_history()
+        .find_many(vec![prisma::name_history::profile_id::equals(Some(profile_id))])
+        .order_by(prisma::name_history::changed_at::order(prisma::SortOrder::Asc))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn last_renamed_at(&self, profile_id: i64) -> RepoResult<Option<DateTime<FixedOffset>>> {
+    last_renamed_at(&self.db, profile_id).await
+  }
+
+  async fn find_key(&self, profile_id: i64) -> RepoResult<Option<prisma::profile_key::Data>> {
+    Ok(self.db.profile_key().find_unique(prisma::profile_key::profile_id::equals(profile_id)).exec().await?)
+  }
+
+  async fn save_key(&self, profile_id: i64, key: ProfileKeyPair) -> RepoResult<prisma::profile_key::Data> {
+    Ok(
+      self
+        .db
+        .profile_key()
+        .upsert(
+          prisma::profile_key::profile_id::equals(profile_id),
+          prisma::profile_key::create(
+            key.private_key.clone(),
+            key.public_key.clone(),
+            key.public_key_signature.clone(),
+            key.legacy_key_signature.clone(),
+            key.expires_at,
+            key.refreshed_after,
+            prisma::profile::id::equals(profile_id),
+            vec![],
+          ),
+          vec![
+            prisma::profile_key::private_key::set(key.private_key),
+            prisma::profile_key::public_key::set(key.public_key),
+            prisma::profile_key::public_key_signature::set(key.public_key_signature),
+            prisma::profile_key::legacy_key_signature::set(key.legacy_key_signature),
+            prisma::profile_key::expires_at::set(key.expires_at),
+            prisma::profile_key::refreshed_after::set(key.refreshed_after),
+            prisma::profile_key::created_at::set(chrono::Utc::now().into()),
+          ],
+        )
+        .exec()
+        .await?,
+    )
+  }
+}
+
+#[async_trait]
+impl TokenRepo for PrismaRepo {
+  async fn create(
+    &self,
+    owner_id: i64,
+    profile_id: Option<i64>,
+    access_token: String,
+    client_token: String,
+  ) -> RepoResult<prisma::token::Data> {
+    let mut params = vec![];
+    if let Some(profile_id) = profile_id {
+      params.push(prisma::token::profile::connect(prisma::profile::id::equals(profile_id)));
+    }
+    Ok(self.db.token().create(access_token, client_token, prisma::user::id::equals(owner_id), params).exec().await?)
+  }
+
+  async fn find(&self, access_token: &str, client_token: Option<&str>) -> RepoResult<Option<prisma::token::Data>> {
+    let mut filters = vec![
+      prisma::token::access_token::equals(access_token.to_owned()),
+      prisma::token::status::not(prisma::TokenStatus::Invalid),
+    ];
+    if let Some(client_token) = client_token {
+      filters.push(prisma::token::client_token::equals(client_token.to_owned()));
+    }
+    Ok(self.db.token().find_first(filters).with(prisma::token::owner::fetch()).with(token_profile()).exec().await?)
+  }
+
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::token::Data>> {
+    Ok(self.db.token().find_unique(prisma::token::id::equals(id)).exec().await?)
+  }
+
+  async fn list(&self, filter: TokenFilter, params: ListParams) -> RepoResult<(Vec<prisma::token::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(owner_id) = filter.owner_id {
+      filters.push(prisma::token::owner_id::equals(owner_id));
+    }
+    if let Some(profile_id) = filter.profile_id {
+      filters.push(prisma::token::profile_id::equals(Some(profile_id)));
+    }
+    if let Some(status) = filter.status {
+      filters.push(prisma::token::status::equals(status));
+    }
+    let order = match params.sort {
+      SortBy::CreatedAt => prisma::token::created_at::order(params.order),
+      _ => prisma::token::id::order(params.order),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.token().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.token().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn list_by_owner(&self, owner_id: i64, status: prisma::TokenStatus) -> RepoResult<Vec<prisma::token::Data>> {
+    Ok(
+      self
+        .db
+        .token()
+        .find_many(vec![prisma::token::owner_id::equals(owner_id), prisma::token::status::equals(status)])
+        .order_by(prisma::token::created_at::order(prisma::SortOrder::Desc))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn set_status(&self, id: i64, status: prisma::TokenStatus) -> RepoResult<()> {
+    self.db.token().update(prisma::token::id::equals(id), vec![prisma::token::status::set(status)]).exec().await?;
+    Ok(())
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    match self.db.token().delete_many(vec![prisma::token::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+
+  async fn invalidate(&self, access_token: &str) -> RepoResult<i64> {
+    Ok(
+      self
+        .db
+        .token()
+        .update_many(
+          vec![
+            prisma::token::access_token::equals(access_token.to_owned()),
+            prisma::token::status::not(prisma::TokenStatus::Invalid),
+          ],
+          vec![prisma::token::status::set(prisma::TokenStatus::Invalid)],
+        )
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn invalidate_by_owner(&self, owner_id: i64) -> RepoResult<i64> {
+    invalidate_owner_tokens(&self.db, owner_id).await
+  }
+
+  async fn invalidate_by_profile(&self, profile_id: i64) -> RepoResult<i64> {
+    invalidate_profile_tokens(&self.db, profile_id).await
+  }
+}
+
+#[async_trait]
+impl TextureRepo for PrismaRepo {
+  async fn find_or_create_skin(&self, hash: Vec<u8>, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    let skin = self
+      .db
+      .skin()
+      .find_first(vec![prisma::skin::hash::equals(hash.clone()), prisma::skin::model::equals(model)])
+      .exec()
+      .await?;
+    match skin {
+      Some(x) => Ok(x),
+      None => Ok(self.db.skin().create(hash, model, vec![]).exec().await?),
+    }
+  }
+
+  async fn find_or_create_cape(&self, hash: Vec<u8>, frame_rate: Option<i32>) -> RepoResult<prisma::cape::Data> {
+    let cape = self
+      .db
+      .cape()
+      .find_first(vec![
+        prisma::cape::hash::equals(hash.clone()),
+        prisma::cape::frame_rate::equals(frame_rate),
+        prisma::cape::official::equals(false),
+      ])
+      .exec()
+      .await?;
+    match cape {
+      Some(x) => Ok(x),
+      None => Ok(self.db.cape().create(hash, vec![prisma::cape::frame_rate::set(frame_rate)]).exec().await?),
+    }
+  }
+
+  async fn find_skin(&self, id: i64) -> RepoResult<Option<prisma::skin::Data>> {
+    Ok(self.db.skin().find_unique(prisma::skin::id::equals(id)).exec().await?)
+  }
+
+  async fn list_skins(
+    &self,
+    model: Option<prisma::SkinType>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::skin::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(model) = model {
+      filters.push(prisma::skin::model::equals(model));
+    }
+    let order = match params.sort {
+      SortBy::CreatedAt => prisma::skin::created_at::order(params.order),
+      _ => prisma::skin::id::order(params.order),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.skin().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.skin().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn set_skin_model(&self, id: i64, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    if self.db.skin().find_unique(prisma::skin::id::equals(id)).exec().await?.is_none() {
+      return Err(RepoError::NotFound);
+    }
+    Ok(self.db.skin().update(prisma::skin::id::equals(id), vec![prisma::skin::model::set(model)]).exec().await?)
+  }
+
+  async fn delete_skin(&self, id: i64) -> RepoResult<()> {
+    match self.db.skin().delete_many(vec![prisma::skin::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+
+  async fn find_cape(&self, id: i64) -> RepoResult<Option<prisma::cape::Data>> {
+    Ok(self.db.cape().find_unique(prisma::cape::id::equals(id)).exec().await?)
+  }
+
+  async fn list_capes(
+    &self,
+    official: Option<bool>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::cape::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(official) = official {
+      filters.push(prisma::cape::official::equals(official));
+    }
+    let order = match params.sort {
+      SortBy::CreatedAt => prisma::cape::created_at::order(params.order),
+      _ => prisma::cape::id::order(params.order),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.cape().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.cape().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn create_official_cape(
+    &self,
+    hash: Vec<u8>,
+    name: String,
+    frame_rate: Option<i32>,
+  ) -> RepoResult<prisma::cape::Data> {
+    Ok(
+      self
+        .db
+        .cape()
+        .create(hash, vec![
+          prisma::cape::official::set(true),
+          prisma::cape::name::set(Some(name)),
+          prisma::cape::frame_rate::set(frame_rate),
+        ])
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn update_cape(&self, id: i64, update: CapeUpdate) -> RepoResult<prisma::cape::Data> {
+    let mut params = vec![];
+    if let Some(name) = update.name {
+      params.push(prisma::cape::name::set(name));
+    }
+    if let Some(official) = update.official {
+      params.push(prisma::cape::official::set(official));
+    }
+    if let Some(frame_rate) = update.frame_rate {
+      params.push(prisma::cape::frame_rate::set(frame_rate));
+    }
+    if self.db.cape().find_unique(prisma::cape::id::equals(id)).exec().await?.is_none() {
+      return Err(RepoError::NotFound);
+    }
+    Ok(self.db.cape().update(prisma::cape::id::equals(id), params).exec().await?)
+  }
+
+  async fn delete_cape(&self, id: i64) -> RepoResult<()> {
+    match self.db.cape().delete_many(vec![prisma::cape::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+
+  async fn set_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType, hash: Vec<u8>) -> RepoResult<()> {
+    self.clear_extra(profile_id, kind).await?;
+    self.db.extra_texture().create(kind, hash, prisma::profile::id::equals(profile_id), vec![]).exec().await?;
+    Ok(())
+  }
+
+  async fn clear_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType) -> RepoResult<i64> {
+    Ok(
+      self
+        .db
+        .extra_texture()
+        .delete_many(vec![
+          prisma::extra_texture::profile_id::equals(profile_id),
+          prisma::extra_texture::kind::equals(kind),
+        ])
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn add_to_library(
+    &self,
+    owner_id: i64,
+    name: String,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<()> {
+    // 已在材质库中的材质只更新名称与时间
+    let updated = self
+      .db
+      .user_texture()
+      .update_many(
+        vec![
+          prisma::user_texture::owner_id::equals(owner_id),
+          prisma::user_texture::skin_id::equals(skin_id),
+          prisma::user_texture::cape_id::equals(cape_id),
+        ],
+        vec![
+          prisma::user_texture::name::set(name.clone()),
+          prisma::user_texture::created_at::set(chrono::Utc::now().into()),
+        ],
+      )
+      .exec()
+      .await?;
+    if updated > 0 {
+      return Ok(());
+    }
+    let mut params = vec![];
+    if let Some(x) = skin_id {
+      params.push(prisma::user_texture::skin::connect(prisma::skin::id::equals(x)));
+    }
+    if let Some(x) = cape_id {
+      params.push(prisma::user_texture::cape::connect(prisma::cape::id::equals(x)));
+    }
+    self.db.user_texture().create(name, prisma::user::id::equals(owner_id), params).exec().await?;
+    Ok(())
+  }
+
+  async fn list_library(&self, owner_id: i64) -> RepoResult<Vec<prisma::user_texture::Data>> {
+    Ok(
+      self
+        .db
+        .user_texture()
+        .find_many(vec![prisma::user_texture::owner_id::equals(owner_id)])
+        .with(prisma::user_texture::skin::fetch())
+        .with(prisma::user_texture::cape::fetch())
+        .order_by(prisma::user_texture::created_at::order(prisma::SortOrder::Desc))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn find_library_texture(&self, owner_id: i64, id: i64) -> RepoResult<Option<prisma::user_texture::Data>> {
+    Ok(
+      self
+        .db
+        .user_texture()
+        .find_first(vec![prisma::user_texture::id::equals(id), prisma::user_texture::owner_id::equals(owner_id)])
+        .with(prisma::user_texture::skin::fetch())
+        .with(prisma::user_texture::cape::fetch())
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn rename_library_texture(&self, id: i64, name: String) -> RepoResult<()> {
+    self
+      .db
+      .user_texture()
+      .update(prisma::user_texture::id::equals(id), vec![prisma::user_texture::name::set(name)])
+      .exec()
+      .await?;
+    Ok(())
+  }
+
+  async fn remove_library_texture(&self, id: i64) -> RepoResult<()> {
+    self.db.user_texture().delete(prisma::user_texture::id::equals(id)).exec().await?;
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl GalleryRepo for PrismaRepo {
+  async fn search(
+    &self,
+    filter: GalleryFilter,
+    skip: i64,
+    take: i64,
+  ) -> RepoResult<(Vec<prisma::gallery_item::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(title) = filter.title {
+      filters.push(prisma::gallery_item::title::contains(title));
+      filters.push(prisma::gallery_item::title::mode(prisma::QueryMode::Insensitive));
+    }
+    if let Some(tag) = filter.tag {
+      filters.push(prisma::gallery_item::tags::has(tag));
+    }
+    match filter.texture_type {
+      Some(TextureType::Skin) => filters.push(prisma::gallery_item::skin_id::not(None)),
+      Some(TextureType::Cape) => filters.push(prisma::gallery_item::cape_id::not(None)),
+      _ => {},
+    }
+    let order = match filter.popular {
+      true => prisma::gallery_item::likes::order(prisma::SortOrder::Desc),
+      false => prisma::gallery_item::created_at::order(prisma::SortOrder::Desc),
+    };
+    Ok(
+      self
+        .db
+        ._batch((
+          self
+            .db
+            .gallery_item()
+            .find_many(filters.clone())
+            .with(prisma::gallery_item::uploader::fetch())
+            .with(prisma::gallery_item::skin::fetch())
+            .with(prisma::gallery_item::cape::fetch())
+            .order_by(order)
+            .skip(skip)
+            .take(take),
+          self.db.gallery_item().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn find(&self, id: i64) -> RepoResult<Option<prisma::gallery_item::Data>> {
+    Ok(
+      self
+        .db
+        .gallery_item()
+        .find_unique(prisma::gallery_item::id::equals(id))
+        .with(prisma::gallery_item::uploader::fetch())
+        .with(prisma::gallery_item::skin::fetch())
+        .with(prisma::gallery_item::cape::fetch())
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn publish(
+    &self,
+    uploader_id: i64,
+    title: String,
+    tags: Vec<String>,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<prisma::gallery_item::Data> {
+    let mut params = vec![prisma::gallery_item::tags::set(tags)];
+    if let Some(skin_id) = skin_id {
+      params.push(prisma::gallery_item::skin::connect(prisma::skin::id::equals(skin_id)));
+    }
+    if let Some(cape_id) = cape_id {
+      params.push(prisma::gallery_item::cape::connect(prisma::cape::id::equals(cape_id)));
+    }
+    Ok(
+      self
+        .db
+        .gallery_item()
+        .create(title, prisma::user::id::equals(uploader_id), params)
+        .with(prisma::gallery_item::uploader::fetch())
+        .with(prisma::gallery_item::skin::fetch())
+        .with(prisma::gallery_item::cape::fetch())
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self.db.gallery_item().delete(prisma::gallery_item::id::equals(id)).exec().await?;
+    Ok(())
+  }
+
+  async fn like(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let liked = cli
+            .gallery_like()
+            .find_first(vec![
+              prisma::gallery_like::item_id::equals(item_id),
+              prisma::gallery_like::user_id::equals(user_id),
+            ])
+            .exec()
+            .await?;
+          if liked.is_none() {
+            cli
+              .gallery_like()
+              .create(prisma::gallery_item::id::equals(item_id), prisma::user::id::equals(user_id), vec![])
+              .exec()
+              .await?;
+            cli
+              .gallery_item()
+              .update(prisma::gallery_item::id::equals(item_id), vec![prisma::gallery_item::likes::increment(1)])
+              .exec()
+              .await?;
+          }
+          Ok(())
+        }
+      })
+      .await
+  }
+
+  async fn unlike(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let deleted = cli
+            .gallery_like()
+            .delete_many(vec![
+              prisma::gallery_like::item_id::equals(item_id),
+              prisma::gallery_like::user_id::equals(user_id),
+            ])
+            .exec()
+            .await?;
+          if deleted > 0 {
+            cli
+              .gallery_item()
+              .update(prisma::gallery_item::id::equals(item_id), vec![prisma::gallery_item::likes::decrement(deleted)])
+              .exec()
+              .await?;
+          }
+          Ok(())
+        }
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl EntitlementRepo for PrismaRepo {
+  async fn list(&self, user_id: i64) -> RepoResult<Vec<prisma::cape_entitlement::Data>> {
+    Ok(
+      self
+        .db
+        .cape_entitlement()
+        .find_many(vec![prisma::cape_entitlement::user_id::equals(user_id)])
+        .with(prisma::cape_entitlement::cape::fetch())
+        .order_by(prisma::cape_entitlement::created_at::order(prisma::SortOrder::Desc))
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn find(&self, user_id: i64, cape_id: i64) -> RepoResult<Option<prisma::cape_entitlement::Data>> {
+    Ok(
+      self
+        .db
+        .cape_entitlement()
+        .find_first(vec![
+          prisma::cape_entitlement::user_id::equals(user_id),
+          prisma::cape_entitlement::cape_id::equals(cape_id),
+        ])
+        .with(prisma::cape_entitlement::cape::fetch())
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn grant(&self, user_id: i64, cape_id: i64, reason: Option<String>) -> RepoResult<i64> {
+    if EntitlementRepo::find(self, user_id, cape_id).await?.is_some() {
+      return Ok(0);
+    }
+    self
+      .db
+      .cape_entitlement()
+      .create(prisma::user::id::equals(user_id), prisma::cape::id::equals(cape_id), vec![
+        prisma::cape_entitlement::reason::set(reason),
+      ])
+      .exec()
+      .await?;
+    Ok(1)
+  }
+
+  async fn revoke(&self, user_id: i64, cape_id: i64) -> RepoResult<()> {
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let deleted = cli
+            .cape_entitlement()
+            .delete_many(vec![
+              prisma::cape_entitlement::user_id::equals(user_id),
+              prisma::cape_entitlement::cape_id::equals(cape_id),
+            ])
+            .exec()
+            .await?;
+          if deleted == 0 {
+            return Err(RepoError::NotFound);
+          }
+          cli
+            .profile()
+            .update_many(
+              vec![prisma::profile::owner_id::equals(user_id), prisma::profile::cape_id::equals(Some(cape_id))],
+              vec![prisma::profile::cape_id::set(None)],
+            )
+            .exec()
+            .await?;
+          Ok(())
+        }
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl BanRepo for PrismaRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::ban::Data>> {
+    Ok(self.db.ban().find_unique(prisma::ban::id::equals(id)).exec().await?)
+  }
+
+  async fn list(&self, filter: BanFilter, params: ListParams) -> RepoResult<(Vec<prisma::ban::Data>, i64)> {
+    let mut filters = vec![];
+    if let Some(user_id) = filter.user_id {
+      filters.push(prisma::ban::user_id::equals(Some(user_id)));
+    }
+    if let Some(profile_id) = filter.profile_id {
+      filters.push(prisma::ban::profile_id::equals(Some(profile_id)));
+    }
+    if filter.active {
+      filters.push(ban_active());
+    }
+    let order = prisma::ban::id::order(params.order);
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.ban().find_many(filters.clone()).order_by(order).skip(params.skip).take(params.take),
+          self.db.ban().count(filters),
+        ))
+        .await?,
+    )
+  }
+
+  async fn issue(
+    &self,
+    target: BanTarget,
+    reason: String,
+    issuer_id: Option<i64>,
+    expires_at: Option<DateTime<FixedOffset>>,
+  ) -> RepoResult<prisma::ban::Data> {
+    let mut params = vec![prisma::ban::expires_at::set(expires_at)];
+    if let Some(issuer_id) = issuer_id {
+      params.push(prisma::ban::issuer::connect(prisma::user::id::equals(issuer_id)));
+    }
+    params.push(match target {
+      BanTarget::User(user_id) => prisma::ban::user::connect(prisma::user::id::equals(user_id)),
+      BanTarget::Profile(profile_id) => prisma::ban::profile::connect(prisma::profile::id::equals(profile_id)),
+    });
+    // 封禁与吊销令牌在同一事务中完成, 不会留下仍然有效的令牌
+    self
+      .db
+      ._transaction()
+      .run(move |cli| {
+        async move {
+          let ban = cli.ban().create(reason, params).exec().await?;
+          match target {
+            BanTarget::User(user_id) => invalidate_owner_tokens(&cli, user_id).await?,
+            BanTarget::Profile(profile_id) => invalidate_profile_tokens(&cli, profile_id).await?,
+          };
+          Ok(ban)
+        }
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    match self.db.ban().delete_many(vec![prisma::ban::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+}
+
+#[async_trait]
+impl BlockedServerRepo for PrismaRepo {
+  async fn list(&self, params: ListParams) -> RepoResult<(Vec<prisma::blocked_server::Data>, i64)> {
+    let order = prisma::blocked_server::id::order(params.order);
+    Ok(
+      self
+        .db
+        ._batch((
+          self.db.blocked_server().find_many(vec![]).order_by(order).skip(params.skip).take(params.take),
+          self.db.blocked_server().count(vec![]),
+        ))
+        .await?,
+    )
+  }
+
+  async fn create(
+    &self,
+    hash: String,
+    pattern: Option<String>,
+    reason: Option<String>,
+  ) -> RepoResult<prisma::blocked_server::Data> {
+    Ok(
+      self
+        .db
+        .blocked_server()
+        .create(hash, vec![prisma::blocked_server::pattern::set(pattern), prisma::blocked_server::reason::set(reason)])
+        .exec()
+        .await?,
+    )
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    match self.db.blocked_server().delete_many(vec![prisma::blocked_server::id::equals(id)]).exec().await? {
+      0 => Err(RepoError::NotFound),
+      _ => Ok(()),
+    }
+  }
+}
+
+#[async_trait]
+impl JoinRepo for PrismaRepo {
+  async fn upsert(&self, server_id: String, access_token: String, ip: String) -> RepoResult<()> {
+    self
+      .db
+      .join_request()
+      .upsert(
+        prisma::join_request::server_id::equals(server_id.clone()),
+        prisma::join_request::create(
+          server_id,
+          ip.clone(),
+          prisma::token::access_token::equals(access_token.clone()),
+          vec![],
+        ),
+        vec![
+          prisma::join_request::ip::set(ip),
+          prisma::join_request::token::connect(prisma::token::access_token::equals(access_token)),
+          prisma::join_request::created_at::set(chrono::Utc::now().into()),
+        ],
+      )
+      .exec()
+      .await?;
+    Ok(())
+  }
+
+  async fn find(&self, server_id: &str) -> RepoResult<Option<prisma::join_request::Data>> {
+    Ok(
+      self
+        .db
+        .join_request()
+        .find_unique(prisma::join_request::server_id::equals(server_id.to_owned()))
+        .with(prisma::join_request::token::fetch().with(prisma::token::owner::fetch()).with(token_profile()))
+        .exec()
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,1917 @@
+//! 基于单个 SQLite 文件的存储, 适合小型私人服务器
+//! 表结构与 prisma/schema.prisma 保持一致, 枚举按变体名称保存, 列表按 JSON 保存, 时间按 UTC 保存
+//! 所有查询共用一个连接, 在阻塞线程池中持有锁执行
+//! 表结构的变更按 MIGRATIONS 的顺序应用, 已应用的数量记录在 user_version 中
+
+use std::sync::{Arc, Mutex};
+
+use axum::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use rusqlite::{Connection, OpenFlags, OptionalExtension, Params, Row, ToSql};
+use serde::de::DeserializeOwned;
+
+use super::{
+  BanFilter, BanRepo, BanTarget, BlockedServerRepo, CapeUpdate, EntitlementRepo, GalleryFilter, GalleryRepo, JoinRepo,
+  ListParams, NameRules, ProfileFilter, ProfileKeyPair, ProfileRepo, ProfileUpdate, RepoError, RepoResult,
+  SettingUpdate, SortBy, TextureRepo, TokenFilter, TokenRepo, UserFilter, UserRepo, UserUpdate,
+};
+use crate::{prisma, settings, utils::textures::TextureType};
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS "User" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "uuid" BLOB NOT NULL UNIQUE,
+  "nickname" TEXT NOT NULL,
+  "email" TEXT NOT NULL UNIQUE,
+  "password" TEXT NOT NULL,
+  "language" TEXT NOT NULL DEFAULT 'ZH_CN' CHECK ("language" IN ('ZH_CN', 'EN')),
+  "disabled" INTEGER NOT NULL DEFAULT 0,
+  "role" TEXT NOT NULL DEFAULT 'Player' CHECK ("role" IN ('Player', 'Moderator', 'Admin')),
+  "permissions" TEXT NOT NULL DEFAULT '[]',
+  "profileDeletedAt" TEXT,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "Setting" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "userId" INTEGER NOT NULL UNIQUE REFERENCES "User" ("id") ON DELETE CASCADE,
+  "maxToken" INTEGER NOT NULL DEFAULT 10,
+  "tokenNeedRefreshDuration" INTEGER NOT NULL DEFAULT 1296000,
+  "tokenInvalidDuration" INTEGER NOT NULL DEFAULT 432000
+);
+
+CREATE TABLE IF NOT EXISTS "Skin" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "hash" BLOB NOT NULL,
+  "model" TEXT NOT NULL CHECK ("model" IN ('Default', 'Slim')),
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "Cape" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "hash" BLOB NOT NULL,
+  "official" INTEGER NOT NULL DEFAULT 0,
+  "name" TEXT,
+  "frameRate" INTEGER,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "CapeEntitlement" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "userID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "capeID" INTEGER NOT NULL REFERENCES "Cape" ("id") ON DELETE CASCADE,
+  "reason" TEXT,
+  "createdAt" TEXT NOT NULL,
+  UNIQUE ("userID", "capeID")
+);
+
+CREATE TABLE IF NOT EXISTS "Profile" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "uuid" BLOB NOT NULL UNIQUE,
+  "ownerID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "displayName" TEXT NOT NULL UNIQUE,
+  "skinID" INTEGER REFERENCES "Skin" ("id") ON DELETE SET NULL,
+  "capeID" INTEGER REFERENCES "Cape" ("id") ON DELETE SET NULL,
+  "uploadableTextures" TEXT NOT NULL DEFAULT 'SkinAndCape'
+    CHECK ("uploadableTextures" IN ('SkinOnly', 'SkinAndCape', 'None')),
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "ProfileKey" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "profileID" INTEGER NOT NULL UNIQUE REFERENCES "Profile" ("id") ON DELETE CASCADE,
+  "privateKey" TEXT NOT NULL,
+  "publicKey" TEXT NOT NULL,
+  "publicKeySignature" TEXT NOT NULL,
+  "legacyKeySignature" TEXT NOT NULL,
+  "expiresAt" TEXT NOT NULL,
+  "refreshedAfter" TEXT NOT NULL,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "NameHistory" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "profileID" INTEGER REFERENCES "Profile" ("id") ON DELETE SET NULL,
+  "name" TEXT NOT NULL,
+  "changedAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "ExtraTexture" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "profileID" INTEGER NOT NULL REFERENCES "Profile" ("id") ON DELETE CASCADE,
+  "kind" TEXT NOT NULL CHECK ("kind" IN ('Elytra', 'Ears')),
+  "hash" BLOB NOT NULL,
+  "createdAt" TEXT NOT NULL,
+  UNIQUE ("profileID", "kind")
+);
+
+CREATE TABLE IF NOT EXISTS "UserTexture" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "ownerID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "name" TEXT NOT NULL,
+  "skinID" INTEGER REFERENCES "Skin" ("id") ON DELETE CASCADE,
+  "capeID" INTEGER REFERENCES "Cape" ("id") ON DELETE CASCADE,
+  "createdAt" TEXT NOT NULL,
+  UNIQUE ("ownerID", "skinID"),
+  UNIQUE ("ownerID", "capeID")
+);
+
+CREATE TABLE IF NOT EXISTS "GalleryItem" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "uploaderID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "title" TEXT NOT NULL,
+  "tags" TEXT NOT NULL DEFAULT '[]',
+  "skinID" INTEGER REFERENCES "Skin" ("id") ON DELETE CASCADE,
+  "capeID" INTEGER REFERENCES "Cape" ("id") ON DELETE CASCADE,
+  "likes" INTEGER NOT NULL DEFAULT 0,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "GalleryLike" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "itemID" INTEGER NOT NULL REFERENCES "GalleryItem" ("id") ON DELETE CASCADE,
+  "userID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "createdAt" TEXT NOT NULL,
+  UNIQUE ("itemID", "userID")
+);
+
+CREATE TABLE IF NOT EXISTS "Ban" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "userID" INTEGER REFERENCES "User" ("id") ON DELETE CASCADE,
+  "profileID" INTEGER REFERENCES "Profile" ("id") ON DELETE CASCADE,
+  "reason" TEXT NOT NULL,
+  "issuerID" INTEGER REFERENCES "User" ("id") ON DELETE SET NULL,
+  "expiresAt" TEXT,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "Token" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "accessToken" TEXT NOT NULL UNIQUE,
+  "clientToken" TEXT NOT NULL,
+  "ownerID" INTEGER NOT NULL REFERENCES "User" ("id") ON DELETE CASCADE,
+  "profileID" INTEGER REFERENCES "Profile" ("id") ON DELETE SET NULL,
+  "createdAt" TEXT NOT NULL,
+  "status" TEXT NOT NULL DEFAULT 'Available' CHECK ("status" IN ('Available', 'NeedRefresh', 'Invalid'))
+);
+
+CREATE TABLE IF NOT EXISTS "JoinRequest" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "serverID" TEXT NOT NULL UNIQUE,
+  "accessToken" TEXT NOT NULL REFERENCES "Token" ("accessToken") ON DELETE CASCADE,
+  "ip" TEXT NOT NULL,
+  "createdAt" TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "BlockedServer" (
+  "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+  "hash" TEXT NOT NULL UNIQUE,
+  "pattern" TEXT,
+  "reason" TEXT,
+  "createdAt" TEXT NOT NULL
+);
+"#;
+
+/// 按顺序应用的迁移, 数据库的 user_version 记录已应用的数量
+/// 引入版本号之前创建的数据库 user_version 为 0, 第一个迁移只用 IF NOT EXISTS 建表, 可以直接在其上应用
+const MIGRATIONS: &[(&str, &str)] = &[("1_init", SCHEMA)];
+
+/// 按 JSON 保存的列, 包括列表与 Prisma 生成的枚举
+fn get_json<T: DeserializeOwned>(row: &Row, column: &str, value: serde_json::Result<T>) -> rusqlite::Result<T> {
+  value.map_err(|err| {
+    rusqlite::Error::FromSqlConversionFailure(
+      row.as_ref().column_index(column).unwrap_or_default(),
+      rusqlite::types::Type::Text,
+      Box::new(err),
+    )
+  })
+}
+
+/// Prisma 生成的枚举按变体名称保存
+fn get_enum<T: DeserializeOwned>(row: &Row, column: &str) -> rusqlite::Result<T> {
+  let value: String = row.get(column)?;
+  get_json(row, column, serde_json::from_value(serde_json::Value::String(value)))
+}
+
+fn get_list<T: DeserializeOwned>(row: &Row, column: &str) -> rusqlite::Result<T> {
+  let value: String = row.get(column)?;
+  get_json(row, column, serde_json::from_str(&value))
+}
+
+/// 保存与比较时统一使用 UTC, 保证按文本比较的结果与时间顺序一致
+fn utc(time: DateTime<FixedOffset>) -> DateTime<Utc> {
+  time.with_timezone(&Utc)
+}
+
+fn sort_order(order: prisma::SortOrder) -> &'static str {
+  match order {
+    prisma::SortOrder::Asc => "ASC",
+    prisma::SortOrder::Desc => "DESC",
+  }
+}
+
+fn user_from_row(row: &Row) -> rusqlite::Result<prisma::user::Data> {
+  Ok(prisma::user::Data {
+    id: row.get("id")?,
+    uuid: row.get("uuid")?,
+    nickname: row.get("nickname")?,
+    email: row.get("email")?,
+    password: row.get("password")?,
+    language: get_enum(row, "language")?,
+    disabled: row.get("disabled")?,
+    role: get_enum(row, "role")?,
+    permissions: get_list(row, "permissions")?,
+    profile_deleted_at: row.get("profileDeletedAt")?,
+    created_at: row.get("createdAt")?,
+    profile: None,
+    setting: None,
+    token: None,
+    user_texture: None,
+    gallery_item: None,
+    gallery_like: None,
+    cape_entitlement: None,
+    ban: None,
+    issued_ban: None,
+  })
+}
+
+fn setting_from_row(row: &Row) -> rusqlite::Result<prisma::setting::Data> {
+  Ok(prisma::setting::Data {
+    id: row.get("id")?,
+    user_id: row.get("userId")?,
+    max_token: row.get("maxToken")?,
+    token_need_refresh_duration: row.get("tokenNeedRefreshDuration")?,
+    token_invalid_duration: row.get("tokenInvalidDuration")?,
+    user: None,
+  })
+}
+
+fn ban_from_row(row: &Row) -> rusqlite::Result<prisma::ban::Data> {
+  Ok(prisma::ban::Data {
+    id: row.get("id")?,
+    user_id: row.get("userID")?,
+    profile_id: row.get("profileID")?,
+    reason: row.get("reason")?,
+    issuer_id: row.get("issuerID")?,
+    expires_at: row.get("expiresAt")?,
+    created_at: row.get("createdAt")?,
+    user: None,
+    profile: None,
+    issuer: None,
+  })
+}
+
+fn profile_from_row(row: &Row) -> rusqlite::Result<prisma::profile::Data> {
+  Ok(prisma::profile::Data {
+    id: row.get("id")?,
+    uuid: row.get("uuid")?,
+    owner_id: row.get("ownerID")?,
+    display_name: row.get("displayName")?,
+    skin_id: row.get("skinID")?,
+    cape_id: row.get("capeID")?,
+    uploadable_textures: get_enum(row, "uploadableTextures")?,
+    created_at: row.get("createdAt")?,
+    cape: None,
+    owner: None,
+    skin: None,
+    token: None,
+    extra_texture: None,
+    ban: None,
+    name_history: None,
+    profile_key: None,
+  })
+}
+
+fn profile_key_from_row(row: &Row) -> rusqlite::Result<prisma::profile_key::Data> {
+  Ok(prisma::profile_key::Data {
+    id: row.get("id")?,
+    profile_id: row.get("profileID")?,
+    private_key: row.get("privateKey")?,
+    public_key: row.get("publicKey")?,
+    public_key_signature: row.get("publicKeySignature")?,
+    legacy_key_signature: row.get("legacyKeySignature")?,
+    expires_at: row.get("expiresAt")?,
+    refreshed_after: row.get("refreshedAfter")?,
+    created_at: row.get("createdAt")?,
+    profile: None,
+  })
+}
+
+fn name_history_from_row(row: &Row) -> rusqlite::Result<prisma::name_history::Data> {
+  Ok(prisma::name_history::Data {
+    id: row.get("id")?,
+    profile_id: row.get("profileID")?,
+    name: row.get("name")?,
+    changed_at: row.get("changedAt")?,
+    profile: None,
+  })
+}
+
+fn skin_from_row(row: &Row) -> rusqlite::Result<prisma::skin::Data> {
+  Ok(prisma::skin::Data {
+    id: row.get("id")?,
+    hash: row.get("hash")?,
+    model: get_enum(row, "model")?,
+    created_at: row.get("createdAt")?,
+    profile: None,
+    user_texture: None,
+    gallery_item: None,
+  })
+}
+
+fn cape_from_row(row: &Row) -> rusqlite::Result<prisma::cape::Data> {
+  Ok(prisma::cape::Data {
+    id: row.get("id")?,
+    hash: row.get("hash")?,
+    official: row.get("official")?,
+    name: row.get("name")?,
+    frame_rate: row.get("frameRate")?,
+    created_at: row.get("createdAt")?,
+    profile: None,
+    user_texture: None,
+    gallery_item: None,
+    cape_entitlement: None,
+  })
+}
+
+fn cape_entitlement_from_row(row: &Row) -> rusqlite::Result<prisma::cape_entitlement::Data> {
+  Ok(prisma::cape_entitlement::Data {
+    id: row.get("id")?,
+    user_id: row.get("userID")?,
+    cape_id: row.get("capeID")?,
+    reason: row.get("reason")?,
+    created_at: row.get("createdAt")?,
+    user: None,
+    cape: None,
+  })
+}
+
+fn extra_texture_from_row(row: &Row) -> rusqlite::Result<prisma::extra_texture::Data> {
+  Ok(prisma::extra_texture::Data {
+    id: row.get("id")?,
+    profile_id: row.get("profileID")?,
+    kind: get_enum(row, "kind")?,
+    hash: row.get("hash")?,
+    created_at: row.get("createdAt")?,
+    profile: None,
+  })
+}
+
+fn user_texture_from_row(row: &Row) -> rusqlite::Result<prisma::user_texture::Data> {
+  Ok(prisma::user_texture::Data {
+    id: row.get("id")?,
+    owner_id: row.get("ownerID")?,
+    name: row.get("name")?,
+    skin_id: row.get("skinID")?,
+    cape_id: row.get("capeID")?,
+    created_at: row.get("createdAt")?,
+    owner: None,
+    skin: None,
+    cape: None,
+  })
+}
+
+fn gallery_item_from_row(row: &Row) -> rusqlite::Result<prisma::gallery_item::Data> {
+  Ok(prisma::gallery_item::Data {
+    id: row.get("id")?,
+    uploader_id: row.get("uploaderID")?,
+    title: row.get("title")?,
+    tags: get_list(row, "tags")?,
+    skin_id: row.get("skinID")?,
+    cape_id: row.get("capeID")?,
+    likes: row.get("likes")?,
+    created_at: row.get("createdAt")?,
+    uploader: None,
+    skin: None,
+    cape: None,
+    gallery_like: None,
+  })
+}
+
+fn token_from_row(row: &Row) -> rusqlite::Result<prisma::token::Data> {
+  Ok(prisma::token::Data {
+    id: row.get("id")?,
+    access_token: row.get("accessToken")?,
+    client_token: row.get("clientToken")?,
+    owner_id: row.get("ownerID")?,
+    profile_id: row.get("profileID")?,
+    created_at: row.get("createdAt")?,
+    status: get_enum(row, "status")?,
+    join_request: None,
+    owner: None,
+    profile: None,
+  })
+}
+
+fn join_request_from_row(row: &Row) -> rusqlite::Result<prisma::join_request::Data> {
+  Ok(prisma::join_request::Data {
+    id: row.get("id")?,
+    server_id: row.get("serverID")?,
+    access_token: row.get("accessToken")?,
+    ip: row.get("ip")?,
+    created_at: row.get("createdAt")?,
+    token: None,
+  })
+}
+
+fn blocked_server_from_row(row: &Row) -> rusqlite::Result<prisma::blocked_server::Data> {
+  Ok(prisma::blocked_server::Data {
+    id: row.get("id")?,
+    hash: row.get("hash")?,
+    pattern: row.get("pattern")?,
+    reason: row.get("reason")?,
+    created_at: row.get("createdAt")?,
+  })
+}
+
+fn query_one<T, P: Params>(
+  conn: &Connection,
+  sql: &str,
+  params: P,
+  f: fn(&Row) -> rusqlite::Result<T>,
+) -> rusqlite::Result<Option<T>> {
+  conn.prepare_cached(sql)?.query_row(params, f).optional()
+}
+
+fn query_all<T, P: Params>(
+  conn: &Connection,
+  sql: &str,
+  params: P,
+  f: fn(&Row) -> rusqlite::Result<T>,
+) -> rusqlite::Result<Vec<T>> {
+  let mut stmt = conn.prepare_cached(sql)?;
+  let rows = stmt.query_map(params, f)?;
+  rows.collect()
+}
+
+/// 分页查询, 返回 (当前页, 总数)
+#[allow(clippy::too_many_arguments)]
+fn query_page<T>(
+  conn: &Connection,
+  table: &str,
+  filter: &str,
+  params: &[&dyn ToSql],
+  order: &str,
+  skip: i64,
+  take: i64,
+  f: fn(&Row) -> rusqlite::Result<T>,
+) -> rusqlite::Result<(Vec<T>, i64)> {
+  let total = conn
+    .prepare_cached(&format!(r#"SELECT COUNT(*) FROM "{table}" WHERE {filter}"#))?
+    .query_row(params, |row| row.get(0))?;
+  let sql =
+    format!(r#"SELECT * FROM "{table}" WHERE {filter} ORDER BY {order} LIMIT {} OFFSET {}"#, take.max(0), skip.max(0));
+  Ok((query_all(conn, &sql, params, f)?, total))
+}
+
+fn find_skin(conn: &Connection, id: Option<i64>) -> rusqlite::Result<Option<Box<prisma::skin::Data>>> {
+  Ok(match id {
+    Some(id) => query_one(conn, r#"SELECT * FROM "Skin" WHERE "id" = ?1"#, [id], skin_from_row)?.map(Box::new),
+    None => None,
+  })
+}
+
+fn find_cape(conn: &Connection, id: Option<i64>) -> rusqlite::Result<Option<Box<prisma::cape::Data>>> {
+  Ok(match id {
+    Some(id) => query_one(conn, r#"SELECT * FROM "Cape" WHERE "id" = ?1"#, [id], cape_from_row)?.map(Box::new),
+    None => None,
+  })
+}
+
+fn with_textures(conn: &Connection, mut profile: prisma::profile::Data) -> rusqlite::Result<prisma::profile::Data> {
+  profile.skin = Some(find_skin(conn, profile.skin_id)?);
+  profile.cape = Some(find_cape(conn, profile.cape_id)?);
+  profile.extra_texture = Some(query_all(
+    conn,
+    r#"SELECT * FROM "ExtraTexture" WHERE "profileID" = ?1"#,
+    [profile.id],
+    extra_texture_from_row,
+  )?);
+  Ok(profile)
+}
+
+fn with_profiles(conn: &Connection, mut user: prisma::user::Data) -> rusqlite::Result<prisma::user::Data> {
+  let profiles =
+    query_all(conn, r#"SELECT * FROM "Profile" WHERE "ownerID" = ?1 ORDER BY "id""#, [user.id], profile_from_row)?;
+  user.profile = Some(profiles.into_iter().map(|x| with_textures(conn, x)).collect::<rusqlite::Result<_>>()?);
+  Ok(user)
+}
+
+fn with_owner_and_profile(conn: &Connection, mut token: prisma::token::Data) -> rusqlite::Result<prisma::token::Data> {
+  token.owner =
+    query_one(conn, r#"SELECT * FROM "User" WHERE "id" = ?1"#, [token.owner_id], user_from_row)?.map(Box::new);
+  let profile = match token.profile_id {
+    Some(id) => query_one(conn, r#"SELECT * FROM "Profile" WHERE "id" = ?1"#, [id], profile_from_row)?,
+    None => None,
+  };
+  token.profile = Some(match profile {
+    Some(x) => Some(Box::new(with_textures(conn, x)?)),
+    None => None,
+  });
+  Ok(token)
+}
+
+fn with_skin_and_cape(
+  conn: &Connection,
+  mut texture: prisma::user_texture::Data,
+) -> rusqlite::Result<prisma::user_texture::Data> {
+  texture.skin = Some(find_skin(conn, texture.skin_id)?);
+  texture.cape = Some(find_cape(conn, texture.cape_id)?);
+  Ok(texture)
+}
+
+fn with_uploader(
+  conn: &Connection,
+  mut item: prisma::gallery_item::Data,
+) -> rusqlite::Result<prisma::gallery_item::Data> {
+  item.uploader =
+    query_one(conn, r#"SELECT * FROM "User" WHERE "id" = ?1"#, [item.uploader_id], user_from_row)?.map(Box::new);
+  item.skin = Some(find_skin(conn, item.skin_id)?);
+  item.cape = Some(find_cape(conn, item.cape_id)?);
+  Ok(item)
+}
+
+fn with_cape(
+  conn: &Connection,
+  mut entitlement: prisma::cape_entitlement::Data,
+) -> rusqlite::Result<prisma::cape_entitlement::Data> {
+  entitlement.cape = find_cape(conn, Some(entitlement.cape_id))?;
+  Ok(entitlement)
+}
+
+fn exists<P: Params>(conn: &Connection, sql: &str, params: P) -> rusqlite::Result<bool> {
+  conn.prepare_cached(sql)?.exists(params)
+}
+
+fn find_profile(conn: &Connection, id: i64) -> rusqlite::Result<Option<prisma::profile::Data>> {
+  let profile = query_one(conn, r#"SELECT * FROM "Profile" WHERE "id" = ?1"#, [id], profile_from_row)?;
+  profile.map(|x| with_textures(conn, x)).transpose()
+}
+
+fn find_user(conn: &Connection, id: i64) -> rusqlite::Result<Option<prisma::user::Data>> {
+  let user = query_one(conn, r#"SELECT * FROM "User" WHERE "id" = ?1"#, [id], user_from_row)?;
+  user.map(|x| with_profiles(conn, x)).transpose()
+}
+
+fn find_gallery_item(conn: &Connection, id: i64) -> rusqlite::Result<Option<prisma::gallery_item::Data>> {
+  let item = query_one(conn, r#"SELECT * FROM "GalleryItem" WHERE "id" = ?1"#, [id], gallery_item_from_row)?;
+  item.map(|x| with_uploader(conn, x)).transpose()
+}
+
+fn collate(case_insensitive: bool) -> &'static str {
+  match case_insensitive {
+    true => " COLLATE NOCASE",
+    false => "",
+  }
+}
+
+fn name_available(
+  conn: &Connection,
+  name: &str,
+  rules: &NameRules,
+  except_profile: Option<i64>,
+) -> rusqlite::Result<bool> {
+  let sql = format!(
+    r#"SELECT 1 FROM "Profile" WHERE "displayName" = ?1{} AND (?2 IS NULL OR "id" <> ?2)"#,
+    collate(rules.case_insensitive)
+  );
+  if exists(conn, &sql, rusqlite::params![name, except_profile])? {
+    return Ok(false);
+  }
+  // 保留期内只有原角色可以改回该名称
+  let since = match rules.reserved_since {
+    Some(x) => x,
+    None => {
+      return Ok(true);
+    },
+  };
+  // 已删除角色的名称 (profileID 为空) 对所有角色保留
+  let sql = format!(
+    r#"SELECT 1 FROM "NameHistory" WHERE "name" = ?1{} AND "changedAt" > ?2 AND (?3 IS NULL OR "profileID" IS NOT ?3)"#,
+    collate(rules.case_insensitive)
+  );
+  Ok(!exists(conn, &sql, rusqlite::params![name, utc(since), except_profile])?)
+}
+
+fn last_renamed_at(conn: &Connection, profile_id: i64) -> rusqlite::Result<Option<DateTime<FixedOffset>>> {
+  let last = query_one(
+    conn,
+    r#"SELECT * FROM "NameHistory" WHERE "profileID" = ?1 ORDER BY "changedAt" DESC, "id" DESC LIMIT 1"#,
+    [profile_id],
+    name_history_from_row,
+  )?;
+  Ok(last.map(|x| x.changed_at))
+}
+
+/// 冷却中时返回剩余秒数
+fn remaining_cooldown(since: Option<DateTime<FixedOffset>>, cooldown: Option<i64>) -> Option<i64> {
+  let elapsed = Utc::now().timestamp() - since?.timestamp();
+  cooldown.filter(|x| elapsed < *x).map(|x| x - elapsed)
+}
+
+fn invalidate_owner_tokens(conn: &Connection, owner_id: i64) -> rusqlite::Result<i64> {
+  let count = conn
+    .execute(r#"UPDATE "Token" SET "status" = 'Invalid' WHERE "ownerID" = ?1 AND "status" <> 'Invalid'"#, [owner_id])?;
+  Ok(count as i64)
+}
+
+fn invalidate_profile_tokens(conn: &Connection, profile_id: i64) -> rusqlite::Result<i64> {
+  let count = conn.execute(
+    r#"UPDATE "Token" SET "status" = 'Invalid' WHERE "profileID" = ?1 AND "status" <> 'Invalid'"#,
+    [profile_id],
+  )?;
+  Ok(count as i64)
+}
+
+fn schema_version(conn: &Connection) -> RepoResult<usize> {
+  let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+  match usize::try_from(version) {
+    Ok(x) if x <= MIGRATIONS.len() => Ok(x),
+    _ => Err(RepoError::UnknownVersion(version)),
+  }
+}
+
+/// 逐个应用未应用的迁移, 每个迁移与更新后的版本号在同一事务中提交
+fn migrate(conn: &mut Connection) -> RepoResult<()> {
+  for (version, (name, sql)) in MIGRATIONS.iter().enumerate().skip(schema_version(conn)?) {
+    tracing::info!("正在应用迁移 {}", name);
+    let tx = conn.transaction()?;
+    tx.execute_batch(sql)?;
+    tx.pragma_update(None, "user_version", version as i64 + 1)?;
+    tx.commit()?;
+  }
+  Ok(())
+}
+
+/// 尚未应用到数据库文件的迁移, 只读打开, 文件不存在时全部未应用
+pub fn pending_migrations(path: &str) -> RepoResult<Vec<String>> {
+  let version = match std::path::Path::new(path).exists() {
+    true => schema_version(&Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?)?,
+    false => 0,
+  };
+  Ok(MIGRATIONS[version..].iter().map(|(name, _)| (*name).to_owned()).collect())
+}
+
+fn affected(count: usize) -> RepoResult<()> {
+  match count {
+    0 => Err(RepoError::NotFound),
+    _ => Ok(()),
+  }
+}
+
+pub struct SqliteRepo {
+  conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteRepo {
+  /// 打开数据库文件, 文件不存在时自动创建, 并应用未应用的迁移
+  pub fn open(path: &str) -> RepoResult<Self> {
+    let mut conn = Connection::open(path)?;
+    conn.pragma_update(None, "foreign_keys", true)?;
+    migrate(&mut conn)?;
+    Ok(Self { conn: Arc::new(Mutex::new(conn)) })
+  }
+
+  /// 在阻塞线程池中持有连接执行查询
+  async fn run<T: Send + 'static>(
+    &self,
+    f: impl FnOnce(&mut Connection) -> RepoResult<T> + Send + 'static,
+  ) -> RepoResult<T> {
+    let conn = self.conn.clone();
+    match tokio::task::spawn_blocking(move || f(&mut conn.lock().unwrap())).await {
+      Ok(x) => x,
+      Err(err) => std::panic::resume_unwind(err.into_panic()),
+    }
+  }
+}
+
+#[async_trait]
+impl UserRepo for SqliteRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::user::Data>> {
+    self.run(move |conn| Ok(find_user(conn, id)?)).await
+  }
+
+  async fn find_by_email(&self, email: &str) -> RepoResult<Option<prisma::user::Data>> {
+    let email = email.to_owned();
+    self
+      .run(move |conn| {
+        let user = query_one(conn, r#"SELECT * FROM "User" WHERE "email" = ?1"#, [email], user_from_row)?;
+        Ok(user.map(|x| with_profiles(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn list(&self, filter: UserFilter, params: ListParams) -> RepoResult<(Vec<prisma::user::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let column = match params.sort {
+          SortBy::Email => "email",
+          SortBy::Nickname => "nickname",
+          SortBy::CreatedAt => "createdAt",
+          _ => "id",
+        };
+        Ok(query_page(
+          conn,
+          "User",
+          r#"(?1 IS NULL OR instr("email", ?1) > 0) AND (?2 IS NULL OR instr("nickname", ?2) > 0)
+           AND (?3 IS NULL OR "disabled" = ?3) AND (?4 IS NULL OR "role" = ?4)"#,
+          rusqlite::params![filter.email, filter.nickname, filter.disabled, filter.role.map(|x| x.to_string())],
+          &format!(r#""{column}" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          user_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    nickname: String,
+    email: String,
+    password: String,
+  ) -> RepoResult<prisma::user::Data> {
+    self
+      .run(move |conn| {
+        if exists(conn, r#"SELECT 1 FROM "User" WHERE "email" = ?1"#, [&email])? {
+          return Err(RepoError::Conflict("email"));
+        }
+        if exists(conn, r#"SELECT 1 FROM "User" WHERE "uuid" = ?1"#, [&uuid])? {
+          return Err(RepoError::Conflict("uuid"));
+        }
+        conn.execute(
+          r#"INSERT INTO "User" ("uuid", "nickname", "email", "password", "createdAt") VALUES (?1, ?2, ?3, ?4, ?5)"#,
+          rusqlite::params![uuid, nickname, email, password, Utc::now()],
+        )?;
+        find_user(conn, conn.last_insert_rowid())?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn update(&self, id: i64, update: UserUpdate) -> RepoResult<prisma::user::Data> {
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "User" WHERE "id" = ?1"#, [id])? {
+          return Err(RepoError::NotFound);
+        }
+        if let Some(email) = &update.email {
+          if exists(conn, r#"SELECT 1 FROM "User" WHERE "email" = ?1 AND "id" <> ?2"#, rusqlite::params![email, id])? {
+            return Err(RepoError::Conflict("email"));
+          }
+        }
+        let permissions = update.permissions.map(|x| serde_json::Value::from_iter(x.iter().map(|p| p.to_string())));
+        conn.execute(
+          r#"UPDATE "User" SET "email" = COALESCE(?2, "email"), "nickname" = COALESCE(?3, "nickname"),
+           "password" = COALESCE(?4, "password"), "role" = COALESCE(?5, "role"),
+           "permissions" = COALESCE(?6, "permissions"), "language" = COALESCE(?7, "language"),
+           "disabled" = COALESCE(?8, "disabled") WHERE "id" = ?1"#,
+          rusqlite::params![
+            id,
+            update.email,
+            update.nickname,
+            update.password,
+            update.role.map(|x| x.to_string()),
+            permissions.map(|x| x.to_string()),
+            update.language.map(|x| x.to_string()),
+            update.disabled,
+          ],
+        )?;
+        find_user(conn, id)?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        // 角色、令牌、材质库等数据由外键级联删除
+        affected(conn.execute(r#"DELETE FROM "User" WHERE "id" = ?1"#, [id])?)
+      })
+      .await
+  }
+
+  async fn find_setting(&self, user_id: i64) -> RepoResult<Option<prisma::setting::Data>> {
+    self
+      .run(move |conn| {
+        Ok(query_one(conn, r#"SELECT * FROM "Setting" WHERE "userId" = ?1"#, [user_id], setting_from_row)?)
+      })
+      .await
+  }
+
+  async fn list_settings(
+    &self,
+    user_id: Option<i64>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::setting::Data>, i64)> {
+    self
+      .run(move |conn| {
+        Ok(query_page(
+          conn,
+          "Setting",
+          r#"(?1 IS NULL OR "userId" = ?1)"#,
+          rusqlite::params![user_id],
+          &format!(r#""id" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          setting_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn upsert_setting(
+    &self,
+    user_id: i64,
+    update: SettingUpdate,
+    defaults: &settings::Token,
+  ) -> RepoResult<prisma::setting::Data> {
+    let defaults = defaults.clone();
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "User" WHERE "id" = ?1"#, [user_id])? {
+          return Err(RepoError::NotFound);
+        }
+        conn.execute(
+          r#"INSERT INTO "Setting" ("userId", "maxToken", "tokenNeedRefreshDuration", "tokenInvalidDuration")
+           VALUES (?1, COALESCE(?2, ?5), COALESCE(?3, ?6), COALESCE(?4, ?7))
+           ON CONFLICT ("userId") DO UPDATE SET "maxToken" = COALESCE(?2, "maxToken"),
+           "tokenNeedRefreshDuration" = COALESCE(?3, "tokenNeedRefreshDuration"),
+           "tokenInvalidDuration" = COALESCE(?4, "tokenInvalidDuration")"#,
+          rusqlite::params![
+            user_id,
+            update.max_token,
+            update.token_need_refresh_duration,
+            update.token_invalid_duration,
+            defaults.max,
+            defaults.refresh_duration,
+            defaults.invalid_duration,
+          ],
+        )?;
+        query_one(conn, r#"SELECT * FROM "Setting" WHERE "userId" = ?1"#, [user_id], setting_from_row)?
+          .ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn delete_setting(&self, user_id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "Setting" WHERE "userId" = ?1"#, [user_id])?)).await
+  }
+
+  async fn active_ban(&self, user_id: i64, profile_id: Option<i64>) -> RepoResult<Option<prisma::ban::Data>> {
+    self
+      .run(move |conn| {
+        // 优先返回用户封禁
+        Ok(query_one(
+          conn,
+          r#"SELECT * FROM "Ban" WHERE ("userID" = ?1 OR "profileID" = ?2) AND ("expiresAt" IS NULL OR "expiresAt" > ?3)
+           ORDER BY "userID" IS NULL, "userID" LIMIT 1"#,
+          rusqlite::params![user_id, profile_id, Utc::now()],
+          ban_from_row,
+        )?)
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl ProfileRepo for SqliteRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::profile::Data>> {
+    self.run(move |conn| Ok(find_profile(conn, id)?)).await
+  }
+
+  async fn find_by_uuid(&self, uuid: &[u8]) -> RepoResult<Option<prisma::profile::Data>> {
+    let uuid = uuid.to_vec();
+    self
+      .run(move |conn| {
+        let profile = query_one(conn, r#"SELECT * FROM "Profile" WHERE "uuid" = ?1"#, [uuid], profile_from_row)?;
+        Ok(profile.map(|x| with_textures(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn find_by_name(&self, name: &str, case_insensitive: bool) -> RepoResult<Option<prisma::profile::Data>> {
+    let name = name.to_owned();
+    self
+      .run(move |conn| {
+        let sql = format!(r#"SELECT * FROM "Profile" WHERE "displayName" = ?1{} LIMIT 1"#, collate(case_insensitive));
+        let profile = query_one(conn, &sql, [name], profile_from_row)?;
+        Ok(profile.map(|x| with_textures(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn find_by_names(&self, names: &[String], case_insensitive: bool) -> RepoResult<Vec<prisma::profile::Data>> {
+    if names.is_empty() {
+      return Ok(vec![]);
+    }
+    let names = names.to_vec();
+    self
+      .run(move |conn| {
+        let sql = format!(
+          r#"SELECT * FROM "Profile" WHERE "displayName"{} IN ({})"#,
+          collate(case_insensitive),
+          vec!["?"; names.len()].join(", "),
+        );
+        let profiles = query_all(conn, &sql, rusqlite::params_from_iter(names), profile_from_row)?;
+        Ok(profiles.into_iter().map(|x| with_textures(conn, x)).collect::<rusqlite::Result<_>>()?)
+      })
+      .await
+  }
+
+  async fn find_by_name_at(
+    &self,
+    name: &str,
+    at: DateTime<FixedOffset>,
+    case_insensitive: bool,
+  ) -> RepoResult<Option<prisma::profile::Data>> {
+    let name = name.to_owned();
+    self
+      .run(move |conn| {
+        // 在该时间之后才改掉这个名称的角色: 改名记录需要是该时间之后的第一次改名
+        let first_change = |profile_id: i64| {
+          query_one(
+            conn,
+            r#"SELECT * FROM "NameHistory" WHERE "profileID" = ?1 AND "changedAt" > ?2
+             ORDER BY "changedAt", "id" LIMIT 1"#,
+            rusqlite::params![profile_id, utc(at)],
+            name_history_from_row,
+          )
+        };
+        let sql = format!(
+          r#"SELECT * FROM "NameHistory" WHERE "name" = ?1{} AND "changedAt" > ?2 ORDER BY "changedAt", "id""#,
+          collate(case_insensitive)
+        );
+        let released = query_all(conn, &sql, rusqlite::params![name, utc(at)], name_history_from_row)?;
+        // 已删除角色的记录不对应任何角色
+        for (x, profile_id) in released.into_iter().filter_map(|x| x.profile_id.map(|id| (x, id))) {
+          let profile = query_one(conn, r#"SELECT * FROM "Profile" WHERE "id" = ?1"#, [profile_id], profile_from_row)?;
+          if first_change(profile_id)?.is_some_and(|y| y.id == x.id)
+            && profile.as_ref().is_some_and(|y| y.created_at <= at)
+          {
+            return Ok(profile);
+          }
+        }
+        // 当前使用该名称, 且该时间之后没有改过名的角色
+        let sql = format!(
+          r#"SELECT * FROM "Profile" WHERE "displayName" = ?1{} AND "createdAt" <= ?2 LIMIT 1"#,
+          collate(case_insensitive)
+        );
+        match query_one(conn, &sql, rusqlite::params![name, utc(at)], profile_from_row)? {
+          Some(x) if first_change(x.id)?.is_none() => Ok(Some(x)),
+          _ => Ok(None),
+        }
+      })
+      .await
+  }
+
+  async fn list(&self, filter: ProfileFilter, params: ListParams) -> RepoResult<(Vec<prisma::profile::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let column = match params.sort {
+          SortBy::Name => "displayName",
+          SortBy::CreatedAt => "createdAt",
+          _ => "id",
+        };
+        Ok(query_page(
+          conn,
+          "Profile",
+          r#"(?1 IS NULL OR "ownerID" = ?1) AND (?2 IS NULL OR instr("displayName", ?2) > 0)"#,
+          rusqlite::params![filter.owner_id, filter.name],
+          &format!(r#""{column}" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          profile_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn name_available(&self, name: &str, rules: &NameRules, except_profile: Option<i64>) -> RepoResult<bool> {
+    let (name, rules) = (name.to_owned(), *rules);
+    self.run(move |conn| Ok(name_available(conn, &name, &rules, except_profile)?)).await
+  }
+
+  async fn create(
+    &self,
+    uuid: Vec<u8>,
+    name: String,
+    owner_id: i64,
+    rules: &NameRules,
+    limit: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let rules = *rules;
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        if !exists(&tx, r#"SELECT 1 FROM "User" WHERE "id" = ?1"#, [owner_id])? {
+          return Err(RepoError::NotFound);
+        }
+        if let Some(limit) = limit {
+          let count: i64 =
+            tx.query_row(r#"SELECT COUNT(*) FROM "Profile" WHERE "ownerID" = ?1"#, [owner_id], |row| row.get(0))?;
+          if count >= limit {
+            return Err(RepoError::LimitReached(limit));
+          }
+        }
+        if !name_available(&tx, &name, &rules, None)? {
+          return Err(RepoError::Conflict("displayName"));
+        }
+        if exists(&tx, r#"SELECT 1 FROM "Profile" WHERE "uuid" = ?1"#, [&uuid])? {
+          return Err(RepoError::Conflict("uuid"));
+        }
+        tx.execute(
+          r#"INSERT INTO "Profile" ("uuid", "ownerID", "displayName", "createdAt") VALUES (?1, ?2, ?3, ?4)"#,
+          rusqlite::params![uuid, owner_id, name, Utc::now()],
+        )?;
+        let profile = find_profile(&tx, tx.last_insert_rowid())?.ok_or(RepoError::NotFound)?;
+        tx.commit()?;
+        Ok(profile)
+      })
+      .await
+  }
+
+  async fn rename(
+    &self,
+    profile_id: i64,
+    name: String,
+    rules: &NameRules,
+    cooldown: Option<i64>,
+  ) -> RepoResult<prisma::profile::Data> {
+    let rules = *rules;
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        let profile = find_profile(&tx, profile_id)?.ok_or(RepoError::NotFound)?;
+        if let Some(remaining) = remaining_cooldown(last_renamed_at(&tx, profile_id)?, cooldown) {
+          return Err(RepoError::Cooldown(remaining));
+        }
+        if !name_available(&tx, &name, &rules, Some(profile_id))? {
+          return Err(RepoError::Conflict("displayName"));
+        }
+        tx.execute(
+          r#"INSERT INTO "NameHistory" ("profileID", "name", "changedAt") VALUES (?1, ?2, ?3)"#,
+          rusqlite::params![profile_id, profile.display_name, Utc::now()],
+        )?;
+        tx.execute(r#"UPDATE "Profile" SET "displayName" = ?2 WHERE "id" = ?1"#, rusqlite::params![profile_id, name])?;
+        invalidate_profile_tokens(&tx, profile_id)?;
+        let profile = find_profile(&tx, profile_id)?.ok_or(RepoError::NotFound)?;
+        tx.commit()?;
+        Ok(profile)
+      })
+      .await
+  }
+
+  async fn update(&self, profile_id: i64, update: ProfileUpdate) -> RepoResult<prisma::profile::Data> {
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "Profile" WHERE "id" = ?1"#, [profile_id])? {
+          return Err(RepoError::NotFound);
+        }
+        if let Some(name) = &update.name {
+          let sql = r#"SELECT 1 FROM "Profile" WHERE "displayName" = ?1 AND "id" <> ?2"#;
+          if exists(conn, sql, rusqlite::params![name, profile_id])? {
+            return Err(RepoError::Conflict("displayName"));
+          }
+        }
+        if let Some(owner_id) = update.owner_id {
+          if !exists(conn, r#"SELECT 1 FROM "User" WHERE "id" = ?1"#, [owner_id])? {
+            return Err(RepoError::NotFound);
+          }
+        }
+        conn.execute(
+          r#"UPDATE "Profile" SET "displayName" = COALESCE(?2, "displayName"), "ownerID" = COALESCE(?3, "ownerID"),
+           "uploadableTextures" = COALESCE(?4, "uploadableTextures"),
+           "skinID" = CASE WHEN ?5 THEN ?6 ELSE "skinID" END, "capeID" = CASE WHEN ?7 THEN ?8 ELSE "capeID" END
+           WHERE "id" = ?1"#,
+          rusqlite::params![
+            profile_id,
+            update.name,
+            update.owner_id,
+            update.uploadable_textures.map(|x| x.to_string()),
+            update.skin_id.is_some(),
+            update.skin_id.flatten(),
+            update.cape_id.is_some(),
+            update.cape_id.flatten(),
+          ],
+        )?;
+        find_profile(conn, profile_id)?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn delete(&self, profile_id: i64, cooldown: Option<i64>) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        let profile = query_one(&tx, r#"SELECT * FROM "Profile" WHERE "id" = ?1"#, [profile_id], profile_from_row)?
+          .ok_or(RepoError::NotFound)?;
+        if cooldown.is_some() {
+          let owner = query_one(&tx, r#"SELECT * FROM "User" WHERE "id" = ?1"#, [profile.owner_id], user_from_row)?;
+          if let Some(remaining) = remaining_cooldown(owner.and_then(|x| x.profile_deleted_at), cooldown) {
+            return Err(RepoError::Cooldown(remaining));
+          }
+        }
+        invalidate_profile_tokens(&tx, profile_id)?;
+        // 删除后名称同样在保留期内不能被其他角色使用
+        tx.execute(
+          r#"INSERT INTO "NameHistory" ("profileID", "name", "changedAt") VALUES (?1, ?2, ?3)"#,
+          rusqlite::params![profile_id, profile.display_name, Utc::now()],
+        )?;
+        tx.execute(r#"DELETE FROM "Profile" WHERE "id" = ?1"#, [profile_id])?;
+        if cooldown.is_some() {
+          tx.execute(
+            r#"UPDATE "User" SET "profileDeletedAt" = ?2 WHERE "id" = ?1"#,
+            rusqlite::params![profile.owner_id, Utc::now()],
+          )?;
+        }
+        tx.commit()?;
+        Ok(())
+      })
+      .await
+  }
+
+  async fn set_skin(&self, profile_id: i64, skin_id: Option<i64>) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        affected(
+          conn
+            .execute(r#"UPDATE "Profile" SET "skinID" = ?2 WHERE "id" = ?1"#, rusqlite::params![profile_id, skin_id])?,
+        )
+      })
+      .await
+  }
+
+  async fn set_cape(&self, profile_id: i64, cape_id: Option<i64>) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        affected(
+          conn
+            .execute(r#"UPDATE "Profile" SET "capeID" = ?2 WHERE "id" = ?1"#, rusqlite::params![profile_id, cape_id])?,
+        )
+      })
+      .await
+  }
+
+  async fn name_history(&self, profile_id: i64) -> RepoResult<Vec<prisma::name_history::Data>> {
+    self
+      .run(move |conn| {
+        Ok(query_all(
+          conn,
+          r#"SELECT * FROM "NameHistory" WHERE "profileID" = ?1 ORDER BY "changedAt", "id""#,
+          [profile_id],
+          name_history_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn last_renamed_at(&self, profile_id: i64) -> RepoResult<Option<DateTime<FixedOffset>>> {
+    self.run(move |conn| Ok(last_renamed_at(conn, profile_id)?)).await
+  }
+
+  async fn find_key(&self, profile_id: i64) -> RepoResult<Option<prisma::profile_key::Data>> {
+    self
+      .run(move |conn| {
+        Ok(query_one(conn, r#"SELECT * FROM "ProfileKey" WHERE "profileID" = ?1"#, [profile_id], profile_key_from_row)?)
+      })
+      .await
+  }
+
+  async fn save_key(&self, profile_id: i64, key: ProfileKeyPair) -> RepoResult<prisma::profile_key::Data> {
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "Profile" WHERE "id" = ?1"#, [profile_id])? {
+          return Err(RepoError::NotFound);
+        }
+        conn.execute(
+        r#"INSERT INTO "ProfileKey" ("profileID", "privateKey", "publicKey", "publicKeySignature", "legacyKeySignature",
+           "expiresAt", "refreshedAfter", "createdAt") VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+           ON CONFLICT ("profileID") DO UPDATE SET "privateKey" = excluded."privateKey",
+           "publicKey" = excluded."publicKey", "publicKeySignature" = excluded."publicKeySignature",
+           "legacyKeySignature" = excluded."legacyKeySignature", "expiresAt" = excluded."expiresAt",
+           "refreshedAfter" = excluded."refreshedAfter", "createdAt" = excluded."createdAt""#,
+        rusqlite::params![
+          profile_id,
+          key.private_key,
+          key.public_key,
+          key.public_key_signature,
+          key.legacy_key_signature,
+          utc(key.expires_at),
+          utc(key.refreshed_after),
+          Utc::now(),
+        ],
+      )?;
+        query_one(conn, r#"SELECT * FROM "ProfileKey" WHERE "profileID" = ?1"#, [profile_id], profile_key_from_row)?
+          .ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl TokenRepo for SqliteRepo {
+  async fn create(
+    &self,
+    owner_id: i64,
+    profile_id: Option<i64>,
+    access_token: String,
+    client_token: String,
+  ) -> RepoResult<prisma::token::Data> {
+    self
+      .run(move |conn| {
+        if exists(conn, r#"SELECT 1 FROM "Token" WHERE "accessToken" = ?1"#, [&access_token])? {
+          return Err(RepoError::Conflict("accessToken"));
+        }
+        conn.execute(
+          r#"INSERT INTO "Token" ("accessToken", "clientToken", "ownerID", "profileID", "createdAt")
+           VALUES (?1, ?2, ?3, ?4, ?5)"#,
+          rusqlite::params![access_token, client_token, owner_id, profile_id, Utc::now()],
+        )?;
+        query_one(conn, r#"SELECT * FROM "Token" WHERE "id" = ?1"#, [conn.last_insert_rowid()], token_from_row)?
+          .ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn find(&self, access_token: &str, client_token: Option<&str>) -> RepoResult<Option<prisma::token::Data>> {
+    let (access_token, client_token) = (access_token.to_owned(), client_token.map(str::to_owned));
+    self
+      .run(move |conn| {
+        let token = query_one(
+          conn,
+          r#"SELECT * FROM "Token" WHERE "accessToken" = ?1 AND "status" <> 'Invalid'
+           AND (?2 IS NULL OR "clientToken" = ?2)"#,
+          rusqlite::params![access_token, client_token],
+          token_from_row,
+        )?;
+        Ok(token.map(|x| with_owner_and_profile(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::token::Data>> {
+    self.run(move |conn| Ok(query_one(conn, r#"SELECT * FROM "Token" WHERE "id" = ?1"#, [id], token_from_row)?)).await
+  }
+
+  async fn list(&self, filter: TokenFilter, params: ListParams) -> RepoResult<(Vec<prisma::token::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let column = match params.sort {
+          SortBy::CreatedAt => "createdAt",
+          _ => "id",
+        };
+        Ok(query_page(
+          conn,
+          "Token",
+          r#"(?1 IS NULL OR "ownerID" = ?1) AND (?2 IS NULL OR "profileID" = ?2) AND (?3 IS NULL OR "status" = ?3)"#,
+          rusqlite::params![filter.owner_id, filter.profile_id, filter.status.map(|x| x.to_string())],
+          &format!(r#""{column}" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          token_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn list_by_owner(&self, owner_id: i64, status: prisma::TokenStatus) -> RepoResult<Vec<prisma::token::Data>> {
+    self
+      .run(move |conn| {
+        Ok(query_all(
+          conn,
+          r#"SELECT * FROM "Token" WHERE "ownerID" = ?1 AND "status" = ?2 ORDER BY "createdAt" DESC, "id" DESC"#,
+          rusqlite::params![owner_id, status.to_string()],
+          token_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn set_status(&self, id: i64, status: prisma::TokenStatus) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        affected(
+          conn.execute(
+            r#"UPDATE "Token" SET "status" = ?2 WHERE "id" = ?1"#,
+            rusqlite::params![id, status.to_string()],
+          )?,
+        )
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "Token" WHERE "id" = ?1"#, [id])?)).await
+  }
+
+  async fn invalidate(&self, access_token: &str) -> RepoResult<i64> {
+    let access_token = access_token.to_owned();
+    self
+      .run(move |conn| {
+        let count = conn.execute(
+          r#"UPDATE "Token" SET "status" = 'Invalid' WHERE "accessToken" = ?1 AND "status" <> 'Invalid'"#,
+          [access_token],
+        )?;
+        Ok(count as i64)
+      })
+      .await
+  }
+
+  async fn invalidate_by_owner(&self, owner_id: i64) -> RepoResult<i64> {
+    self.run(move |conn| Ok(invalidate_owner_tokens(conn, owner_id)?)).await
+  }
+
+  async fn invalidate_by_profile(&self, profile_id: i64) -> RepoResult<i64> {
+    self.run(move |conn| Ok(invalidate_profile_tokens(conn, profile_id)?)).await
+  }
+}
+
+#[async_trait]
+impl TextureRepo for SqliteRepo {
+  async fn find_or_create_skin(&self, hash: Vec<u8>, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    self
+      .run(move |conn| {
+        let model = model.to_string();
+        let sql = r#"SELECT * FROM "Skin" WHERE "hash" = ?1 AND "model" = ?2 LIMIT 1"#;
+        if let Some(x) = query_one(conn, sql, rusqlite::params![hash, model], skin_from_row)? {
+          return Ok(x);
+        }
+        conn.execute(
+          r#"INSERT INTO "Skin" ("hash", "model", "createdAt") VALUES (?1, ?2, ?3)"#,
+          rusqlite::params![hash, model, Utc::now()],
+        )?;
+        query_one(conn, sql, rusqlite::params![hash, model], skin_from_row)?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn find_or_create_cape(&self, hash: Vec<u8>, frame_rate: Option<i32>) -> RepoResult<prisma::cape::Data> {
+    self
+      .run(move |conn| {
+        let sql = r#"SELECT * FROM "Cape" WHERE "hash" = ?1 AND "frameRate" IS ?2 AND "official" = 0 LIMIT 1"#;
+        if let Some(x) = query_one(conn, sql, rusqlite::params![hash, frame_rate], cape_from_row)? {
+          return Ok(x);
+        }
+        conn.execute(
+          r#"INSERT INTO "Cape" ("hash", "frameRate", "createdAt") VALUES (?1, ?2, ?3)"#,
+          rusqlite::params![hash, frame_rate, Utc::now()],
+        )?;
+        query_one(conn, sql, rusqlite::params![hash, frame_rate], cape_from_row)?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn find_skin(&self, id: i64) -> RepoResult<Option<prisma::skin::Data>> {
+    self.run(move |conn| Ok(find_skin(conn, Some(id))?.map(|x| *x))).await
+  }
+
+  async fn list_skins(
+    &self,
+    model: Option<prisma::SkinType>,
+    params: ListParams,
+  ) -> RepoResult<(Vec<prisma::skin::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let column = match params.sort {
+          SortBy::CreatedAt => "createdAt",
+          _ => "id",
+        };
+        Ok(query_page(
+          conn,
+          "Skin",
+          r#"(?1 IS NULL OR "model" = ?1)"#,
+          rusqlite::params![model.map(|x| x.to_string())],
+          &format!(r#""{column}" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          skin_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn set_skin_model(&self, id: i64, model: prisma::SkinType) -> RepoResult<prisma::skin::Data> {
+    self
+      .run(move |conn| {
+        affected(
+          conn
+            .execute(r#"UPDATE "Skin" SET "model" = ?2 WHERE "id" = ?1"#, rusqlite::params![id, model.to_string()])?,
+        )?;
+        Ok(*find_skin(conn, Some(id))?.ok_or(RepoError::NotFound)?)
+      })
+      .await
+  }
+
+  async fn delete_skin(&self, id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "Skin" WHERE "id" = ?1"#, [id])?)).await
+  }
+
+  async fn find_cape(&self, id: i64) -> RepoResult<Option<prisma::cape::Data>> {
+    self.run(move |conn| Ok(find_cape(conn, Some(id))?.map(|x| *x))).await
+  }
+
+  async fn list_capes(&self, official: Option<bool>, params: ListParams) -> RepoResult<(Vec<prisma::cape::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let column = match params.sort {
+          SortBy::CreatedAt => "createdAt",
+          _ => "id",
+        };
+        Ok(query_page(
+          conn,
+          "Cape",
+          r#"(?1 IS NULL OR "official" = ?1)"#,
+          rusqlite::params![official],
+          &format!(r#""{column}" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          cape_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn create_official_cape(
+    &self,
+    hash: Vec<u8>,
+    name: String,
+    frame_rate: Option<i32>,
+  ) -> RepoResult<prisma::cape::Data> {
+    self
+      .run(move |conn| {
+        conn.execute(
+          r#"INSERT INTO "Cape" ("hash", "official", "name", "frameRate", "createdAt") VALUES (?1, 1, ?2, ?3, ?4)"#,
+          rusqlite::params![hash, name, frame_rate, Utc::now()],
+        )?;
+        Ok(*find_cape(conn, Some(conn.last_insert_rowid()))?.ok_or(RepoError::NotFound)?)
+      })
+      .await
+  }
+
+  async fn update_cape(&self, id: i64, update: CapeUpdate) -> RepoResult<prisma::cape::Data> {
+    self
+      .run(move |conn| {
+        affected(conn.execute(
+          r#"UPDATE "Cape" SET "name" = CASE WHEN ?2 THEN ?3 ELSE "name" END, "official" = COALESCE(?4, "official"),
+           "frameRate" = CASE WHEN ?5 THEN ?6 ELSE "frameRate" END WHERE "id" = ?1"#,
+          rusqlite::params![
+            id,
+            update.name.is_some(),
+            update.name.flatten(),
+            update.official,
+            update.frame_rate.is_some(),
+            update.frame_rate.flatten(),
+          ],
+        )?)?;
+        Ok(*find_cape(conn, Some(id))?.ok_or(RepoError::NotFound)?)
+      })
+      .await
+  }
+
+  async fn delete_cape(&self, id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "Cape" WHERE "id" = ?1"#, [id])?)).await
+  }
+
+  async fn set_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType, hash: Vec<u8>) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        conn.execute(
+          r#"INSERT INTO "ExtraTexture" ("profileID", "kind", "hash", "createdAt") VALUES (?1, ?2, ?3, ?4)
+           ON CONFLICT ("profileID", "kind")
+           DO UPDATE SET "hash" = excluded."hash", "createdAt" = excluded."createdAt""#,
+          rusqlite::params![profile_id, kind.to_string(), hash, Utc::now()],
+        )?;
+        Ok(())
+      })
+      .await
+  }
+
+  async fn clear_extra(&self, profile_id: i64, kind: prisma::ExtraTextureType) -> RepoResult<i64> {
+    self
+      .run(move |conn| {
+        let count = conn.execute(
+          r#"DELETE FROM "ExtraTexture" WHERE "profileID" = ?1 AND "kind" = ?2"#,
+          rusqlite::params![profile_id, kind.to_string()],
+        )?;
+        Ok(count as i64)
+      })
+      .await
+  }
+
+  async fn add_to_library(
+    &self,
+    owner_id: i64,
+    name: String,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        // 已在材质库中的材质只更新名称与时间
+        let updated = conn.execute(
+          r#"UPDATE "UserTexture" SET "name" = ?2, "createdAt" = ?5
+            WHERE "ownerID" = ?1 AND "skinID" IS ?3 AND "capeID" IS ?4"#,
+          rusqlite::params![owner_id, name, skin_id, cape_id, Utc::now()],
+        )?;
+        if updated == 0 {
+          conn.execute(
+            r#"INSERT INTO "UserTexture" ("ownerID", "name", "skinID", "capeID", "createdAt")
+              VALUES (?1, ?2, ?3, ?4, ?5)"#,
+            rusqlite::params![owner_id, name, skin_id, cape_id, Utc::now()],
+          )?;
+        }
+        Ok(())
+      })
+      .await
+  }
+
+  async fn list_library(&self, owner_id: i64) -> RepoResult<Vec<prisma::user_texture::Data>> {
+    self
+      .run(move |conn| {
+        let textures = query_all(
+          conn,
+          r#"SELECT * FROM "UserTexture" WHERE "ownerID" = ?1 ORDER BY "createdAt" DESC, "id" DESC"#,
+          [owner_id],
+          user_texture_from_row,
+        )?;
+        Ok(textures.into_iter().map(|x| with_skin_and_cape(conn, x)).collect::<rusqlite::Result<_>>()?)
+      })
+      .await
+  }
+
+  async fn find_library_texture(&self, owner_id: i64, id: i64) -> RepoResult<Option<prisma::user_texture::Data>> {
+    self
+      .run(move |conn| {
+        let texture = query_one(
+          conn,
+          r#"SELECT * FROM "UserTexture" WHERE "id" = ?1 AND "ownerID" = ?2"#,
+          rusqlite::params![id, owner_id],
+          user_texture_from_row,
+        )?;
+        Ok(texture.map(|x| with_skin_and_cape(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn rename_library_texture(&self, id: i64, name: String) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        affected(conn.execute(r#"UPDATE "UserTexture" SET "name" = ?2 WHERE "id" = ?1"#, rusqlite::params![id, name])?)
+      })
+      .await
+  }
+
+  async fn remove_library_texture(&self, id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        conn.execute(r#"DELETE FROM "UserTexture" WHERE "id" = ?1"#, [id])?;
+        Ok(())
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl GalleryRepo for SqliteRepo {
+  async fn search(
+    &self,
+    filter: GalleryFilter,
+    skip: i64,
+    take: i64,
+  ) -> RepoResult<(Vec<prisma::gallery_item::Data>, i64)> {
+    self
+      .run(move |conn| {
+        let order = match filter.popular {
+          true => r#""likes" DESC, "id" DESC"#,
+          false => r#""createdAt" DESC, "id" DESC"#,
+        };
+        let (items, total) = query_page(
+          conn,
+          "GalleryItem",
+          r#"(?1 IS NULL OR instr(lower("title"), lower(?1)) > 0)
+           AND (?2 IS NULL OR EXISTS (SELECT 1 FROM json_each("GalleryItem"."tags") WHERE "value" = ?2))
+           AND (NOT ?3 OR "skinID" IS NOT NULL) AND (NOT ?4 OR "capeID" IS NOT NULL)"#,
+          rusqlite::params![
+            filter.title,
+            filter.tag,
+            filter.texture_type == Some(TextureType::Skin),
+            filter.texture_type == Some(TextureType::Cape),
+          ],
+          order,
+          skip,
+          take,
+          gallery_item_from_row,
+        )?;
+        let items = items.into_iter().map(|x| with_uploader(conn, x)).collect::<rusqlite::Result<_>>()?;
+        Ok((items, total))
+      })
+      .await
+  }
+
+  async fn find(&self, id: i64) -> RepoResult<Option<prisma::gallery_item::Data>> {
+    self.run(move |conn| Ok(find_gallery_item(conn, id)?)).await
+  }
+
+  async fn publish(
+    &self,
+    uploader_id: i64,
+    title: String,
+    tags: Vec<String>,
+    skin_id: Option<i64>,
+    cape_id: Option<i64>,
+  ) -> RepoResult<prisma::gallery_item::Data> {
+    self
+      .run(move |conn| {
+        conn.execute(
+          r#"INSERT INTO "GalleryItem" ("uploaderID", "title", "tags", "skinID", "capeID", "createdAt")
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
+          rusqlite::params![
+            uploader_id,
+            title,
+            serde_json::Value::from(tags).to_string(),
+            skin_id,
+            cape_id,
+            Utc::now()
+          ],
+        )?;
+        find_gallery_item(conn, conn.last_insert_rowid())?.ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        conn.execute(r#"DELETE FROM "GalleryItem" WHERE "id" = ?1"#, [id])?;
+        Ok(())
+      })
+      .await
+  }
+
+  async fn like(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        if !exists(&tx, r#"SELECT 1 FROM "GalleryItem" WHERE "id" = ?1"#, [item_id])? {
+          return Err(RepoError::NotFound);
+        }
+        let liked = tx.execute(
+          r#"INSERT INTO "GalleryLike" ("itemID", "userID", "createdAt") VALUES (?1, ?2, ?3)
+           ON CONFLICT ("itemID", "userID") DO NOTHING"#,
+          rusqlite::params![item_id, user_id, Utc::now()],
+        )?;
+        tx.execute(
+          r#"UPDATE "GalleryItem" SET "likes" = "likes" + ?2 WHERE "id" = ?1"#,
+          rusqlite::params![item_id, liked as i64],
+        )?;
+        tx.commit()?;
+        Ok(())
+      })
+      .await
+  }
+
+  async fn unlike(&self, item_id: i64, user_id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        let deleted = tx.execute(
+          r#"DELETE FROM "GalleryLike" WHERE "itemID" = ?1 AND "userID" = ?2"#,
+          rusqlite::params![item_id, user_id],
+        )?;
+        tx.execute(
+          r#"UPDATE "GalleryItem" SET "likes" = "likes" - ?2 WHERE "id" = ?1"#,
+          rusqlite::params![item_id, deleted as i64],
+        )?;
+        tx.commit()?;
+        Ok(())
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl EntitlementRepo for SqliteRepo {
+  async fn list(&self, user_id: i64) -> RepoResult<Vec<prisma::cape_entitlement::Data>> {
+    self
+      .run(move |conn| {
+        let entitlements = query_all(
+          conn,
+          r#"SELECT * FROM "CapeEntitlement" WHERE "userID" = ?1 ORDER BY "createdAt" DESC, "id" DESC"#,
+          [user_id],
+          cape_entitlement_from_row,
+        )?;
+        Ok(entitlements.into_iter().map(|x| with_cape(conn, x)).collect::<rusqlite::Result<_>>()?)
+      })
+      .await
+  }
+
+  async fn find(&self, user_id: i64, cape_id: i64) -> RepoResult<Option<prisma::cape_entitlement::Data>> {
+    self
+      .run(move |conn| {
+        let entitlement = query_one(
+          conn,
+          r#"SELECT * FROM "CapeEntitlement" WHERE "userID" = ?1 AND "capeID" = ?2"#,
+          rusqlite::params![user_id, cape_id],
+          cape_entitlement_from_row,
+        )?;
+        Ok(entitlement.map(|x| with_cape(conn, x)).transpose()?)
+      })
+      .await
+  }
+
+  async fn grant(&self, user_id: i64, cape_id: i64, reason: Option<String>) -> RepoResult<i64> {
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "User" WHERE "id" = ?1"#, [user_id])?
+          || !exists(conn, r#"SELECT 1 FROM "Cape" WHERE "id" = ?1"#, [cape_id])?
+        {
+          return Err(RepoError::NotFound);
+        }
+        let count = conn.execute(
+          r#"INSERT INTO "CapeEntitlement" ("userID", "capeID", "reason", "createdAt") VALUES (?1, ?2, ?3, ?4)
+           ON CONFLICT ("userID", "capeID") DO NOTHING"#,
+          rusqlite::params![user_id, cape_id, reason, Utc::now()],
+        )?;
+        Ok(count as i64)
+      })
+      .await
+  }
+
+  async fn revoke(&self, user_id: i64, cape_id: i64) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        let tx = conn.transaction()?;
+        affected(tx.execute(
+          r#"DELETE FROM "CapeEntitlement" WHERE "userID" = ?1 AND "capeID" = ?2"#,
+          rusqlite::params![user_id, cape_id],
+        )?)?;
+        // 已经穿戴的披风一并取下
+        tx.execute(
+          r#"UPDATE "Profile" SET "capeID" = NULL WHERE "ownerID" = ?1 AND "capeID" = ?2"#,
+          rusqlite::params![user_id, cape_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+      })
+      .await
+  }
+}
+
+#[async_trait]
+impl BanRepo for SqliteRepo {
+  async fn find_by_id(&self, id: i64) -> RepoResult<Option<prisma::ban::Data>> {
+    self.run(move |conn| Ok(query_one(conn, r#"SELECT * FROM "Ban" WHERE "id" = ?1"#, [id], ban_from_row)?)).await
+  }
+
+  async fn list(&self, filter: BanFilter, params: ListParams) -> RepoResult<(Vec<prisma::ban::Data>, i64)> {
+    self
+      .run(move |conn| {
+        Ok(query_page(
+          conn,
+          "Ban",
+          r#"(?1 IS NULL OR "userID" = ?1) AND (?2 IS NULL OR "profileID" = ?2)
+           AND (NOT ?3 OR "expiresAt" IS NULL OR "expiresAt" > ?4)"#,
+          rusqlite::params![filter.user_id, filter.profile_id, filter.active, Utc::now()],
+          &format!(r#""id" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          ban_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn issue(
+    &self,
+    target: BanTarget,
+    reason: String,
+    issuer_id: Option<i64>,
+    expires_at: Option<DateTime<FixedOffset>>,
+  ) -> RepoResult<prisma::ban::Data> {
+    self
+      .run(move |conn| {
+        let (user_id, profile_id) = match target {
+          BanTarget::User(user_id) => (Some(user_id), None),
+          BanTarget::Profile(profile_id) => (None, Some(profile_id)),
+        };
+        // 封禁与吊销令牌在同一事务中完成, 不会留下仍然有效的令牌
+        let tx = conn.transaction()?;
+        tx.execute(
+          r#"INSERT INTO "Ban" ("userID", "profileID", "reason", "issuerID", "expiresAt", "createdAt")
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
+          rusqlite::params![user_id, profile_id, reason, issuer_id, expires_at.map(utc), Utc::now()],
+        )?;
+        let ban = query_one(&tx, r#"SELECT * FROM "Ban" WHERE "id" = ?1"#, [tx.last_insert_rowid()], ban_from_row)?
+          .ok_or(RepoError::NotFound)?;
+        match target {
+          BanTarget::User(user_id) => invalidate_owner_tokens(&tx, user_id)?,
+          BanTarget::Profile(profile_id) => invalidate_profile_tokens(&tx, profile_id)?,
+        };
+        tx.commit()?;
+        Ok(ban)
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "Ban" WHERE "id" = ?1"#, [id])?)).await
+  }
+}
+
+#[async_trait]
+impl BlockedServerRepo for SqliteRepo {
+  async fn list(&self, params: ListParams) -> RepoResult<(Vec<prisma::blocked_server::Data>, i64)> {
+    self
+      .run(move |conn| {
+        Ok(query_page(
+          conn,
+          "BlockedServer",
+          "1",
+          rusqlite::params![],
+          &format!(r#""id" {}"#, sort_order(params.order)),
+          params.skip,
+          params.take,
+          blocked_server_from_row,
+        )?)
+      })
+      .await
+  }
+
+  async fn create(
+    &self,
+    hash: String,
+    pattern: Option<String>,
+    reason: Option<String>,
+  ) -> RepoResult<prisma::blocked_server::Data> {
+    self
+      .run(move |conn| {
+        if exists(conn, r#"SELECT 1 FROM "BlockedServer" WHERE "hash" = ?1"#, [&hash])? {
+          return Err(RepoError::Conflict("hash"));
+        }
+        conn.execute(
+          r#"INSERT INTO "BlockedServer" ("hash", "pattern", "reason", "createdAt") VALUES (?1, ?2, ?3, ?4)"#,
+          rusqlite::params![hash, pattern, reason, Utc::now()],
+        )?;
+        query_one(
+          conn,
+          r#"SELECT * FROM "BlockedServer" WHERE "id" = ?1"#,
+          [conn.last_insert_rowid()],
+          blocked_server_from_row,
+        )?
+        .ok_or(RepoError::NotFound)
+      })
+      .await
+  }
+
+  async fn delete(&self, id: i64) -> RepoResult<()> {
+    self.run(move |conn| affected(conn.execute(r#"DELETE FROM "BlockedServer" WHERE "id" = ?1"#, [id])?)).await
+  }
+}
+
+#[async_trait]
+impl JoinRepo for SqliteRepo {
+  async fn upsert(&self, server_id: String, access_token: String, ip: String) -> RepoResult<()> {
+    self
+      .run(move |conn| {
+        if !exists(conn, r#"SELECT 1 FROM "Token" WHERE "accessToken" = ?1"#, [&access_token])? {
+          return Err(RepoError::NotFound);
+        }
+        conn.execute(
+          r#"INSERT INTO "JoinRequest" ("serverID", "accessToken", "ip", "createdAt") VALUES (?1, ?2, ?3, ?4)
+           ON CONFLICT ("serverID") DO UPDATE
+           SET "accessToken" = excluded."accessToken", "ip" = excluded."ip", "createdAt" = excluded."createdAt""#,
+          rusqlite::params![server_id, access_token, ip, Utc::now()],
+        )?;
+        Ok(())
+      })
+      .await
+  }
+
+  async fn find(&self, server_id: &str) -> RepoResult<Option<prisma::join_request::Data>> {
+    let server_id = server_id.to_owned();
+    self
+      .run(move |conn| {
+        let join_request =
+          query_one(conn, r#"SELECT * FROM "JoinRequest" WHERE "serverID" = ?1"#, [server_id], join_request_from_row)?;
+        let mut join_request = match join_request {
+          Some(x) => x,
+          None => {
+            return Ok(None);
+          },
+        };
+        let token = query_one(
+          conn,
+          r#"SELECT * FROM "Token" WHERE "accessToken" = ?1"#,
+          [&join_request.access_token],
+          token_from_row,
+        )?;
+        join_request.token = match token {
+          Some(x) => Some(Box::new(with_owner_and_profile(conn, x)?)),
+          None => None,
+        };
+        Ok(Some(join_request))
+      })
+      .await
+  }
+}
@@ -0,0 +1,1375 @@
+//! 认证服务器的公开接口, 包括 Yggdrasil 协议与材质、角色管理接口, 管理 API 挂载在 `/admin/api` 下
+
+use std::net::SocketAddr;
+
+use axum::{
+  extract::{ConnectInfo, Multipart, Path, Query, State},
+  http::{header, StatusCode},
+  response::IntoResponse,
+  routing, Json, Router,
+};
+
+use crate::{
+  admin,
+  app_state::AppState,
+  models::{
+    capes, certificates, error, gallery, login as login_model,
+    meta::meta_resp,
+    profile::{self, Profile},
+    refresh as refresh_model, services, session, textures, token as token_model,
+    user::{self, User},
+    wardrobe,
+  },
+  prisma,
+  repo::{GalleryFilter, ListParams, NameRules, RepoError, RepoResult, Repos},
+  utils::{self, auth::BearerToken, permissions, textures::TextureType},
+};
+
+pub fn router() -> Router<AppState> {
+  Router::new()
+    // API 元数据获取
+    .route("/", routing::get(index))
+    // 登录
+    .route("/authserver/authenticate", routing::post(login))
+    // 刷新
+    .route("/authserver/refresh", routing::post(refresh))
+    // 验证令牌
+    .route("/authserver/validate", routing::post(validate))
+    // 吊销令牌
+    .route("/authserver/invalidate", routing::post(invalidate))
+    // 登出
+    .route("/authserver/signout", routing::post(signout))
+    // 客户端进入服务器
+    .route("/sessionserver/session/minecraft/join", routing::post(join))
+    // 服务端验证客户端
+    .route("/sessionserver/session/minecraft/hasJoined", routing::get(has_joined))
+    // 禁止加入的服务器列表
+    .route("/blockedservers", routing::get(get_blocked_servers))
+    .route("/sessionserver/blockedservers", routing::get(get_blocked_servers))
+    // 查询角色属性
+    .route("/sessionserver/session/minecraft/profile/:uuid", routing::get(get_session_profile))
+    // 按名称批量查询角色
+    .route("/api/profiles/minecraft", routing::post(lookup_profiles))
+    // 签发聊天签名所需的密钥对
+    .route("/minecraftservices/player/certificates", routing::post(get_certificates))
+    // 用于验证角色属性与玩家证书的公钥
+    .route("/minecraftservices/publickeys", routing::get(get_public_keys))
+    // 令牌绑定的角色及其皮肤与披风
+    .route("/minecraftservices/minecraft/profile", routing::get(get_services_profile))
+    // 改名信息, 名称是否可用, 修改角色名
+    .route("/minecraftservices/minecraft/profile/namechange", routing::get(get_name_change_info))
+    .route("/minecraftservices/minecraft/profile/name/:name/available", routing::get(check_name_availability))
+    .route("/minecraftservices/minecraft/profile/name/:name", routing::put(change_services_profile_name))
+    // 创建与删除角色
+    .route("/api/user/profiles", routing::post(create_profile))
+    .route("/api/user/profiles/:uuid", routing::delete(delete_profile))
+    // 修改角色名
+    .route("/api/user/profiles/:uuid/name", routing::put(rename_profile))
+    // 按名称查询单个角色, 可指定时间
+    .route("/users/profiles/minecraft/:name", routing::get(lookup_profile_by_name))
+    .route("/api/users/profiles/minecraft/:name", routing::get(lookup_profile_by_name))
+    // 按 UUID 查询角色名
+    .route("/user/profile/:uuid", routing::get(lookup_profile_by_uuid))
+    .route("/api/user/profile/:uuid", routing::get(lookup_profile_by_uuid))
+    // 查询角色的改名记录
+    .route("/api/profile/:uuid/names", routing::get(get_name_history))
+    // 上传材质
+    .route("/api/user/profile/:uuid/:textureType", routing::put(upload_texture))
+    // 清除材质
+    .route("/api/user/profile/:uuid/:textureType", routing::delete(clear_texture))
+    // 获取材质
+    .route("/textures/:hash", routing::get(get_texture))
+    // 旧版皮肤接口, 按角色名获取皮肤
+    .route("/skins/MinecraftSkins/:name", routing::get(get_legacy_skin))
+    // 材质库
+    .route("/api/user/textures", routing::get(list_library))
+    // 重命名材质库中的材质
+    .route("/api/user/textures/:id", routing::patch(rename_library_texture))
+    // 从材质库中移除材质
+    .route("/api/user/textures/:id", routing::delete(remove_library_texture))
+    // 为角色换上材质库中的材质
+    .route("/api/user/wardrobe/:uuid", routing::put(apply_library_texture))
+    // 公开材质库
+    .route("/api/gallery", routing::get(search_gallery).post(publish_gallery_item))
+    .route("/api/gallery/:id", routing::get(get_gallery_item).delete(unpublish_gallery_item))
+    // 点赞
+    .route("/api/gallery/:id/like", routing::put(like_gallery_item).delete(unlike_gallery_item))
+    // 为角色换上公开材质
+    .route("/api/gallery/:id/apply/:uuid", routing::put(apply_gallery_item))
+    // 已获得授权的官方披风
+    .route("/api/user/capes", routing::get(list_entitled_capes))
+    // 为角色换上官方披风
+    .route("/api/user/capes/:id/apply/:uuid", routing::put(apply_entitled_cape))
+    // 管理 API
+    .nest("/admin/api", admin::router())
+}
+
+async fn index(State(state): State<AppState>) -> Json<meta_resp::GetMetadataResp> {
+  Json(meta_resp::GetMetadataResp {
+    meta: meta_resp::Meta {
+      server_name: state.settings.server_name,
+      implementation_name: state.settings.implementation_name,
+      implementation_version: state.settings.implementation_version,
+      links: meta_resp::MetaLinks { homepage: state.settings.homepage_link, register: state.settings.register_link },
+      feature_non_email_login: state.settings.features.non_email_login,
+      feature_legacy_skin_api: state.settings.features.legacy_skin_api,
+      feature_no_mojang_namespace: state.settings.features.no_mojang_namespace,
+      feature_enable_mojang_anti_features: state.settings.features.enable_mojang_anti_features,
+      feature_enable_profile_key: state.settings.features.enable_profile_key
+        && state.settings.signature.prikey_obj.is_some(),
+      feature_username_check: state.settings.features.username_check,
+    },
+    skin_domains: state.settings.skin_domains,
+    signature_publickey: state.settings.signature.pubkey,
+  })
+}
+
+/// 根据用户名匹配用户, 用户名可以是邮箱、角色名:邮箱, 开启 non-email-login 时也可以是角色名
+async fn find_login_user(
+  state: &AppState,
+  req: &login_model::req::LoginReq,
+) -> Result<(Option<prisma::profile::Data>, prisma::user::Data), login_model::LoginTransactionError> {
+  let repos = &state.repos;
+  let (profile, user) = match req.username.split_once(":") {
+    Some((dn, email)) => {
+      // 根据 角色名+邮箱 匹配用户
+      let user = repos.users.find_by_email(email).await?.ok_or(login_model::LoginTransactionError::InvalidUser)?;
+      let profile = user.profile().unwrap().iter().find(|x| x.display_name == dn).cloned();
+      match profile {
+        Some(x) => (Some(x), user),
+        None => {
+          return Err(login_model::LoginTransactionError::InvalidUser);
+        },
+      }
+    },
+    None if state.settings.features.non_email_login && !req.username.contains('@') => {
+      // 根据角色名匹配用户, 并自动选择该角色
+      let profile = repos
+        .profiles
+        .find_by_name(&req.username, state.settings.names.case_insensitive)
+        .await?
+        .ok_or(login_model::LoginTransactionError::InvalidUser)?;
+      let user =
+        repos.users.find_by_id(profile.owner_id).await?.ok_or(login_model::LoginTransactionError::InvalidUser)?;
+      (Some(profile), user)
+    },
+    None => {
+      // 根据邮箱匹配用户
+      let user =
+        repos.users.find_by_email(&req.username).await?.ok_or(login_model::LoginTransactionError::InvalidUser)?;
+      (None, user)
+    },
+  };
+  if user.password != req.password || user.disabled {
+    return Err(login_model::LoginTransactionError::WrongPassword);
+  }
+  if let Some(ban) = repos.users.active_ban(user.id, profile.as_ref().map(|x| x.id)).await? {
+    return Err(login_model::LoginTransactionError::Banned(ban));
+  }
+  Ok((profile, user))
+}
+
+async fn login(
+  State(state): State<AppState>,
+  req: Json<login_model::req::LoginReq>,
+) -> Result<Json<login_model::resp::LoginResp>, error::ErrorResponse> {
+  let access_token = utils::gen_access_token();
+  let client_token = req.client_token.clone().unwrap_or(utils::gen_uuid());
+  let request_user = match req.request_user {
+    Some(x) => x,
+    None => false,
+  };
+  let user = match find_login_user(&state, &req).await {
+    Ok(v) => {
+      tracing::debug!("匹配到用户 {:?}", v);
+      v
+    },
+    Err(e) => {
+      tracing::debug!("登录失败: {:?}", e);
+      match e {
+        login_model::LoginTransactionError::InvalidUser | login_model::LoginTransactionError::WrongPassword => {
+          return Err(error::Error::new_invalid_credentials().to_response());
+        },
+        login_model::LoginTransactionError::Banned(ban) => {
+          return Err(error::Error::new_banned(&ban).to_response());
+        },
+        _ => {
+          return Err(error::Error::new_database_error().to_response());
+        },
+      }
+    },
+  };
+
+  let add_token_result = state
+    .repos
+    .tokens
+    .create(user.1.id, user.0.as_ref().map(|x| x.id), access_token.clone(), client_token.clone())
+    .await;
+  if let Err(e) = add_token_result {
+    tracing::debug!("创建令牌失败: {:?}", e);
+    return Err(error::Error::new_database_error().to_response());
+  }
+
+  if let Err(e) = utils::check_tokens(&state.repos, &state.settings.token, user.1.id).await {
+    tracing::debug!("刷新令牌失败: {:?}", e);
+    return Err(error::Error::new_database_error().to_response());
+  }
+
+  tracing::debug!("请求: {:?}", req);
+
+  let profiles = user.1.profile().unwrap();
+  let profiles = profiles
+    .iter()
+    .map(|x| {
+      profile::Profile::from_query(x.clone())
+        .with_textures(textures::ProfileTextures::from_query(x.clone()).with_settings(state.settings.clone()))
+        .with_settings(state.settings.clone())
+    })
+    .collect();
+  let user_info = user::User { id: utils::uuid_vec_to_string(user.1.uuid), properties: vec![] };
+  tracing::debug!("角色列表: {:?}", profiles);
+  Ok(Json(login_model::resp::LoginResp {
+    access_token,
+    client_token,
+    available_profiles: profiles,
+    selected_profile: user.0.map_or_else(
+      || None,
+      |x| {
+        Some(
+          profile::Profile::from_query(x.clone())
+            .with_textures(textures::ProfileTextures::from_query(x.clone()).with_settings(state.settings.clone()))
+            .with_settings(state.settings.clone()),
+        )
+      },
+    ),
+    user: request_user.then(|| user_info),
+  }))
+}
+
+async fn refresh_token(
+  state: &AppState,
+  req: &refresh_model::req::RefreshReq,
+) -> Result<(Option<prisma::profile::Data>, prisma::user::Data, String, String), refresh_model::RefreshTransactionError>
+{
+  let repos = &state.repos;
+  let token =
+    match utils::find_checked_token(repos, &state.settings.token, &req.access_token, req.client_token.as_deref())
+      .await?
+    {
+      Some(x) if !x.owner().unwrap().disabled => x,
+      _ => {
+        return Err(refresh_model::RefreshTransactionError::InvalidToken);
+      },
+    };
+  let user = token.owner().unwrap().clone();
+  let profile = token.profile().unwrap().cloned();
+  let s_profile = match &req.selected_profile {
+    Some(x) => repos.profiles.find_by_uuid(&utils::string_to_uuid_vec(x.id.clone())).await?,
+    None => None,
+  };
+  let profile = match s_profile {
+    Some(x) => {
+      match profile {
+        Some(_y) => {
+          return Err(refresh_model::RefreshTransactionError::ReassignProfile);
+        },
+        None if x.owner_id != user.id => {
+          return Err(refresh_model::RefreshTransactionError::AssignOthersProfile);
+        },
+        None => Some(x),
+      }
+    },
+    None => profile,
+  };
+  if let Some(ban) = repos.users.active_ban(user.id, profile.as_ref().map(|x| x.id)).await? {
+    return Err(refresh_model::RefreshTransactionError::Banned(ban));
+  }
+  let client_token = match &req.client_token {
+    Some(x) => x.clone(),
+    None => token.client_token.clone(),
+  };
+  // 旧令牌立即失效, 换发新的 accessToken
+  repos.tokens.invalidate(&req.access_token).await?;
+  let access_token = utils::gen_access_token();
+  repos.tokens.create(user.id, profile.as_ref().map(|x| x.id), access_token.clone(), client_token.clone()).await?;
+  Ok((profile, user, access_token, client_token))
+}
+
+async fn refresh(
+  State(state): State<AppState>,
+  req: Json<refresh_model::req::RefreshReq>,
+) -> Result<Json<refresh_model::resp::RefreshResp>, error::ErrorResponse> {
+  let request_user = match req.request_user {
+    Some(x) => x,
+    None => false,
+  };
+  let (profile, user, access_token, client_token) = match refresh_token(&state, &req).await {
+    Ok(x) => (x.0, x.1, x.2, x.3),
+    Err(err) => {
+      match err {
+        refresh_model::RefreshTransactionError::RepoError(err) => {
+          tracing::debug!("刷新令牌失败: {:?}", err);
+          return Err(error::Error::new_database_error().to_response());
+        },
+        refresh_model::RefreshTransactionError::InvalidToken => {
+          return Err(error::Error::new_invalid_token().to_response());
+        },
+        refresh_model::RefreshTransactionError::ReassignProfile => {
+          return Err(error::Error::new_reassign_profile().to_response());
+        },
+        refresh_model::RefreshTransactionError::AssignOthersProfile => {
+          return Err(error::Error::new_assign_others_profile().to_response());
+        },
+        refresh_model::RefreshTransactionError::Banned(ban) => {
+          return Err(error::Error::new_banned(&ban).to_response());
+        },
+      }
+    },
+  };
+  Ok(Json(refresh_model::resp::RefreshResp {
+    access_token,
+    client_token,
+    selected_profile: match profile {
+      Some(x) => {
+        Some(
+          Profile::from_query(x.clone())
+            .with_textures(textures::ProfileTextures::from_query(x).with_settings(state.settings.clone()))
+            .with_settings(state.settings),
+        )
+      },
+      None => None,
+    },
+    user: request_user.then(|| User { id: utils::uuid_vec_to_string(user.uuid), properties: vec![] }),
+  }))
+}
+
+async fn validate(
+  State(state): State<AppState>,
+  Json(req): Json<token_model::req::TokenReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  match utils::find_checked_token(&state.repos, &state.settings.token, &req.access_token, req.client_token.as_deref())
+    .await
+  {
+    // 暂时失效的令牌只能用于刷新
+    Ok(Some(x)) if x.status == prisma::TokenStatus::Available && !x.owner().unwrap().disabled => {
+      Ok(StatusCode::NO_CONTENT)
+    },
+    Ok(_) => Err(error::Error::new_invalid_token().to_response()),
+    Err(err) => {
+      tracing::debug!("验证令牌失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn invalidate(
+  State(state): State<AppState>,
+  Json(req): Json<token_model::req::TokenReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  // 不检查 clientToken, 令牌不存在时同样视为成功
+  match state.repos.tokens.invalidate(&req.access_token).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("吊销令牌失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn signout(
+  State(state): State<AppState>,
+  Json(req): Json<token_model::req::SignoutReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let user = if state.settings.features.non_email_login && !req.username.contains('@') {
+    // 使用角色名登出
+    match state.repos.profiles.find_by_name(&req.username, state.settings.names.case_insensitive).await {
+      Ok(Some(x)) => state.repos.users.find_by_id(x.owner_id).await,
+      Ok(None) => Ok(None),
+      Err(err) => Err(err),
+    }
+  } else {
+    state.repos.users.find_by_email(&req.username).await
+  };
+  let user = match user {
+    Ok(Some(x)) if x.password == req.password && !x.disabled => x,
+    Ok(_) => {
+      return Err(error::Error::new_invalid_credentials().to_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询用户失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  match state.repos.tokens.invalidate_by_owner(user.id).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("吊销令牌失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn get_session_profile(
+  State(state): State<AppState>,
+  Path(uuid): Path<String>,
+  Query(query): Query<session::req::ProfileQuery>,
+) -> Result<axum::response::Response, error::ErrorResponse> {
+  let profile = state.repos.profiles.find_by_uuid(&utils::string_to_uuid_vec(uuid)).await;
+  let profile = match profile {
+    Ok(Some(x)) => x,
+    Ok(None) => {
+      return Ok(StatusCode::NO_CONTENT.into_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  let profile = Profile::from_query(profile.clone())
+    .with_textures(textures::ProfileTextures::from_query(profile).with_settings(state.settings.clone()))
+    .with_settings(state.settings);
+  if query.unsigned.unwrap_or(true) {
+    return Ok(Json(profile.without_signatures()).into_response());
+  }
+  Ok(Json(profile).into_response())
+}
+
+async fn join(
+  State(state): State<AppState>,
+  ConnectInfo(addr): ConnectInfo<SocketAddr>,
+  Json(req): Json<session::req::JoinReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let token = match utils::find_checked_token(&state.repos, &state.settings.token, &req.access_token, None).await {
+    Ok(Some(x)) if x.status == prisma::TokenStatus::Available && !x.owner().unwrap().disabled => x,
+    Ok(_) => {
+      return Err(error::Error::new_invalid_token().to_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询令牌失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  // 令牌必须绑定到客户端选择的角色
+  let profile = match token.profile().unwrap() {
+    Some(x) if x.uuid == utils::string_to_uuid_vec(req.selected_profile.clone()) => x,
+    _ => {
+      return Err(error::Error::new_invalid_profile().to_response());
+    },
+  };
+  match state.repos.users.active_ban(token.owner_id, Some(profile.id)).await {
+    Ok(Some(ban)) => {
+      return Err(error::Error::new_banned(&ban).to_response());
+    },
+    Ok(None) => {},
+    Err(err) => {
+      tracing::debug!("查询封禁失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  }
+  let ip = addr.ip().to_string();
+  match state.repos.joins.upsert(req.server_id, token.access_token.clone(), ip).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("记录加入服务器请求失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn has_joined(
+  State(state): State<AppState>,
+  Query(query): Query<session::req::HasJoinedQuery>,
+) -> Result<axum::response::Response, error::ErrorResponse> {
+  let join_request = match state.repos.joins.find(&query.server_id).await {
+    Ok(Some(x)) => x,
+    Ok(None) => {
+      return Ok(StatusCode::NO_CONTENT.into_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询加入服务器请求失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  let access_token = &join_request.token().unwrap().access_token;
+  let token = match utils::find_checked_token(&state.repos, &state.settings.token, access_token, None).await {
+    Ok(Some(x)) => x,
+    Ok(None) => {
+      return Ok(StatusCode::NO_CONTENT.into_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询令牌失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  // 请求过期、IP 不一致、令牌失效或角色名不匹配时, 视为未加入
+  let expired = chrono::Utc::now().timestamp() - join_request.created_at.timestamp() > session::JOIN_EXPIRE_SECONDS;
+  let ip_mismatch = query.ip.as_ref().is_some_and(|x| *x != join_request.ip);
+  let profile = match token.profile().unwrap() {
+    Some(x)
+      if !expired
+        && !ip_mismatch
+        && token.status == prisma::TokenStatus::Available
+        && !token.owner().unwrap().disabled
+        && x.display_name == query.username =>
+    {
+      x.clone()
+    },
+    _ => {
+      return Ok(StatusCode::NO_CONTENT.into_response());
+    },
+  };
+  match state.repos.users.active_ban(token.owner_id, Some(profile.id)).await {
+    Ok(Some(ban)) => {
+      return Err(error::Error::new_banned(&ban).to_response());
+    },
+    Ok(None) => {},
+    Err(err) => {
+      tracing::debug!("查询封禁失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  }
+  Ok(
+    Json(
+      Profile::from_query(profile.clone())
+        .with_textures(textures::ProfileTextures::from_query(profile).with_settings(state.settings.clone()))
+        .with_settings(state.settings),
+    )
+    .into_response(),
+  )
+}
+
+async fn find_owned_profile(
+  state: &AppState,
+  owner_id: i64,
+  uuid: String,
+) -> Result<prisma::profile::Data, error::ErrorResponse> {
+  match state.repos.profiles.find_by_uuid(&utils::string_to_uuid_vec(uuid)).await {
+    Ok(Some(x)) if x.owner_id == owner_id => Ok(x),
+    Ok(_) => Err(error::Error::new_assign_others_profile().to_response()),
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 额外材质与披风遵循相同的上传限制
+fn texture_allowed(profile: &prisma::profile::Data, texture_type: TextureType) -> bool {
+  match (profile.uploadable_textures, texture_type) {
+    (prisma::UploadableTextures::SkinAndCape, _) => true,
+    (prisma::UploadableTextures::SkinOnly, TextureType::Skin) => true,
+    _ => false,
+  }
+}
+
+/// 为令牌绑定的角色签发密钥对, 需要开启 enable-profile-key 并配置签名私钥
+async fn get_certificates(
+  State(state): State<AppState>,
+  token: BearerToken,
+) -> Result<Json<certificates::resp::CertificatesResp>, error::ErrorResponse> {
+  if !state.settings.features.enable_profile_key {
+    return Err(error::Error::new_not_found().to_response());
+  }
+  let profile = match token.profile() {
+    Some(x) => x,
+    None => {
+      return Err(error::Error::new_invalid_profile().to_response());
+    },
+  };
+  match utils::profile_keys::get_or_rotate(&state.repos, &state.settings, profile).await {
+    Ok(x) => Ok(Json(certificates::resp::CertificatesResp::from_query(x))),
+    Err(utils::profile_keys::ProfileKeyError::NoSigningKey) => Err(error::Error::new_not_found().to_response()),
+    Err(err) => {
+      tracing::error!("签发角色密钥对失败: {}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn get_public_keys(State(state): State<AppState>) -> Json<certificates::resp::PublicKeysResp> {
+  Json(certificates::resp::PublicKeysResp::from_settings(&state.settings))
+}
+
+/// 令牌绑定的角色, 附带皮肤与披风, 令牌未绑定角色时返回 404
+async fn find_token_profile(
+  state: &AppState,
+  token: &BearerToken,
+) -> Result<prisma::profile::Data, error::ErrorResponse> {
+  match token.profile() {
+    Some(x) => find_profile_with_textures(state, x.id).await,
+    None => Err(error::Error::new_not_found().to_response()),
+  }
+}
+
+async fn find_profile_with_textures(
+  state: &AppState,
+  profile_id: i64,
+) -> Result<prisma::profile::Data, error::ErrorResponse> {
+  match state.repos.profiles.find_by_id(profile_id).await {
+    Ok(Some(x)) => Ok(x),
+    Ok(None) => Err(error::Error::new_not_found().to_response()),
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn services_profile_resp(
+  state: &AppState,
+  profile: prisma::profile::Data,
+) -> Result<services::resp::ProfileResp, error::ErrorResponse> {
+  match state.repos.entitlements.list(profile.owner_id).await {
+    Ok(x) => Ok(services::resp::ProfileResp::from_query(profile, x, &state.settings)),
+    Err(err) => {
+      tracing::debug!("查询官方披风失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn get_services_profile(
+  State(state): State<AppState>,
+  token: BearerToken,
+) -> Result<Json<services::resp::ProfileResp>, error::ErrorResponse> {
+  let profile = find_token_profile(&state, &token).await?;
+  Ok(Json(services_profile_resp(&state, profile).await?))
+}
+
+async fn get_name_change_info(
+  State(state): State<AppState>,
+  token: BearerToken,
+) -> Result<Json<services::resp::NameChangeResp>, error::ErrorResponse> {
+  let profile = find_token_profile(&state, &token).await?;
+  let renamed_at = match state.repos.profiles.last_renamed_at(profile.id).await {
+    Ok(x) => x,
+    Err(err) => {
+      tracing::debug!("查询改名记录失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  let cooldown = state.settings.profiles.rename_cooldown;
+  Ok(Json(services::resp::NameChangeResp {
+    changed_at: renamed_at.map(|x| x.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+    created_at: profile.created_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    name_change_allowed: renamed_at.map_or(true, |x| chrono::Utc::now().timestamp() - x.timestamp() >= cooldown),
+  }))
+}
+
+async fn check_name_availability(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(name): Path<String>,
+) -> Result<Json<services::resp::NameAvailabilityResp>, error::ErrorResponse> {
+  let status = if utils::profiles::validate_name(&name, &state.settings.names).is_err() {
+    "NOT_ALLOWED"
+  } else {
+    let except_profile = token.profile().map(|x| x.id);
+    let rules = NameRules::new(&state.settings);
+    match state.repos.profiles.name_available(&name, &rules, except_profile).await {
+      Ok(true) => "AVAILABLE",
+      Ok(false) => "DUPLICATE",
+      Err(err) => {
+        tracing::debug!("查询角色名失败: {:?}", err);
+        return Err(error::Error::new_database_error().to_response());
+      },
+    }
+  };
+  Ok(Json(services::resp::NameAvailabilityResp { status: status.to_owned() }))
+}
+
+/// 修改令牌绑定的角色的名称, 与 /api/user/profiles/:uuid/name 规则相同
+async fn change_services_profile_name(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(name): Path<String>,
+) -> Result<Json<services::resp::ProfileResp>, error::ErrorResponse> {
+  let profile = find_token_profile(&state, &token).await?;
+  let renamed = rename_owned_profile(&state, profile, name).await?;
+  let profile = find_profile_with_textures(&state, renamed.id).await?;
+  Ok(Json(services_profile_resp(&state, profile).await?))
+}
+
+/// 角色操作的错误, 名称冲突、数量上限与冷却时间返回对应的错误信息
+fn profile_repo_error(err: RepoError) -> error::ErrorResponse {
+  match err {
+    RepoError::LimitReached(limit) => error::Error::new_profile_limit_reached(limit).to_response(),
+    RepoError::Cooldown(remaining) => error::Error::new_cooldown(remaining).to_response(),
+    err if err.is_conflict() => error::Error::new_conflict().to_response(),
+    err => {
+      tracing::debug!("角色操作失败: {:?}", err);
+      error::Error::new_database_error().to_response()
+    },
+  }
+}
+
+async fn create_profile(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Json(req): Json<profile::req::CreateProfileReq>,
+) -> Result<Json<Profile>, error::ErrorResponse> {
+  let name = req.name.trim().to_owned();
+  if let Err(message) = utils::profiles::validate_name(&name, &state.settings.names) {
+    return Err(error::Error::new_illegal_argument(&message).to_response());
+  }
+  let max_profiles = permissions::max_profiles(token.owner(), &state.settings);
+  let uuid = utils::profiles::new_profile_uuid(&name, &state.settings);
+  let rules = NameRules::new(&state.settings);
+  let result = state.repos.profiles.create(uuid, name, token.owner().id, &rules, max_profiles).await;
+  let created = result.map_err(profile_repo_error)?;
+  Ok(Json(Profile::from_query(created).with_settings(state.settings)))
+}
+
+/// 删除角色, 两次删除之间需要等待配置中的冷却时间
+async fn delete_profile(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(uuid): Path<String>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  let cooldown = state.settings.profiles.delete_cooldown;
+  state.repos.profiles.delete(profile.id, Some(cooldown)).await.map_err(profile_repo_error)?;
+  Ok(StatusCode::NO_CONTENT)
+}
+
+/// 修改角色名, 两次改名之间需要等待配置中的冷却时间
+async fn rename_owned_profile(
+  state: &AppState,
+  profile: prisma::profile::Data,
+  name: String,
+) -> Result<prisma::profile::Data, error::ErrorResponse> {
+  let name = name.trim().to_owned();
+  if let Err(message) = utils::profiles::validate_name(&name, &state.settings.names) {
+    return Err(error::Error::new_illegal_argument(&message).to_response());
+  }
+  if profile.display_name == name {
+    return Err(error::Error::new_illegal_argument("The profile already has this name.").to_response());
+  }
+  let cooldown = state.settings.profiles.rename_cooldown;
+  let rules = NameRules::new(&state.settings);
+  state.repos.profiles.rename(profile.id, name, &rules, Some(cooldown)).await.map_err(profile_repo_error)
+}
+
+async fn rename_profile(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(uuid): Path<String>,
+  Json(req): Json<profile::req::RenameProfileReq>,
+) -> Result<Json<Profile>, error::ErrorResponse> {
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  let renamed = rename_owned_profile(&state, profile, req.name).await?;
+  Ok(Json(Profile::from_query(renamed).with_settings(state.settings)))
+}
+
+/// 按名称批量查询角色, 空白与重复的名称会被忽略
+/// 不按命名规则过滤, 管理员强制设置的名称同样可以查到
+async fn lookup_profiles(
+  State(state): State<AppState>,
+  Json(names): Json<Vec<String>>,
+) -> Result<Json<Vec<profile::resp::ProfileName>>, error::ErrorResponse> {
+  let rules = &state.settings.names;
+  let mut normalized: Vec<String> = vec![];
+  for name in names.iter().map(|x| x.trim()) {
+    if name.is_empty() {
+      continue;
+    }
+    let duplicated = normalized.iter().any(|x| {
+      match rules.case_insensitive {
+        true => x.to_lowercase() == name.to_lowercase(),
+        false => x == name,
+      }
+    });
+    if !duplicated {
+      normalized.push(name.to_owned());
+    }
+  }
+  if normalized.len() > profile::BATCH_LOOKUP_LIMIT {
+    return Err(
+      error::Error::new_illegal_argument(&format!(
+        "Too many names, at most {} are allowed.",
+        profile::BATCH_LOOKUP_LIMIT
+      ))
+      .to_response(),
+    );
+  }
+  if normalized.is_empty() {
+    return Ok(Json(vec![]));
+  }
+  match state.repos.profiles.find_by_names(&normalized, rules.case_insensitive).await {
+    Ok(x) => Ok(Json(x.into_iter().map(profile::resp::ProfileName::from_query).collect())),
+    Err(err) => {
+      tracing::debug!("批量查询角色失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 角色不存在时返回 204
+async fn lookup_profile_by_name(
+  State(state): State<AppState>,
+  Path(name): Path<String>,
+  Query(query): Query<profile::req::NameLookupQuery>,
+) -> Result<axum::response::Response, error::ErrorResponse> {
+  let rules = &state.settings.names;
+  let profile = match query.at {
+    Some(at) => {
+      let at = match chrono::DateTime::from_timestamp(at, 0) {
+        Some(x) => x.into(),
+        None => {
+          return Err(error::Error::new_illegal_argument("Invalid timestamp.").to_response());
+        },
+      };
+      state.repos.profiles.find_by_name_at(&name, at, rules.case_insensitive).await
+    },
+    None => state.repos.profiles.find_by_name(&name, rules.case_insensitive).await,
+  };
+  match profile {
+    Ok(Some(x)) => Ok(Json(profile::resp::ProfileName::from_query(x)).into_response()),
+    Ok(None) => Ok(StatusCode::NO_CONTENT.into_response()),
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 角色不存在时返回 204
+async fn lookup_profile_by_uuid(
+  State(state): State<AppState>,
+  Path(uuid): Path<String>,
+) -> Result<axum::response::Response, error::ErrorResponse> {
+  match state.repos.profiles.find_by_uuid(&utils::string_to_uuid_vec(uuid)).await {
+    Ok(Some(x)) => Ok(Json(profile::resp::ProfileName::from_query(x)).into_response()),
+    Ok(None) => Ok(StatusCode::NO_CONTENT.into_response()),
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 角色的改名记录, 按时间升序排列
+async fn get_name_history(
+  State(state): State<AppState>,
+  Path(uuid): Path<String>,
+) -> Result<Json<Vec<profile::resp::NameHistoryEntry>>, error::ErrorResponse> {
+  let profile = match state.repos.profiles.find_by_uuid(&utils::string_to_uuid_vec(uuid)).await {
+    Ok(Some(x)) => x,
+    Ok(None) => {
+      return Err(error::Error::new_not_found().to_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  match state.repos.profiles.name_history(profile.id).await {
+    Ok(x) => Ok(Json(profile::resp::NameHistoryEntry::from_query(x, profile.display_name))),
+    Err(err) => {
+      tracing::debug!("查询改名记录失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 为角色换上上传的材质, 皮肤与披风同时加入上传者的材质库
+async fn apply_uploaded_texture(
+  repos: &Repos,
+  profile: &prisma::profile::Data,
+  texture_type: TextureType,
+  hash: Vec<u8>,
+  model: prisma::SkinType,
+  frame_rate: Option<i32>,
+  name: String,
+) -> RepoResult<()> {
+  match texture_type {
+    TextureType::Skin => {
+      let skin = repos.textures.find_or_create_skin(hash, model).await?;
+      repos.profiles.set_skin(profile.id, Some(skin.id)).await?;
+      repos.textures.add_to_library(profile.owner_id, name, Some(skin.id), None).await
+    },
+    TextureType::Cape => {
+      let cape = repos.textures.find_or_create_cape(hash, frame_rate).await?;
+      repos.profiles.set_cape(profile.id, Some(cape.id)).await?;
+      repos.textures.add_to_library(profile.owner_id, name, None, Some(cape.id)).await
+    },
+    // 额外材质直接绑定在角色上, 不进入材质库
+    TextureType::Elytra | TextureType::Ears => {
+      repos.textures.set_extra(profile.id, texture_type.extra().unwrap(), hash).await
+    },
+  }
+}
+
+async fn upload_texture(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path((uuid, texture_type)): Path<(String, String)>,
+  mut multipart: Multipart,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let texture_type = TextureType::from_path(&texture_type)
+    .filter(|x| x.enabled(&state.settings))
+    .ok_or(error::Error::new_not_found().to_response())?;
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  if !texture_allowed(&profile, texture_type) {
+    return Err(error::Error::new_texture_not_uploadable().to_response());
+  }
+
+  let mut model = prisma::SkinType::Default;
+  let mut frame_rate: Option<u32> = None;
+  let mut file: Option<(String, Vec<u8>)> = None;
+  while let Ok(Some(field)) = multipart.next_field().await {
+    match field.name() {
+      Some("model") => {
+        if field.text().await.unwrap_or_default() == "slim" {
+          model = prisma::SkinType::Slim;
+        }
+      },
+      Some("frameRate") => {
+        frame_rate = field.text().await.ok().and_then(|x| x.trim().parse().ok());
+      },
+      Some("file") => {
+        let file_name = field.file_name().unwrap_or_default().to_owned();
+        match field.bytes().await {
+          Ok(x) => file = Some((file_name, x.to_vec())),
+          Err(_err) => {
+            return Err(error::Error::new_invalid_texture("Failed to read the texture file.").to_response());
+          },
+        }
+      },
+      _ => {},
+    }
+  }
+  let (file_name, data) = file.ok_or(error::Error::new_invalid_texture("Missing texture file.").to_response())?;
+  let checked = utils::textures::validate(&data, texture_type, &state.settings).and_then(|(width, height)| {
+    match texture_type {
+      TextureType::Cape => {
+        let frames = utils::textures::cape_frames(width, height).unwrap_or(1);
+        utils::textures::validate_frame_rate(frames, frame_rate, &state.settings).map(|x| (width, x))
+      },
+      _ => Ok((width, None)),
+    }
+  });
+  let (width, frame_rate) = match checked {
+    Ok(x) => x,
+    Err(err) => {
+      tracing::debug!("材质校验失败: {}", err);
+      return Err(error::Error::new_invalid_texture(err.message()).to_response());
+    },
+  };
+  // 宽度超过 64 的高清皮肤需要单独的权限
+  if texture_type == TextureType::Skin
+    && width > 64
+    && !permissions::has_permission(token.owner(), prisma::Permission::UploadHdSkin)
+  {
+    return Err(error::Error::new_permission_denied().to_response());
+  }
+
+  let hash = utils::textures::hash(&data);
+  if let Err(err) = utils::textures::save(&state.settings, &utils::texture_vec_to_string(hash.clone()), &data).await {
+    tracing::error!("保存材质失败: {}", err);
+    return Err(error::Error::new_database_error().to_response());
+  }
+
+  let name = match file_name.trim_end_matches(".png") {
+    "" => format!("{}-{}", profile.display_name, chrono::Utc::now().format("%Y%m%d%H%M%S")),
+    x => x.to_owned(),
+  };
+  let result = apply_uploaded_texture(&state.repos, &profile, texture_type, hash, model, frame_rate, name).await;
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("上传材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn clear_texture(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path((uuid, texture_type)): Path<(String, String)>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let texture_type = TextureType::from_path(&texture_type).ok_or(error::Error::new_not_found().to_response())?;
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  let result = match texture_type {
+    TextureType::Skin | TextureType::Cape => {
+      match texture_type {
+        TextureType::Skin => state.repos.profiles.set_skin(profile.id, None).await,
+        _ => state.repos.profiles.set_cape(profile.id, None).await,
+      }
+    },
+    TextureType::Elytra | TextureType::Ears => {
+      state.repos.textures.clear_extra(profile.id, texture_type.extra().unwrap()).await.map(|_| ())
+    },
+  };
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("清除材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn get_texture(
+  State(state): State<AppState>,
+  Path(hash): Path<String>,
+) -> Result<impl IntoResponse, error::ErrorResponse> {
+  match utils::textures::load(&state.settings, &hash).await {
+    Ok(data) => Ok(([(header::CONTENT_TYPE, "image/png")], data)),
+    Err(_err) => Err(error::Error::new_not_found().to_response()),
+  }
+}
+
+/// 每行一个主机名模式的 SHA-1, 与 Mojang 的格式相同
+async fn get_blocked_servers(State(state): State<AppState>) -> Result<impl IntoResponse, error::ErrorResponse> {
+  match state.repos.blocked_servers.list(ListParams::all()).await {
+    Ok((x, _)) => {
+      let body: Vec<String> = x.into_iter().map(|x| x.hash).collect();
+      Ok(([(header::CONTENT_TYPE, "text/plain")], body.join("\n")))
+    },
+    Err(err) => {
+      tracing::debug!("查询服务器黑名单失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+/// 路径为 /skins/MinecraftSkins/{角色名}.png, 需要开启 legacy-skin-api
+async fn get_legacy_skin(
+  State(state): State<AppState>,
+  Path(name): Path<String>,
+) -> Result<impl IntoResponse, error::ErrorResponse> {
+  let name = match name.strip_suffix(".png") {
+    Some(x) if state.settings.features.legacy_skin_api => x.to_owned(),
+    _ => {
+      return Err(error::Error::new_not_found().to_response());
+    },
+  };
+  let profile = state.repos.profiles.find_by_name(&name, state.settings.names.case_insensitive).await;
+  let hash = match profile {
+    Ok(Some(x)) => {
+      match x.skin().ok().flatten() {
+        Some(skin) => utils::texture_vec_to_string(skin.hash.clone()),
+        None => {
+          return Err(error::Error::new_not_found().to_response());
+        },
+      }
+    },
+    Ok(None) => {
+      return Err(error::Error::new_not_found().to_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询角色失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  };
+  match utils::textures::load(&state.settings, &hash).await {
+    Ok(data) => Ok(([(header::CONTENT_TYPE, "image/png")], data)),
+    Err(_err) => Err(error::Error::new_not_found().to_response()),
+  }
+}
+
+async fn list_library(
+  State(state): State<AppState>,
+  token: BearerToken,
+) -> Result<Json<wardrobe::resp::LibraryResp>, error::ErrorResponse> {
+  match state.repos.textures.list_library(token.owner().id).await {
+    Ok(x) => {
+      Ok(Json(wardrobe::resp::LibraryResp {
+        textures: x
+          .into_iter()
+          .filter_map(|t| wardrobe::resp::LibraryTexture::from_query(t, &state.settings))
+          .collect(),
+      }))
+    },
+    Err(err) => {
+      tracing::debug!("查询材质库失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn find_library_texture(
+  state: &AppState,
+  owner_id: i64,
+  id: i64,
+) -> Result<prisma::user_texture::Data, error::ErrorResponse> {
+  match state.repos.textures.find_library_texture(owner_id, id).await {
+    Ok(Some(x)) => Ok(x),
+    Ok(None) => Err(error::Error::new_not_found().to_response()),
+    Err(err) => {
+      tracing::debug!("查询材质库失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn rename_library_texture(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(id): Path<i64>,
+  Json(req): Json<wardrobe::req::RenameTextureReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let texture = find_library_texture(&state, token.owner().id, id).await?;
+  let name = req.name.trim().to_owned();
+  if name.is_empty() {
+    return Err(error::Error::new_invalid_texture("Texture name must not be empty.").to_response());
+  }
+  match state.repos.textures.rename_library_texture(texture.id, name).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("重命名材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn remove_library_texture(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let texture = find_library_texture(&state, token.owner().id, id).await?;
+  // 只从材质库中移除, 正在使用该材质的角色不受影响
+  match state.repos.textures.remove_library_texture(texture.id).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("移除材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn apply_library_texture(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(uuid): Path<String>,
+  Json(req): Json<wardrobe::req::ApplyTextureReq>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  let texture = find_library_texture(&state, token.owner().id, req.texture_id).await?;
+  let result = if let Ok(Some(skin)) = texture.skin() {
+    if !texture_allowed(&profile, TextureType::Skin) {
+      return Err(error::Error::new_texture_not_uploadable().to_response());
+    }
+    state.repos.profiles.set_skin(profile.id, Some(skin.id)).await
+  } else if let Ok(Some(cape)) = texture.cape() {
+    if !texture_allowed(&profile, TextureType::Cape) {
+      return Err(error::Error::new_texture_not_uploadable().to_response());
+    }
+    state.repos.profiles.set_cape(profile.id, Some(cape.id)).await
+  } else {
+    return Err(error::Error::new_not_found().to_response());
+  };
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("更换材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn search_gallery(
+  State(state): State<AppState>,
+  Query(query): Query<gallery::req::SearchQuery>,
+) -> Result<Json<gallery::resp::SearchResp>, error::ErrorResponse> {
+  let page = query.page.unwrap_or(1).max(1);
+  let page_size = query.page_size.unwrap_or(gallery::DEFAULT_PAGE_SIZE).clamp(1, gallery::MAX_PAGE_SIZE);
+  let filter = GalleryFilter {
+    title: query.q.map(|x| x.trim().to_owned()).filter(|x| !x.is_empty()),
+    tag: query.tag.map(|x| x.trim().to_lowercase()).filter(|x| !x.is_empty()),
+    texture_type: query.texture_type.as_deref().and_then(TextureType::from_path),
+    popular: query.sort.as_deref() == Some("popular"),
+  };
+  let result = state.repos.gallery.search(filter, (page - 1) * page_size, page_size).await;
+  match result {
+    Ok((items, total)) => {
+      Ok(Json(gallery::resp::SearchResp {
+        items: items.into_iter().filter_map(|x| gallery::resp::GalleryItem::from_query(x, &state.settings)).collect(),
+        page,
+        page_size,
+        total,
+      }))
+    },
+    Err(err) => {
+      tracing::debug!("查询公开材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn find_gallery_item(state: &AppState, id: i64) -> Result<prisma::gallery_item::Data, error::ErrorResponse> {
+  match state.repos.gallery.find(id).await {
+    Ok(Some(x)) => Ok(x),
+    Ok(None) => Err(error::Error::new_not_found().to_response()),
+    Err(err) => {
+      tracing::debug!("查询公开材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn get_gallery_item(
+  State(state): State<AppState>,
+  Path(id): Path<i64>,
+) -> Result<Json<gallery::resp::GalleryItem>, error::ErrorResponse> {
+  let item = find_gallery_item(&state, id).await?;
+  gallery::resp::GalleryItem::from_query(item, &state.settings)
+    .map(Json)
+    .ok_or(error::Error::new_not_found().to_response())
+}
+
+async fn publish_gallery_item(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Json(req): Json<gallery::req::PublishReq>,
+) -> Result<Json<gallery::resp::GalleryItem>, error::ErrorResponse> {
+  let texture = find_library_texture(&state, token.owner().id, req.texture_id).await?;
+  let title = req.title.trim().to_owned();
+  if title.is_empty() {
+    return Err(error::Error::new_illegal_argument("Title must not be empty.").to_response());
+  }
+  let (skin_id, cape_id) = match (texture.skin_id, texture.cape_id) {
+    (Some(x), _) => (Some(x), None),
+    (None, Some(x)) => (None, Some(x)),
+    (None, None) => {
+      return Err(error::Error::new_not_found().to_response());
+    },
+  };
+  let tags = gallery::normalize_tags(req.tags);
+  match state.repos.gallery.publish(token.owner().id, title, tags, skin_id, cape_id).await {
+    Ok(x) => {
+      gallery::resp::GalleryItem::from_query(x, &state.settings)
+        .map(Json)
+        .ok_or(error::Error::new_not_found().to_response())
+    },
+    Err(err) => {
+      tracing::debug!("发布材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn unpublish_gallery_item(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let item = find_gallery_item(&state, id).await?;
+  if item.uploader_id != token.owner().id {
+    return Err(error::Error::new_not_found().to_response());
+  }
+  match state.repos.gallery.delete(item.id).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("取消发布材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn like_gallery_item(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let item = find_gallery_item(&state, id).await?;
+  let result = state.repos.gallery.like(item.id, token.owner().id).await;
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("点赞失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn unlike_gallery_item(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path(id): Path<i64>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let item = find_gallery_item(&state, id).await?;
+  let result = state.repos.gallery.unlike(item.id, token.owner().id).await;
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("取消点赞失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn apply_gallery_item(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path((id, uuid)): Path<(i64, String)>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let item = find_gallery_item(&state, id).await?;
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  let result = if let Some(skin_id) = item.skin_id {
+    if !texture_allowed(&profile, TextureType::Skin) {
+      return Err(error::Error::new_texture_not_uploadable().to_response());
+    }
+    state.repos.profiles.set_skin(profile.id, Some(skin_id)).await
+  } else if let Some(cape_id) = item.cape_id {
+    if !texture_allowed(&profile, TextureType::Cape) {
+      return Err(error::Error::new_texture_not_uploadable().to_response());
+    }
+    state.repos.profiles.set_cape(profile.id, Some(cape_id)).await
+  } else {
+    return Err(error::Error::new_not_found().to_response());
+  };
+  match result {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("更换材质失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn list_entitled_capes(
+  State(state): State<AppState>,
+  token: BearerToken,
+) -> Result<Json<capes::resp::EntitledCapesResp>, error::ErrorResponse> {
+  match state.repos.entitlements.list(token.owner().id).await {
+    Ok(x) => {
+      Ok(Json(capes::resp::EntitledCapesResp {
+        capes: x.into_iter().filter_map(|e| capes::resp::EntitledCape::from_query(e, &state.settings)).collect(),
+      }))
+    },
+    Err(err) => {
+      tracing::debug!("查询官方披风失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
+
+async fn apply_entitled_cape(
+  State(state): State<AppState>,
+  token: BearerToken,
+  Path((id, uuid)): Path<(i64, String)>,
+) -> Result<StatusCode, error::ErrorResponse> {
+  let profile = find_owned_profile(&state, token.owner().id, uuid).await?;
+  // 官方披风不受 uploadableTextures 限制
+  match state.repos.entitlements.find(token.owner().id, id).await {
+    Ok(Some(_)) => {},
+    Ok(None) => {
+      return Err(error::Error::new_not_found().to_response());
+    },
+    Err(err) => {
+      tracing::debug!("查询官方披风失败: {:?}", err);
+      return Err(error::Error::new_database_error().to_response());
+    },
+  }
+  match state.repos.profiles.set_cape(profile.id, Some(id)).await {
+    Ok(_) => Ok(StatusCode::NO_CONTENT),
+    Err(err) => {
+      tracing::debug!("更换披风失败: {:?}", err);
+      Err(error::Error::new_database_error().to_response())
+    },
+  }
+}
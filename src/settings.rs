@@ -1,8 +1,10 @@
+use regex::Regex;
 use rsa::{
   pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
   RsaPrivateKey, RsaPublicKey,
 };
 use serde::Deserialize;
+use tokio::fs;
 
 fn default_server_name() -> String {
   "认证服务器".to_string()
@@ -54,6 +56,23 @@ fn default_textures_base() -> String {
   "http://127.0.0.1:2345/textures/".to_owned()
 }
 
+fn default_textures_dir() -> String {
+  "textures".to_owned()
+}
+
+fn default_textures_max_cape_frames() -> u32 {
+  // 1 表示不允许动态披风
+  16
+}
+
+fn default_textures_max_frame_rate() -> u32 {
+  30
+}
+
+fn default_textures_extra_types() -> Vec<String> {
+  vec![]
+}
+
 fn default_textures_max_size() -> u64 {
   // max 16 MB
   2 * 1024
@@ -126,11 +145,291 @@ pub struct Textures {
   #[serde(rename = "base", default = "default_textures_base")]
   pub base: String,
 
+  #[serde(rename = "dir", default = "default_textures_dir")]
+  pub dir: String,
+
   #[serde(rename = "max-size-kb", default = "default_textures_max_size")]
   pub max_size: u64,
 
   #[serde(rename = "max-length", default = "default_textures_max_length")]
   pub max_length: u64,
+
+  /// 动态披风的最大帧数
+  #[serde(rename = "max-cape-frames", default = "default_textures_max_cape_frames")]
+  pub max_cape_frames: u32,
+
+  /// 动态披风的最大帧率
+  #[serde(rename = "max-frame-rate", default = "default_textures_max_frame_rate")]
+  pub max_frame_rate: u32,
+
+  /// 启用的额外材质类型, 可选 elytra 与 ears
+  #[serde(rename = "extra-types", default = "default_textures_extra_types")]
+  pub extra_types: Vec<String>,
+}
+
+fn default_permissions_player_max_profiles() -> i64 {
+  1
+}
+
+fn default_permissions_moderator_max_profiles() -> i64 {
+  5
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Permissions {
+  /// 普通玩家最多可以拥有的角色数量
+  #[serde(rename = "player-max-profiles", default = "default_permissions_player_max_profiles")]
+  pub player_max_profiles: i64,
+
+  /// 管理员与版主最多可以拥有的角色数量, 拥有 UnlimitedProfiles 权限时不受限制
+  #[serde(rename = "moderator-max-profiles", default = "default_permissions_moderator_max_profiles")]
+  pub moderator_max_profiles: i64,
+}
+
+impl Default for Permissions {
+  fn default() -> Self {
+    Self {
+      player_max_profiles: default_permissions_player_max_profiles(),
+      moderator_max_profiles: default_permissions_moderator_max_profiles(),
+    }
+  }
+}
+
+fn default_profiles_delete_cooldown() -> i64 {
+  86400
+}
+
+fn default_profiles_rename_cooldown() -> i64 {
+  2592000
+}
+
+fn default_profiles_name_reserve_duration() -> i64 {
+  3196800
+}
+
+/// 新角色 UUID 的生成方式
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UuidStrategy {
+  /// 随机生成 (v4)
+  #[default]
+  #[serde(rename = "random")]
+  Random,
+
+  /// 与 Java 版离线模式相同, 由 `OfflinePlayer:<角色名>` 计算 (v3)
+  #[serde(rename = "offline")]
+  Offline,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Profiles {
+  /// 用户两次删除角色之间需要间隔的时间 (秒)
+  #[serde(rename = "delete-cooldown", default = "default_profiles_delete_cooldown")]
+  pub delete_cooldown: i64,
+
+  /// 角色两次改名之间需要间隔的时间 (秒)
+  #[serde(rename = "rename-cooldown", default = "default_profiles_rename_cooldown")]
+  pub rename_cooldown: i64,
+
+  /// 改名后旧名称保留给原角色的时间 (秒), 期间其他角色无法使用
+  #[serde(rename = "name-reserve-duration", default = "default_profiles_name_reserve_duration")]
+  pub name_reserve_duration: i64,
+
+  /// 从离线模式服务器迁移时使用 offline, 以保留玩家数据
+  #[serde(rename = "uuid-strategy", default)]
+  pub uuid_strategy: UuidStrategy,
+}
+
+impl Default for Profiles {
+  fn default() -> Self {
+    Self {
+      delete_cooldown: default_profiles_delete_cooldown(),
+      rename_cooldown: default_profiles_rename_cooldown(),
+      name_reserve_duration: default_profiles_name_reserve_duration(),
+      uuid_strategy: UuidStrategy::default(),
+    }
+  }
+}
+
+fn default_names_charset() -> String {
+  "[A-Za-z0-9_]".to_owned()
+}
+
+fn default_names_min_length() -> usize {
+  3
+}
+
+fn default_names_max_length() -> usize {
+  16
+}
+
+fn default_names_case_insensitive() -> bool {
+  true
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Names {
+  /// 角色名中每个字符需要匹配的正则表达式
+  #[serde(rename = "charset", default = "default_names_charset")]
+  pub charset: String,
+
+  #[serde(skip)]
+  pub charset_obj: Option<Regex>,
+
+  #[serde(rename = "min-length", default = "default_names_min_length")]
+  pub min_length: usize,
+
+  #[serde(rename = "max-length", default = "default_names_max_length")]
+  pub max_length: usize,
+
+  /// 允许中日韩文字, 部分原版客户端无法正常显示这些角色名
+  #[serde(rename = "allow-cjk", default)]
+  pub allow_cjk: bool,
+
+  /// 检查角色名是否被占用时忽略大小写
+  #[serde(rename = "case-insensitive", default = "default_names_case_insensitive")]
+  pub case_insensitive: bool,
+
+  /// 禁止使用的角色名, 忽略大小写
+  #[serde(rename = "blocklist", default)]
+  pub blocklist: Vec<String>,
+}
+
+impl Default for Names {
+  fn default() -> Self {
+    Self {
+      charset: default_names_charset(),
+      charset_obj: None,
+      min_length: default_names_min_length(),
+      max_length: default_names_max_length(),
+      allow_cjk: false,
+      case_insensitive: default_names_case_insensitive(),
+      blocklist: vec![],
+    }
+  }
+}
+
+impl Names {
+  /// 字符集不是合法的正则表达式时返回错误, 不会退回默认字符集
+  pub fn convert(self: Self) -> Result<Self, regex::Error> {
+    let charset_obj = Regex::new(&format!("^(?:{})$", self.charset))?;
+    Ok(Self { charset_obj: Some(charset_obj), ..self })
+  }
+}
+
+/// 在 API 元数据中声明的 authlib-injector 功能
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct Features {
+  /// 允许使用角色名登录
+  #[serde(rename = "non-email-login", default)]
+  pub non_email_login: bool,
+
+  /// 提供旧版皮肤接口
+  #[serde(rename = "legacy-skin-api", default)]
+  pub legacy_skin_api: bool,
+
+  /// 禁止客户端使用 Mojang 命名空间
+  #[serde(rename = "no-mojang-namespace", default)]
+  pub no_mojang_namespace: bool,
+
+  /// 开启 Mojang 的反功能, 例如服务器黑名单
+  #[serde(rename = "enable-mojang-anti-features", default)]
+  pub enable_mojang_anti_features: bool,
+
+  /// 为角色签发聊天签名所需的密钥对
+  #[serde(rename = "enable-profile-key", default)]
+  pub enable_profile_key: bool,
+
+  /// 要求服务端检查角色名是否合法
+  #[serde(rename = "username-check", default)]
+  pub username_check: bool,
+}
+
+/// 存储后端
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DatabaseBackend {
+  /// 通过 Prisma 连接 PostgreSQL
+  #[default]
+  #[serde(rename = "postgresql")]
+  Postgresql,
+
+  /// 单个 SQLite 文件, 适合小型私人服务器
+  #[serde(rename = "sqlite")]
+  Sqlite,
+}
+
+fn default_database_sqlite_path() -> String {
+  "mc-auth.db".to_owned()
+}
+
+fn default_database_connect_timeout() -> u64 {
+  // 5 秒
+  5
+}
+
+fn default_database_pool_timeout() -> u64 {
+  // 10 秒
+  10
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Database {
+  #[serde(rename = "backend", default)]
+  pub backend: DatabaseBackend,
+
+  /// PostgreSQL 连接地址, 未设置时读取 DATABASE_URL 环境变量
+  #[serde(rename = "url", default)]
+  pub url: Option<String>,
+
+  /// 连接池大小, 未设置时由 Prisma 按 CPU 核数决定
+  #[serde(rename = "pool-size", default)]
+  pub pool_size: Option<u32>,
+
+  /// 建立连接的超时时间 (秒)
+  #[serde(rename = "connect-timeout", default = "default_database_connect_timeout")]
+  pub connect_timeout: u64,
+
+  /// 等待连接池空闲连接的超时时间 (秒)
+  #[serde(rename = "pool-timeout", default = "default_database_pool_timeout")]
+  pub pool_timeout: u64,
+
+  /// SQLite 数据库文件的路径, 不存在时自动创建
+  #[serde(rename = "sqlite-path", default = "default_database_sqlite_path")]
+  pub sqlite_path: String,
+}
+
+impl Default for Database {
+  fn default() -> Self {
+    Self {
+      backend: DatabaseBackend::default(),
+      url: None,
+      pool_size: None,
+      connect_timeout: default_database_connect_timeout(),
+      pool_timeout: default_database_pool_timeout(),
+      sqlite_path: default_database_sqlite_path(),
+    }
+  }
+}
+
+impl Database {
+  /// 附加了连接池参数的 PostgreSQL 连接地址, 未配置地址时返回 None
+  pub fn connection_url(&self) -> Option<String> {
+    let mut url = self.url.clone().or_else(|| std::env::var("DATABASE_URL").ok())?;
+    let mut params =
+      vec![format!("connect_timeout={}", self.connect_timeout), format!("pool_timeout={}", self.pool_timeout)];
+    if let Some(x) = self.pool_size {
+      params.push(format!("connection_limit={}", x));
+    }
+    url.push(if url.contains('?') { '&' } else { '?' });
+    url.push_str(&params.join("&"));
+    Some(url)
+  }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct Admin {
+  /// 管理 API 的静态密钥, 通过 X-Api-Key 请求头传入
+  #[serde(rename = "api-keys", default)]
+  pub api_keys: Vec<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -164,4 +463,46 @@ pub struct Settings {
 
   #[serde(rename = "textures")]
   pub textures: Textures,
+
+  #[serde(rename = "admin", default)]
+  pub admin: Admin,
+
+  #[serde(rename = "permissions", default)]
+  pub permissions: Permissions,
+
+  #[serde(rename = "profiles", default)]
+  pub profiles: Profiles,
+
+  #[serde(rename = "names", default)]
+  pub names: Names,
+
+  #[serde(rename = "features", default)]
+  pub features: Features,
+
+  #[serde(rename = "database", default)]
+  pub database: Database,
+}
+
+impl Settings {
+  /// 读取配置文件, 文件不存在时全部使用默认值
+  pub async fn load(path: &str) -> Result<Self, toml::de::Error> {
+    let settings_str = match fs::read_to_string(path).await {
+      Ok(v) => v,
+      Err(_e) => "".to_owned(),
+    };
+    Self::parse(&settings_str)
+  }
+
+  /// 从 TOML 字符串解析配置
+  pub fn parse(settings_str: &str) -> Result<Self, toml::de::Error> {
+    let mut settings: Settings = toml::from_str(settings_str)?;
+    settings.signature = settings.signature.convert();
+    settings.names = match settings.names.convert() {
+      Ok(x) => x,
+      Err(err) => {
+        return Err(serde::de::Error::custom(format!("invalid names.charset: {}", err)));
+      },
+    };
+    Ok(settings)
+  }
 }
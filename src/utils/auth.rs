@@ -0,0 +1,103 @@
+use std::marker::PhantomData;
+
+use axum::{
+  async_trait,
+  extract::FromRequestParts,
+  http::{header, request::Parts},
+};
+
+use crate::{
+  app_state::AppState,
+  models::error,
+  prisma,
+  utils::{
+    self,
+    permissions::{self, RequiredPermission},
+  },
+};
+
+/// 通过 `Authorization: Bearer {accessToken}` 认证的令牌, 附带其所属用户与绑定的角色
+pub struct BearerToken(pub prisma::token::Data);
+
+impl BearerToken {
+  pub fn owner(&self) -> &prisma::user::Data {
+    self.0.owner().unwrap()
+  }
+
+  pub fn profile(&self) -> Option<&prisma::profile::Data> {
+    self.0.profile().unwrap()
+  }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for BearerToken {
+  type Rejection = error::ErrorResponse;
+
+  async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+    let access_token = match parts
+      .headers
+      .get(header::AUTHORIZATION)
+      .and_then(|x| x.to_str().ok())
+      .and_then(|x| x.strip_prefix("Bearer "))
+    {
+      Some(x) => x.trim().to_owned(),
+      None => {
+        return Err(error::Error::new_unauthorized().to_response());
+      },
+    };
+    let token = match utils::find_checked_token(&state.repos, &state.settings.token, &access_token, None).await {
+      Ok(Some(x)) if x.status == prisma::TokenStatus::Available && !x.owner().unwrap().disabled => x,
+      Ok(_) => {
+        return Err(error::Error::new_unauthorized().to_response());
+      },
+      Err(err) => {
+        tracing::debug!("查询令牌失败: {:?}", err);
+        return Err(error::Error::new_database_error().to_response());
+      },
+    };
+    // 封禁期间用户及被封禁的角色都不能调用需要令牌的接口
+    match state.repos.users.active_ban(token.owner_id, token.profile_id).await {
+      Ok(None) => Ok(Self(token)),
+      Ok(Some(ban)) => Err(error::Error::new_banned(&ban).to_response()),
+      Err(err) => {
+        tracing::debug!("查询封禁失败: {:?}", err);
+        Err(error::Error::new_database_error().to_response())
+      },
+    }
+  }
+}
+
+/// 需要特定权限的调用者, 可以是配置中的静态密钥 (拥有全部权限), 也可以是拥有该权限的用户的令牌
+pub enum Caller {
+  ApiKey,
+  User(prisma::user::Data),
+}
+
+pub struct Permitted<P: RequiredPermission> {
+  pub caller: Caller,
+  _permission: PhantomData<P>,
+}
+
+/// 管理 API 的调用者
+pub type AdminAuth = Permitted<permissions::AdminApi>;
+
+#[async_trait]
+impl<P: RequiredPermission> FromRequestParts<AppState> for Permitted<P> {
+  type Rejection = error::ErrorResponse;
+
+  async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+    if let Some(key) = parts.headers.get("X-Api-Key").and_then(|x| x.to_str().ok()) {
+      if state.settings.admin.api_keys.iter().any(|x| !x.is_empty() && x == key) {
+        return Ok(Self { caller: Caller::ApiKey, _permission: PhantomData });
+      }
+      return Err(error::Error::new_unauthorized().to_response());
+    }
+    let token = BearerToken::from_request_parts(parts, state).await?;
+    let owner = token.owner().clone();
+    if permissions::has_permission(&owner, P::PERMISSION) {
+      Ok(Self { caller: Caller::User(owner), _permission: PhantomData })
+    } else {
+      Err(error::Error::new_permission_denied().to_response())
+    }
+  }
+}
@@ -0,0 +1,35 @@
+use crate::{
+  prisma,
+  repo::{RepoError, Repos},
+  settings::Settings,
+  utils::{
+    self,
+    textures::{TextureError, TextureType},
+  },
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum OfficialCapeError {
+  #[error("数据库错误: {0}")]
+  RepoError(#[from] RepoError),
+  #[error("材质不正确: {0}")]
+  InvalidTexture(#[from] TextureError),
+  #[error("保存材质失败: {0}")]
+  IoError(#[from] std::io::Error),
+}
+
+/// 创建官方披风, 普通用户只能通过授权使用
+pub async fn create_official_cape(
+  repos: &Repos,
+  sett: &Settings,
+  name: String,
+  data: &[u8],
+  frame_rate: Option<u32>,
+) -> Result<prisma::cape::Data, OfficialCapeError> {
+  let (width, height) = utils::textures::validate(data, TextureType::Cape, sett)?;
+  let frames = utils::textures::cape_frames(width, height).unwrap_or(1);
+  let frame_rate = utils::textures::validate_frame_rate(frames, frame_rate, sett)?;
+  let hash = utils::textures::hash(data);
+  utils::textures::save(sett, &utils::texture_vec_to_string(hash.clone()), data).await?;
+  Ok(repos.textures.create_official_cape(hash, name, frame_rate).await?)
+}
@@ -1,7 +1,17 @@
-use prisma::PrismaClient;
 use rand::Rng;
 
-use crate::prisma;
+use crate::{
+  prisma,
+  repo::{RepoResult, Repos},
+  settings,
+};
+
+pub mod auth;
+pub mod capes;
+pub mod permissions;
+pub mod profile_keys;
+pub mod profiles;
+pub mod textures;
 
 pub fn gen_access_token() -> String {
   let mut rng = rand::thread_rng();
@@ -32,162 +42,58 @@ pub fn string_to_uuid_vec(x: String) -> Vec<u8> {
   }
 }
 
-pub async fn get_token(
-  cli: &PrismaClient,
-  access_token: String,
-  client_token: Option<String>,
-) -> Result<Option<prisma::token::Data>, prisma_client_rust::QueryError> {
-  match client_token {
-    Some(client_token) => {
-      cli
-        .token()
-        .find_first(vec![
-          prisma::token::WhereParam::AccessToken(prisma::read_filters::StringFilter::Equals(access_token)),
-          prisma::token::WhereParam::ClientToken(prisma::read_filters::StringFilter::Equals(client_token)),
-          prisma::token::WhereParam::Status(prisma::read_filters::TokenStatusFilter::Not(prisma::TokenStatus::Invalid)),
-        ])
-        .with(prisma::token::owner::fetch())
-        .with(prisma::token::profile::fetch().with(prisma::profile::skin::fetch()).with(prisma::profile::cape::fetch()))
-    },
-    None => {
-      cli.token().find_first(vec![
-        prisma::token::WhereParam::AccessToken(prisma::read_filters::StringFilter::Equals(access_token)),
-        prisma::token::WhereParam::Status(prisma::read_filters::TokenStatusFilter::Not(prisma::TokenStatus::Invalid)),
-      ])
-    },
-  }
-  .exec()
-  .await
-}
-
-pub async fn del_token(
-  cli: &PrismaClient,
-  access_token: String,
-) -> Result<prisma::token::Data, prisma_client_rust::QueryError> {
-  cli
-    .token()
-    .update(prisma::token::UniqueWhereParam::AccessTokenEquals(access_token), vec![prisma::token::SetParam::Status(
-      prisma::write_params::TokenStatusParam::Set(prisma::TokenStatus::Invalid),
-    )])
-    .exec()
-    .await
-}
-
-pub async fn add_token(
-  cli: &PrismaClient,
-  profile: Option<i64>,
-  user: i64,
-  access_token: String,
-  client_token: String,
-) -> Result<prisma::token::Data, prisma_client_rust::QueryError> {
-  if let Some(profile_id) = profile {
-    cli
-      .token()
-      .create(access_token, client_token, prisma::user::UniqueWhereParam::IdEquals(user), vec![
-        prisma::token::SetParam::ConnectProfile(prisma::profile::UniqueWhereParam::IdEquals(profile_id)),
-      ])
-      .exec()
-  } else {
-    cli.token().create(access_token, client_token, prisma::user::UniqueWhereParam::IdEquals(user), vec![]).exec()
-  }
-  .await
-}
-
-pub async fn check_tokens(
-  cli: &PrismaClient,
-  default_max_tokens: i64,
-  default_token_need_refresh_duration: i64,
-  default_token_invalid_duration: i64,
-  user_id: Vec<i64>,
-) -> Result<(), prisma_client_rust::QueryError> {
-  let results = cli
-    .user()
-    .find_many(
-      user_id.iter().map(|x| prisma::user::WhereParam::Id(prisma::read_filters::BigIntFilter::Equals(*x))).collect(),
-    )
-    .exec()
-    .await?
-    .into_iter()
-    .map(|u| {
-      (
-        // 可用
-        cli
-          .token()
-          .find_many(vec![
-            prisma::token::status::equals(prisma::TokenStatus::Available),
-            prisma::token::owner::is(vec![prisma::user::WhereParam::Id(prisma::read_filters::BigIntFilter::Equals(
-              u.id,
-            ))]),
-          ])
-          .order_by(prisma::token::created_at::order(prisma::SortOrder::Desc)),
-        // 需要刷新
-        cli
-          .token()
-          .find_many(vec![
-            prisma::token::status::equals(prisma::TokenStatus::NeedRefresh),
-            prisma::token::owner::is(vec![prisma::user::WhereParam::Id(prisma::read_filters::BigIntFilter::Equals(
-              u.id,
-            ))]),
-          ])
-          .order_by(prisma::token::created_at::order(prisma::SortOrder::Desc)),
-        // 用户设置
-        cli.setting().find_unique(prisma::setting::UniqueWhereParam::UserIdEquals(u.id)),
-      )
-    });
-  let results = cli._batch(results).await?;
-  let now = chrono::Utc::now();
-  for (_, (avaliable, need_refresh, settings)) in results.into_iter().enumerate() {
-    let (max_tokens, token_need_refresh_duration, token_invalid_duration) = if let Some(item) = settings {
-      (item.max_token, item.token_need_refresh_duration, item.token_invalid_duration)
-    } else {
-      (default_max_tokens, default_token_need_refresh_duration, default_token_invalid_duration)
+/// 按创建时间与数量上限更新用户令牌的状态, 用户单独的设置优先于配置文件
+pub async fn check_tokens(repos: &Repos, sett: &settings::Token, user_id: i64) -> RepoResult<()> {
+  let avaliable = repos.tokens.list_by_owner(user_id, prisma::TokenStatus::Available).await?;
+  let need_refresh = repos.tokens.list_by_owner(user_id, prisma::TokenStatus::NeedRefresh).await?;
+  let (max_tokens, token_need_refresh_duration, token_invalid_duration) =
+    match repos.users.find_setting(user_id).await? {
+      Some(item) => (item.max_token, item.token_need_refresh_duration, item.token_invalid_duration),
+      None => (sett.max, sett.refresh_duration, sett.invalid_duration),
     };
-    let mut token_counter = max_tokens;
-    // 如果超过创建时间 + token_need_refresh_duration, 则设置令牌为 NeedRefresh
-    // 如果 token_counter <= 0, 则设置令牌为 Invalid
-    for (_, x) in avaliable.into_iter().enumerate() {
-      tracing::info!("检查用户 {} 的可用令牌 {}...", x.owner_id, x.id);
-      if {
-        token_counter -= 1;
-        token_counter > 0
-      } {
-        if now.timestamp() - x.created_at.timestamp() > token_need_refresh_duration {
-          tracing::info!("用户 {} 的可用令牌 {} 暂时失效", x.owner_id, x.id);
-          cli
-            .token()
-            .update(prisma::token::UniqueWhereParam::IdEquals(x.id), vec![prisma::token::SetParam::Status(
-              prisma::write_params::TokenStatusParam::Set(prisma::TokenStatus::NeedRefresh),
-            )])
-            .exec()
-            .await?;
-        }
-      } else {
-        tracing::info!("用户 {} 的可用令牌 {} 因数量超出而永久失效", x.owner_id, x.id);
-        cli
-          .token()
-          .update(prisma::token::UniqueWhereParam::IdEquals(x.id), vec![prisma::token::SetParam::Status(
-            prisma::write_params::TokenStatusParam::Set(prisma::TokenStatus::Invalid),
-          )])
-          .exec()
-          .await?;
+  let now = chrono::Utc::now();
+  let mut token_counter = max_tokens;
+  // 如果超过创建时间 + token_need_refresh_duration, 则设置令牌为 NeedRefresh
+  // 如果 token_counter <= 0, 则设置令牌为 Invalid
+  for x in avaliable {
+    tracing::info!("检查用户 {} 的可用令牌 {}...", x.owner_id, x.id);
+    token_counter -= 1;
+    if token_counter > 0 {
+      if now.timestamp() - x.created_at.timestamp() > token_need_refresh_duration {
+        tracing::info!("用户 {} 的可用令牌 {} 暂时失效", x.owner_id, x.id);
+        repos.tokens.set_status(x.id, prisma::TokenStatus::NeedRefresh).await?;
       }
+    } else {
+      tracing::info!("用户 {} 的可用令牌 {} 因数量超出而永久失效", x.owner_id, x.id);
+      repos.tokens.set_status(x.id, prisma::TokenStatus::Invalid).await?;
     }
-    // 如果超过创建时间 + token_need_refresh_duration + invalid_duration, 则设置令牌为 Invalid
-    for (_, x) in need_refresh.into_iter().enumerate() {
-      tracing::info!("检查用户 {} 的暂时失效令牌 {}...", x.owner_id, x.id);
-      if now.timestamp() - x.created_at.timestamp() > token_need_refresh_duration + token_invalid_duration {
-        tracing::info!("用户 {} 的暂时失效令牌 {} 永久失效", x.owner_id, x.id);
-        cli
-          .token()
-          .update(prisma::token::UniqueWhereParam::IdEquals(x.id), vec![prisma::token::SetParam::Status(
-            prisma::write_params::TokenStatusParam::Set(prisma::TokenStatus::Invalid),
-          )])
-          .exec()
-          .await?;
-      }
+  }
+  // 如果超过创建时间 + token_need_refresh_duration + invalid_duration, 则设置令牌为 Invalid
+  for x in need_refresh {
+    tracing::info!("检查用户 {} 的暂时失效令牌 {}...", x.owner_id, x.id);
+    if now.timestamp() - x.created_at.timestamp() > token_need_refresh_duration + token_invalid_duration {
+      tracing::info!("用户 {} 的暂时失效令牌 {} 永久失效", x.owner_id, x.id);
+      repos.tokens.set_status(x.id, prisma::TokenStatus::Invalid).await?;
     }
   }
-  return Ok(());
+  Ok(())
+}
+
+/// 查询令牌, 返回前先按创建时间更新该用户全部令牌的状态
+pub async fn find_checked_token(
+  repos: &Repos,
+  sett: &settings::Token,
+  access_token: &str,
+  client_token: Option<&str>,
+) -> RepoResult<Option<prisma::token::Data>> {
+  let owner_id = match repos.tokens.find(access_token, client_token).await? {
+    Some(x) => x.owner_id,
+    None => {
+      return Ok(None);
+    },
+  };
+  check_tokens(repos, sett, owner_id).await?;
+  repos.tokens.find(access_token, client_token).await
 }
 
 pub fn texture_vec_to_string(x: Vec<u8>) -> String {
@@ -0,0 +1,66 @@
+use crate::{prisma, settings::Settings};
+
+/// 角色自带的权限, 用户还可以被单独授予额外的权限
+pub fn role_permissions(role: prisma::Role) -> &'static [prisma::Permission] {
+  match role {
+    prisma::Role::Player => &[],
+    prisma::Role::Moderator => {
+      &[prisma::Permission::UploadHdSkin, prisma::Permission::GrantCapes, prisma::Permission::BanUsers]
+    },
+    prisma::Role::Admin => {
+      &[
+        prisma::Permission::UploadHdSkin,
+        prisma::Permission::UnlimitedProfiles,
+        prisma::Permission::GrantCapes,
+        prisma::Permission::BanUsers,
+        prisma::Permission::AdminApi,
+      ]
+    },
+  }
+}
+
+pub fn has_permission(user: &prisma::user::Data, permission: prisma::Permission) -> bool {
+  role_permissions(user.role).contains(&permission) || user.permissions.contains(&permission)
+}
+
+/// 用户最多可以拥有的角色数量, None 表示不限制
+pub fn max_profiles(user: &prisma::user::Data, sett: &Settings) -> Option<i64> {
+  if has_permission(user, prisma::Permission::UnlimitedProfiles) {
+    return None;
+  }
+  Some(match user.role {
+    prisma::Role::Player => sett.permissions.player_max_profiles,
+    prisma::Role::Moderator | prisma::Role::Admin => sett.permissions.moderator_max_profiles,
+  })
+}
+
+/// 权限标记, 用于 [`crate::utils::auth::Permitted`] 提取器
+pub trait RequiredPermission {
+  const PERMISSION: prisma::Permission;
+}
+
+pub struct UploadHdSkin;
+pub struct UnlimitedProfiles;
+pub struct GrantCapes;
+pub struct BanUsers;
+pub struct AdminApi;
+
+impl RequiredPermission for UploadHdSkin {
+  const PERMISSION: prisma::Permission = prisma::Permission::UploadHdSkin;
+}
+
+impl RequiredPermission for UnlimitedProfiles {
+  const PERMISSION: prisma::Permission = prisma::Permission::UnlimitedProfiles;
+}
+
+impl RequiredPermission for GrantCapes {
+  const PERMISSION: prisma::Permission = prisma::Permission::GrantCapes;
+}
+
+impl RequiredPermission for BanUsers {
+  const PERMISSION: prisma::Permission = prisma::Permission::BanUsers;
+}
+
+impl RequiredPermission for AdminApi {
+  const PERMISSION: prisma::Permission = prisma::Permission::AdminApi;
+}
@@ -0,0 +1,99 @@
+use base64::Engine;
+use rsa::{
+  pkcs1v15::SigningKey,
+  pkcs8::{EncodePrivateKey, EncodePublicKey},
+  signature::{RandomizedSigner, SignatureEncoding},
+  RsaPrivateKey,
+};
+use sha1::Sha1;
+
+use crate::{
+  prisma,
+  repo::{ProfileKeyPair, RepoError, Repos},
+  settings::Settings,
+  utils,
+};
+
+/// 密钥对的有效期 (秒)
+pub const KEY_EXPIRE_SECONDS: i64 = 172800;
+/// 签发后超过该时间 (秒) 再次请求时重新生成密钥对
+pub const KEY_REFRESH_SECONDS: i64 = 129600;
+const KEY_BITS: usize = 2048;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProfileKeyError {
+  #[error("数据库错误: {0}")]
+  RepoError(#[from] RepoError),
+  #[error("未配置签名私钥")]
+  NoSigningKey,
+  #[error("生成密钥对失败: {0}")]
+  KeyError(String),
+}
+
+/// 与 Java 版 `Crypt.rsaPublicKeyToString` 相同的格式, 内容按 MIME 规则每 76 个字符换行
+pub fn to_pem(label: &str, der: &[u8]) -> String {
+  let encoded = utils::base64().encode(der);
+  let lines: Vec<&str> = encoded.as_bytes().chunks(76).map(|x| std::str::from_utf8(x).unwrap()).collect();
+  format!("-----BEGIN {}-----\n{}\n-----END {}-----\n", label, lines.join("\r\n"), label)
+}
+
+/// 返回 (新版签名, 旧版签名)
+fn sign_public_key(
+  server_key: RsaPrivateKey,
+  uuid: &[u8],
+  expires_at: i64,
+  public_der: &[u8],
+  public_pem: &str,
+) -> (String, String) {
+  let sign_key = SigningKey::<Sha1>::new(server_key);
+  let mut rng = rand::thread_rng();
+  let be = utils::base64();
+  // 1.19.1 起: 角色 UUID + 过期时间 (毫秒, 大端序) + DER 格式的公钥
+  let mut data = uuid.to_vec();
+  data.extend_from_slice(&expires_at.to_be_bytes());
+  data.extend_from_slice(public_der);
+  let signature = sign_key.sign_with_rng(&mut rng, &data).to_bytes();
+  // 1.19: 过期时间 (毫秒) + PEM 格式的公钥
+  let legacy_data = format!("{}{}", expires_at, public_pem);
+  let legacy_signature = sign_key.sign_with_rng(&mut rng, legacy_data.as_bytes()).to_bytes();
+  (be.encode(signature), be.encode(legacy_signature))
+}
+
+/// 获取角色的密钥对, 不存在或已到刷新时间时重新生成
+pub async fn get_or_rotate(
+  repos: &Repos,
+  sett: &Settings,
+  profile: &prisma::profile::Data,
+) -> Result<prisma::profile_key::Data, ProfileKeyError> {
+  let server_key = sett.signature.prikey_obj.clone().ok_or(ProfileKeyError::NoSigningKey)?;
+  let now = chrono::Utc::now();
+  let current = repos.profiles.find_key(profile.id).await?;
+  if let Some(x) = current {
+    if x.refreshed_after > now {
+      return Ok(x);
+    }
+  }
+
+  let key = tokio::task::spawn_blocking(|| RsaPrivateKey::new(&mut rand::thread_rng(), KEY_BITS))
+    .await
+    .map_err(|err| ProfileKeyError::KeyError(err.to_string()))?
+    .map_err(|err| ProfileKeyError::KeyError(err.to_string()))?;
+  let private_der = key.to_pkcs8_der().map_err(|err| ProfileKeyError::KeyError(err.to_string()))?;
+  let public_der = key.to_public_key().to_public_key_der().map_err(|err| ProfileKeyError::KeyError(err.to_string()))?;
+  let private_pem = to_pem("RSA PRIVATE KEY", private_der.as_bytes());
+  let public_pem = to_pem("RSA PUBLIC KEY", public_der.as_bytes());
+  let expires_at = now + chrono::Duration::seconds(KEY_EXPIRE_SECONDS);
+  let refreshed_after = now + chrono::Duration::seconds(KEY_REFRESH_SECONDS);
+  let (signature, legacy_signature) =
+    sign_public_key(server_key, &profile.uuid, expires_at.timestamp_millis(), public_der.as_bytes(), &public_pem);
+
+  let pair = ProfileKeyPair {
+    private_key: private_pem,
+    public_key: public_pem,
+    public_key_signature: signature,
+    legacy_key_signature: legacy_signature,
+    expires_at: expires_at.into(),
+    refreshed_after: refreshed_after.into(),
+  };
+  Ok(repos.profiles.save_key(profile.id, pair).await?)
+}
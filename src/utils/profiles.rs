@@ -0,0 +1,63 @@
+use md5::{Digest, Md5};
+
+use crate::{
+  repo::NameRules,
+  settings::{self, Settings, UuidStrategy},
+};
+
+/// 检查角色名是否符合配置中的规则, 不合法时返回原因
+pub fn validate_name(name: &str, rules: &settings::Names) -> Result<(), String> {
+  let length = name.chars().count();
+  if length < rules.min_length || length > rules.max_length {
+    return Err(format!("Profile name must be {} to {} characters long.", rules.min_length, rules.max_length));
+  }
+  let charset = rules.charset_obj.as_ref();
+  let mut buf = [0; 4];
+  let valid_char =
+    |x: char| (rules.allow_cjk && is_cjk(x)) || charset.is_some_and(|re| re.is_match(x.encode_utf8(&mut buf)));
+  if !name.chars().all(valid_char) {
+    return Err("Profile name contains characters that are not allowed.".to_owned());
+  }
+  let lowercase = name.to_lowercase();
+  if rules.blocklist.iter().any(|x| x.to_lowercase() == lowercase) {
+    return Err("This profile name is not allowed.".to_owned());
+  }
+  Ok(())
+}
+
+/// 中日韩文字 (汉字、假名与谚文)
+fn is_cjk(x: char) -> bool {
+  matches!(
+    x,
+    '\u{3040}'..='\u{30ff}'
+      | '\u{3400}'..='\u{4dbf}'
+      | '\u{4e00}'..='\u{9fff}'
+      | '\u{ac00}'..='\u{d7af}'
+      | '\u{f900}'..='\u{faff}'
+      | '\u{20000}'..='\u{2a6df}'
+  )
+}
+
+/// 管理员指定角色名时使用的占用规则
+/// 默认与玩家相同, 检查名称格式、大小写与保留期; force 时只要求名称与其他角色不完全相同
+pub fn admin_name_rules(name: &str, sett: &Settings, force: bool) -> Result<NameRules, String> {
+  if force {
+    return Ok(NameRules::exact());
+  }
+  validate_name(name, &sett.names)?;
+  Ok(NameRules::new(sett))
+}
+
+/// 按配置中的策略为新角色分配 UUID
+pub fn new_profile_uuid(name: &str, sett: &Settings) -> Vec<u8> {
+  match sett.profiles.uuid_strategy {
+    UuidStrategy::Random => uuid::Uuid::new_v4().as_bytes().to_vec(),
+    UuidStrategy::Offline => offline_uuid(name).as_bytes().to_vec(),
+  }
+}
+
+/// 与 Java 版 `UUID.nameUUIDFromBytes("OfflinePlayer:" + name)` 相同
+pub fn offline_uuid(name: &str) -> uuid::Uuid {
+  let hash = Md5::digest(format!("OfflinePlayer:{}", name).as_bytes());
+  uuid::Builder::from_md5_bytes(hash.into()).into_uuid()
+}
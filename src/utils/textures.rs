@@ -0,0 +1,188 @@
+use std::path::PathBuf;
+
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use crate::{prisma, settings::Settings};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureType {
+  Skin,
+  Cape,
+  Elytra,
+  Ears,
+}
+
+impl TextureType {
+  pub fn from_path(x: &str) -> Option<Self> {
+    match x {
+      "skin" => Some(Self::Skin),
+      "cape" => Some(Self::Cape),
+      "elytra" => Some(Self::Elytra),
+      "ears" => Some(Self::Ears),
+      _ => None,
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Skin => "skin",
+      Self::Cape => "cape",
+      Self::Elytra => "elytra",
+      Self::Ears => "ears",
+    }
+  }
+
+  /// 除皮肤与披风之外的材质, 需要在配置中启用
+  pub fn extra(&self) -> Option<prisma::ExtraTextureType> {
+    match self {
+      Self::Skin | Self::Cape => None,
+      Self::Elytra => Some(prisma::ExtraTextureType::Elytra),
+      Self::Ears => Some(prisma::ExtraTextureType::Ears),
+    }
+  }
+
+  pub fn enabled(&self, sett: &Settings) -> bool {
+    match self.extra() {
+      None => true,
+      Some(_) => sett.textures.extra_types.iter().any(|x| x == self.name()),
+    }
+  }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TextureError {
+  #[error("不是有效的 PNG 图片")]
+  NotPng,
+  #[error("材质文件过大")]
+  TooLarge,
+  #[error("材质尺寸 {0}x{1} 不正确")]
+  InvalidSize(u32, u32),
+  #[error("动态披风帧数 {0} 超出限制")]
+  TooManyFrames(u32),
+  #[error("动态披风帧率 {0} 不正确")]
+  InvalidFrameRate(u32),
+}
+
+impl TextureError {
+  pub fn message(&self) -> &'static str {
+    match self {
+      Self::NotPng => "The texture is not a valid PNG image.",
+      Self::TooLarge => "The texture file is too large.",
+      Self::InvalidSize(_, _) => "The texture has an invalid size.",
+      Self::TooManyFrames(_) => "The animated cape has too many frames.",
+      Self::InvalidFrameRate(_) => "The animated cape has an invalid frame rate.",
+    }
+  }
+}
+
+/// 从 PNG 的 IHDR 块读取宽高
+pub fn png_size(data: &[u8]) -> Option<(u32, u32)> {
+  if data.len() < 24 || data[0..8] != PNG_SIGNATURE || &data[12..16] != b"IHDR" {
+    return None;
+  }
+  let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+  let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+  Some((width, height))
+}
+
+/// 检查材质文件大小与尺寸, 返回宽高
+pub fn validate(data: &[u8], texture_type: TextureType, sett: &Settings) -> Result<(u32, u32), TextureError> {
+  if data.len() as u64 > sett.textures.max_size * 1024 {
+    return Err(TextureError::TooLarge);
+  }
+  let (width, height) = png_size(data).ok_or(TextureError::NotPng)?;
+  let max_length = sett.textures.max_length as u32;
+  if width == 0 || height == 0 || width > max_length {
+    return Err(TextureError::InvalidSize(width, height));
+  }
+  // 动态披风的每一帧纵向排列, 高度限制作用于单帧
+  let frame_height = match texture_type {
+    TextureType::Cape => cape_frame_height(width).unwrap_or(height),
+    _ => height,
+  };
+  if frame_height == 0 || frame_height > max_length {
+    return Err(TextureError::InvalidSize(width, height));
+  }
+  let valid = match texture_type {
+    // 64x64 或 64x32 (旧版), 以及它们的高清倍数
+    TextureType::Skin => width % 64 == 0 && (height == width || height * 2 == width),
+    // 64x32, 以及 22x17 (旧版) 的倍数; 动态披风的高度为单帧高度的整数倍
+    TextureType::Cape => {
+      let frames = cape_frames(width, height).ok_or(TextureError::InvalidSize(width, height))?;
+      if frames > sett.textures.max_cape_frames.max(1) {
+        return Err(TextureError::TooManyFrames(frames));
+      }
+      true
+    },
+    // 鞘翅与静态披风共用同一张材质的布局
+    TextureType::Elytra => (width % 64 == 0 && height * 2 == width) || (width % 22 == 0 && height * 22 == width * 17),
+    // 各个模组的耳朵材质布局不同, 只限制最大尺寸
+    TextureType::Ears => true,
+  };
+  if valid {
+    Ok((width, height))
+  } else {
+    Err(TextureError::InvalidSize(width, height))
+  }
+}
+
+/// 披风单帧的高度, 宽度不符合任何布局时返回 None
+fn cape_frame_height(width: u32) -> Option<u32> {
+  if width % 64 == 0 {
+    Some(width / 2)
+  } else if width % 22 == 0 {
+    Some(width / 22 * 17)
+  } else {
+    None
+  }
+}
+
+/// 披风的帧数, 静态披风为 1
+pub fn cape_frames(width: u32, height: u32) -> Option<u32> {
+  let frame_height = cape_frame_height(width)?;
+  if height == 0 || height % frame_height != 0 {
+    return None;
+  }
+  Some(height / frame_height)
+}
+
+/// 检查动态披风的帧率, 静态披风不保存帧率
+pub fn validate_frame_rate(frames: u32, frame_rate: Option<u32>, sett: &Settings) -> Result<Option<i32>, TextureError> {
+  if frames <= 1 {
+    return Ok(None);
+  }
+  let frame_rate = frame_rate.unwrap_or(10);
+  if frame_rate == 0 || frame_rate > sett.textures.max_frame_rate {
+    return Err(TextureError::InvalidFrameRate(frame_rate));
+  }
+  Ok(Some(frame_rate as i32))
+}
+
+pub fn hash(data: &[u8]) -> Vec<u8> {
+  Sha256::digest(data).to_vec()
+}
+
+fn texture_path(sett: &Settings, hash: &str) -> PathBuf {
+  PathBuf::from(&sett.textures.dir).join(hash)
+}
+
+/// 保存材质文件, 文件名为材质哈希
+pub async fn save(sett: &Settings, hash: &str, data: &[u8]) -> std::io::Result<()> {
+  let path = texture_path(sett, hash);
+  if fs::try_exists(&path).await? {
+    return Ok(());
+  }
+  fs::create_dir_all(&sett.textures.dir).await?;
+  fs::write(path, data).await
+}
+
+pub async fn load(sett: &Settings, hash: &str) -> std::io::Result<Vec<u8>> {
+  // 哈希只能是十六进制字符串, 防止路径穿越
+  if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+    return Err(std::io::ErrorKind::NotFound.into());
+  }
+  fs::read(texture_path(sett, hash)).await
+}
@@ -0,0 +1,174 @@
+//! 管理 API
+//! 每个测试分别在内存存储与 SQLite 存储上运行
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::{fake_png, TestApp, ADMIN_KEY, PASSWORD};
+use serde_json::json;
+
+common::backend_tests! {
+  users_and_settings,
+  profiles_and_tokens,
+  bans_and_blocked_servers,
+  skins_and_capes,
+}
+
+async fn users_and_settings(app: TestApp) {
+  let resp = app
+    .send(
+      axum::http::Request::builder()
+        .uri("/admin/api/users")
+        .header("X-Api-Key", "wrong-key")
+        .body(axum::body::Body::empty())
+        .unwrap(),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::UNAUTHORIZED);
+
+  let body = json!({ "email": "admin-created@example.com", "nickname": "created", "password": PASSWORD });
+  let resp = app.admin(Method::POST, "/users", Some(body.clone())).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let id = resp.json()["id"].as_i64().unwrap();
+  assert_eq!(app.admin(Method::POST, "/users", Some(body)).await.status, StatusCode::CONFLICT);
+
+  let resp = app.admin(Method::GET, "/users?email=admin-created", None).await;
+  assert_eq!(resp.json()["total"], 1);
+  let resp = app.admin(Method::PATCH, &format!("/users/{}", id), Some(json!({ "nickname": "renamed" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["nickname"], "renamed");
+
+  let uri = format!("/settings/{}", id);
+  let resp = app.admin(Method::PUT, &uri, Some(json!({ "maxToken": 3 }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["maxToken"], 3);
+  assert_eq!(resp.json()["tokenInvalidDuration"], app.settings.token.invalid_duration);
+  assert_eq!(app.admin(Method::GET, &format!("/settings?userId={}", id), None).await.json()["total"], 1);
+  assert_eq!(app.admin(Method::DELETE, &uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &uri, None).await.status, StatusCode::NOT_FOUND);
+
+  assert_eq!(app.admin(Method::DELETE, &format!("/users/{}", id), None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &format!("/users/{}", id), None).await.status, StatusCode::NOT_FOUND);
+}
+
+async fn profiles_and_tokens(app: TestApp) {
+  let user = app.create_user().await;
+  let name = format!("a_{}", &mc_auth::utils::gen_uuid()[..10]);
+  let resp = app.admin(Method::POST, "/profiles", Some(json!({ "ownerId": user.user_id, "name": name }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let profile = resp.json();
+  let profile_id = profile["id"].as_i64().unwrap();
+  let resp = app.admin(Method::GET, &format!("/profiles?ownerId={}", user.user_id), None).await;
+  assert_eq!(resp.json()["total"], 2);
+
+  // 与玩家相同检查名称格式与占用, 指定 force 时跳过
+  let resp = app.admin(Method::POST, "/profiles", Some(json!({ "ownerId": user.user_id, "name": "x" }))).await;
+  resp.assert_error(StatusCode::BAD_REQUEST, "IllegalArgumentException");
+  let body = json!({ "ownerId": user.user_id, "name": user.profile_name.to_uppercase() });
+  assert_eq!(app.admin(Method::POST, "/profiles", Some(body)).await.status, StatusCode::CONFLICT);
+  let body = json!({ "ownerId": user.user_id, "name": "x", "force": true });
+  let resp = app.admin(Method::POST, "/profiles", Some(body)).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let forced_uri = format!("/profiles/{}", resp.json()["id"]);
+  // 不符合命名规则的名称同样可以批量查询
+  let resp = app.post("/api/profiles/minecraft", json!([" x ", "x", ""])).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let found = resp.json();
+  assert_eq!(found.as_array().unwrap().len(), 1);
+  assert_eq!(found[0]["name"], "x");
+  assert_eq!(app.admin(Method::DELETE, &forced_uri, None).await.status, StatusCode::NO_CONTENT);
+
+  let renamed = format!("b_{}", &mc_auth::utils::gen_uuid()[..10]);
+  let uri = format!("/profiles/{}", profile_id);
+  let resp = app.admin(Method::PATCH, &uri, Some(json!({ "name": "bad name" }))).await;
+  resp.assert_error(StatusCode::BAD_REQUEST, "IllegalArgumentException");
+  let resp = app.admin(Method::PATCH, &uri, Some(json!({ "name": renamed }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["name"], renamed);
+  let history = app.get(&format!("/api/profile/{}/names", profile["uuid"].as_str().unwrap())).await.json();
+  assert_eq!(history.as_array().unwrap().len(), 2);
+
+  let body = json!({ "ownerId": user.user_id, "profileId": profile_id, "clientToken": "admin-client" });
+  let resp = app.admin(Method::POST, "/tokens", Some(body)).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let token = resp.json();
+  let access_token = token["accessToken"].as_str().unwrap();
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+  let token_uri = format!("/tokens/{}", token["id"]);
+  let resp = app.admin(Method::PATCH, &token_uri, Some(json!({ "status": "Invalid" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  assert_eq!(app.admin(Method::GET, &format!("/tokens?ownerId={}", user.user_id), None).await.json()["total"], 1);
+  assert_eq!(app.admin(Method::DELETE, &token_uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &token_uri, None).await.status, StatusCode::NOT_FOUND);
+
+  assert_eq!(app.admin(Method::DELETE, &uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &uri, None).await.status, StatusCode::NOT_FOUND);
+}
+
+async fn bans_and_blocked_servers(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let resp = app.admin(Method::POST, "/bans", Some(json!({ "userId": user.user_id, "reason": "cheating" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let ban_id = resp.json()["id"].as_i64().unwrap();
+
+  // 封禁后已有的令牌失效, 也不能再登录
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.email, "password": PASSWORD })).await;
+  assert_eq!(resp.status, StatusCode::FORBIDDEN);
+  let resp = app.admin(Method::GET, &format!("/bans?userId={}&active=true", user.user_id), None).await;
+  assert_eq!(resp.json()["total"], 1);
+  // 封禁期间签发的令牌同样不能调用需要认证的接口
+  let resp =
+    app.admin(Method::POST, "/tokens", Some(json!({ "ownerId": user.user_id, "clientToken": "banned" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let banned_token = resp.json()["accessToken"].as_str().unwrap().to_owned();
+  let resp = app.call(Method::GET, "/api/user/textures", &banned_token, None).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+
+  assert_eq!(app.admin(Method::DELETE, &format!("/bans/{}", ban_id), None).await.status, StatusCode::NO_CONTENT);
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.email, "password": PASSWORD })).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+
+  let resp = app.admin(Method::POST, "/blocked-servers", Some(json!({ "pattern": "*.Example.com" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let blocked = resp.json();
+  assert_eq!(blocked["pattern"], "*.example.com");
+  let hash = blocked["hash"].as_str().unwrap();
+  assert_eq!(app.get("/blockedservers").await.body, hash);
+  assert_eq!(app.admin(Method::GET, "/blocked-servers", None).await.json()["total"], 1);
+  let resp = app.admin(Method::POST, "/blocked-servers", Some(json!({ "hash": hash }))).await;
+  assert_eq!(resp.status, StatusCode::CONFLICT);
+
+  let uri = format!("/blocked-servers/{}", blocked["id"]);
+  assert_eq!(app.admin(Method::DELETE, &uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.get("/blockedservers").await.body, "");
+}
+
+async fn skins_and_capes(app: TestApp) {
+  let auth = ("X-Api-Key", ADMIN_KEY);
+  let resp = app.upload(Method::POST, "/admin/api/skins", auth, &[("model", "slim")], &fake_png(64, 64)).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let skin_uri = format!("/skins/{}", resp.json()["id"]);
+  let resp = app.admin(Method::PATCH, &skin_uri, Some(json!({ "model": "Default" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(app.admin(Method::GET, &skin_uri, None).await.json()["model"], "Default");
+  assert_eq!(app.admin(Method::GET, "/skins?model=Default", None).await.json()["total"], 1);
+  assert_eq!(app.admin(Method::DELETE, &skin_uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &skin_uri, None).await.status, StatusCode::NOT_FOUND);
+
+  let resp = app.upload(Method::POST, "/admin/api/capes", auth, &[("name", "Founder")], &fake_png(64, 32)).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let cape_uri = format!("/capes/{}", resp.json()["id"]);
+  let resp = app.admin(Method::PATCH, &cape_uri, Some(json!({ "name": "Pioneer" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["name"], "Pioneer");
+  assert_eq!(app.admin(Method::GET, "/capes?official=true", None).await.json()["total"], 1);
+  assert_eq!(app.admin(Method::DELETE, &cape_uri, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.admin(Method::GET, &cape_uri, None).await.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,314 @@
+//! 集成测试共用的测试环境
+//! 默认使用内存存储, 不需要数据库; 也可以使用内存中的 SQLite 存储
+
+#![allow(dead_code)]
+
+use std::sync::OnceLock;
+
+use axum::{
+  body::{to_bytes, Body, Bytes},
+  extract::connect_info::MockConnectInfo,
+  http::{header, Method, Request, StatusCode},
+  Router,
+};
+use base64::Engine;
+use mc_auth::{
+  app_state::AppState,
+  repo::{NameRules, Repos},
+  routes,
+  settings::Settings,
+  utils,
+};
+use rsa::{
+  pkcs1v15::{Signature, VerifyingKey},
+  pkcs8::{DecodePublicKey, EncodePrivateKey, LineEnding},
+  signature::Verifier,
+  RsaPrivateKey, RsaPublicKey,
+};
+use serde_json::Value;
+use sha1::Sha1;
+
+pub const PASSWORD: &str = "correct horse battery staple";
+
+/// 管理 API 的静态密钥
+pub const ADMIN_KEY: &str = "test-admin-key";
+
+/// 测试共用的签名私钥, 生成一次即可
+fn test_prikey() -> &'static str {
+  static PRIKEY: OnceLock<String> = OnceLock::new();
+  PRIKEY.get_or_init(|| {
+    let key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).unwrap();
+    key.to_pkcs8_pem(LineEnding::LF).unwrap().to_string()
+  })
+}
+
+pub fn test_settings() -> Settings {
+  let textures_dir = std::env::temp_dir().join("mc-auth-test-textures");
+  Settings::parse(&format!(
+    r#"
+skin-domains = ["127.0.0.1"]
+
+[token]
+
+[signature]
+prikey = """{}"""
+
+[webserver]
+
+[textures]
+base = "http://127.0.0.1:2345/textures/"
+dir = "{}"
+
+[features]
+non-email-login = true
+enable-profile-key = true
+
+[admin]
+api-keys = ["{}"]
+"#,
+    test_prikey(),
+    textures_dir.display().to_string().replace('\\', "/"),
+    ADMIN_KEY,
+  ))
+  .unwrap()
+}
+
+pub struct TestApp {
+  pub router: Router,
+  pub repos: Repos,
+  pub settings: Settings,
+}
+
+pub struct TestResponse {
+  pub status: StatusCode,
+  pub body: Bytes,
+}
+
+impl TestResponse {
+  pub fn json(&self) -> Value {
+    serde_json::from_slice(&self.body).unwrap_or_else(|_| panic!("响应不是 JSON: {:?}", self.body))
+  }
+
+  /// 检查 Yggdrasil 错误响应的状态码与 error 字段
+  pub fn assert_error(&self, status: StatusCode, error: &str) {
+    assert_eq!(self.status, status, "{:?}", self.body);
+    let body = self.json();
+    assert_eq!(body["error"], error);
+    assert!(body["errorMessage"].is_string());
+  }
+}
+
+/// 测试中创建的用户与其唯一的角色
+#[derive(Clone)]
+pub struct TestUser {
+  pub email: String,
+  pub profile_name: String,
+  pub profile_uuid: String,
+  pub user_id: i64,
+}
+
+impl TestApp {
+  pub fn new() -> Self {
+    Self::with_repos(Repos::memory())
+  }
+
+  pub fn sqlite() -> Self {
+    Self::with_repos(Repos::sqlite(":memory:").unwrap())
+  }
+
+  pub fn with_repos(repos: Repos) -> Self {
+    let settings = test_settings();
+    let router = routes::router()
+      .with_state(AppState { repos: repos.clone(), settings: settings.clone() })
+      .layer(MockConnectInfo(std::net::SocketAddr::from(([127, 0, 0, 1], 25565))));
+    Self { router, repos, settings }
+  }
+
+  pub async fn send(&self, req: Request<Body>) -> TestResponse {
+    let resp = tower::ServiceExt::oneshot(self.router.clone(), req).await.unwrap();
+    let status = resp.status();
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    TestResponse { status, body }
+  }
+
+  pub async fn get(&self, uri: &str) -> TestResponse {
+    self.send(Request::builder().uri(uri).body(Body::empty()).unwrap()).await
+  }
+
+  pub async fn post(&self, uri: &str, body: Value) -> TestResponse {
+    self
+      .send(
+        Request::builder()
+          .method(Method::POST)
+          .uri(uri)
+          .header(header::CONTENT_TYPE, "application/json")
+          .body(Body::from(body.to_string()))
+          .unwrap(),
+      )
+      .await
+  }
+
+  /// 携带 accessToken 发送请求, 请求体为 JSON
+  pub async fn call(&self, method: Method, uri: &str, access_token: &str, body: Option<Value>) -> TestResponse {
+    self
+      .send(json_request(method, uri, header::AUTHORIZATION.as_str(), &format!("Bearer {}", access_token), body))
+      .await
+  }
+
+  /// 使用静态密钥调用管理 API
+  pub async fn admin(&self, method: Method, uri: &str, body: Option<Value>) -> TestResponse {
+    self.send(json_request(method, &format!("/admin/api{}", uri), "X-Api-Key", ADMIN_KEY, body)).await
+  }
+
+  /// 以 multipart 表单上传材质, auth 为认证用的请求头
+  pub async fn upload(
+    &self,
+    method: Method,
+    uri: &str,
+    auth: (&str, &str),
+    fields: &[(&str, &str)],
+    png: &[u8],
+  ) -> TestResponse {
+    let boundary = "mc-auth-test-boundary";
+    let mut body = vec![];
+    for (name, value) in fields {
+      body.extend_from_slice(
+        format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n", boundary, name, value).as_bytes(),
+      );
+    }
+    body.extend_from_slice(
+      format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"texture.png\"\r\nContent-Type: \
+         image/png\r\n\r\n",
+        boundary
+      )
+      .as_bytes(),
+    );
+    body.extend_from_slice(png);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    self
+      .send(
+        Request::builder()
+          .method(method)
+          .uri(uri)
+          .header(auth.0, auth.1)
+          .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={}", boundary))
+          .body(Body::from(body))
+          .unwrap(),
+      )
+      .await
+  }
+
+  /// 创建一个拥有单个角色的用户, 邮箱与角色名随机生成
+  pub async fn create_user(&self) -> TestUser {
+    let suffix = &utils::gen_uuid()[..10];
+    let email = format!("{}@example.com", suffix);
+    let profile_name = format!("p_{}", suffix);
+    let user = self
+      .repos
+      .users
+      .create(utils::string_to_uuid_vec(utils::gen_uuid()), profile_name.clone(), email.clone(), PASSWORD.to_owned())
+      .await
+      .unwrap();
+    let profile_uuid = utils::gen_uuid();
+    self
+      .repos
+      .profiles
+      .create(utils::string_to_uuid_vec(profile_uuid.clone()), profile_name.clone(), user.id, &NameRules::exact(), None)
+      .await
+      .unwrap();
+    TestUser { email, profile_name, profile_uuid, user_id: user.id }
+  }
+
+  /// 使用角色名登录, 返回绑定了该角色的 accessToken 与 clientToken
+  pub async fn login(&self, user: &TestUser) -> (String, String) {
+    let resp = self
+      .post(
+        "/authserver/authenticate",
+        serde_json::json!({
+          "username": user.profile_name,
+          "password": PASSWORD,
+          "agent": { "name": "Minecraft", "version": 1 },
+        }),
+      )
+      .await;
+    assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+    let body = resp.json();
+    (body["accessToken"].as_str().unwrap().to_owned(), body["clientToken"].as_str().unwrap().to_owned())
+  }
+
+  /// 从 API 元数据中读取签名公钥
+  pub async fn public_key(&self) -> RsaPublicKey {
+    let body = self.get("/").await.json();
+    RsaPublicKey::from_public_key_pem(body["signaturePublickey"].as_str().unwrap()).unwrap()
+  }
+}
+
+fn json_request(method: Method, uri: &str, auth: &str, value: &str, body: Option<Value>) -> Request<Body> {
+  let builder = Request::builder().method(method).uri(uri).header(auth, value);
+  match body {
+    Some(x) => builder.header(header::CONTENT_TYPE, "application/json").body(Body::from(x.to_string())).unwrap(),
+    None => builder.body(Body::empty()).unwrap(),
+  }
+}
+
+/// 为每个测试函数分别生成使用内存存储与 SQLite 存储的测试, 测试函数接收对应的 TestApp
+macro_rules! backend_tests {
+  ($($name:ident),* $(,)?) => {
+    mod memory {
+      $(
+        #[tokio::test]
+        async fn $name() {
+          super::$name($crate::common::TestApp::new()).await
+        }
+      )*
+    }
+
+    mod sqlite {
+      $(
+        #[tokio::test]
+        async fn $name() {
+          super::$name($crate::common::TestApp::sqlite()).await
+        }
+      )*
+    }
+  };
+}
+
+#[allow(unused_imports)]
+pub(crate) use backend_tests;
+
+/// 使用 SHA1withRSA 验证角色属性的签名
+pub fn verify_property(key: &RsaPublicKey, property: &Value) -> bool {
+  let value = property["value"].as_str().unwrap();
+  let signature = match property["signature"].as_str() {
+    Some(x) => x,
+    None => {
+      return false;
+    },
+  };
+  let signature = match utils::base64().decode(signature).ok().and_then(|x| Signature::try_from(x.as_slice()).ok()) {
+    Some(x) => x,
+    None => {
+      return false;
+    },
+  };
+  VerifyingKey::<Sha1>::new(key.clone()).verify(value.as_bytes(), &signature).is_ok()
+}
+
+/// 解码角色的 textures 属性
+pub fn decode_textures(profile: &Value) -> Value {
+  let property = profile["properties"].as_array().unwrap().iter().find(|x| x["name"] == "textures").unwrap();
+  let value = utils::base64().decode(property["value"].as_str().unwrap()).unwrap();
+  serde_json::from_slice(&value).unwrap()
+}
+
+/// 只包含 IHDR 块的 PNG, 服务端只读取文件头中的宽高
+pub fn fake_png(width: u32, height: u32) -> Vec<u8> {
+  let mut data = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0, 0, 0, 13];
+  data.extend_from_slice(b"IHDR");
+  data.extend_from_slice(&width.to_be_bytes());
+  data.extend_from_slice(&height.to_be_bytes());
+  data.extend_from_slice(&[8, 6, 0, 0, 0, 0, 0, 0, 0]);
+  data
+}
@@ -0,0 +1,74 @@
+//! PostgreSQL 迁移
+//! 需要一个可以清空的数据库, 通过 TEST_DATABASE_URL 指定, 使用 `cargo test -- --ignored` 运行
+
+use mc_auth::{
+  db,
+  prisma::{self, PrismaClient},
+};
+use prisma_client_rust::raw;
+
+/// 第一个迁移与改用迁移之前的 schema.prisma 相同, 用它模拟旧版本通过 db push 创建的数据库
+const BASELINE_SQL: &str = include_str!("../prisma/migrations/20261019000000_init/migration.sql");
+
+async fn execute(db: &PrismaClient, sql: &str) {
+  db._execute_raw(raw!(sql)).exec().await.unwrap();
+}
+
+#[tokio::test]
+#[ignore]
+async fn upgrade_pushed_baseline() {
+  let url = std::env::var("TEST_DATABASE_URL").expect("需要设置 TEST_DATABASE_URL");
+  let db = PrismaClient::_builder().with_url(url).build().await.unwrap();
+  execute(&db, "DROP SCHEMA IF EXISTS public CASCADE").await;
+  execute(&db, "CREATE SCHEMA public").await;
+  for statement in BASELINE_SQL.split(';').map(str::trim).filter(|x| !x.is_empty()) {
+    execute(&db, statement).await;
+  }
+  execute(
+    &db,
+    r#"INSERT INTO "User" ("uuid", "nickname", "email", "password") VALUES ('\x01', 'old', 'old@example.com', 'hash')"#,
+  )
+  .await;
+  execute(
+    &db,
+    r#"INSERT INTO "Profile" ("uuid", "ownerID", "displayName") SELECT '\x02', "id", 'old_player' FROM "User""#,
+  )
+  .await;
+
+  // 没有迁移记录的旧数据库: 第一个迁移标记为已应用, 之后的迁移正常应用
+  assert_eq!(db::pending_migrations(&db).await.unwrap(), db::embedded_migrations());
+  db::migrate(&db).await.unwrap();
+  assert!(db::pending_migrations(&db).await.unwrap().is_empty());
+
+  // 旧数据保留, 新增的列使用默认值
+  let user =
+    db.user().find_unique(prisma::user::email::equals("old@example.com".to_owned())).exec().await.unwrap().unwrap();
+  assert!(!user.disabled);
+  assert_eq!(user.role, prisma::Role::Player);
+  let profile = db
+    .profile()
+    .find_unique(prisma::profile::display_name::equals("old_player".to_owned()))
+    .exec()
+    .await
+    .unwrap()
+    .unwrap();
+
+  // 新增的表可以使用, 修改后的外键按新的规则级联
+  db.name_history()
+    .create(
+      "older_name".to_owned(),
+      vec![prisma::name_history::profile::connect(prisma::profile::id::equals(profile.id))],
+    )
+    .exec()
+    .await
+    .unwrap();
+  db.user().delete(prisma::user::id::equals(user.id)).exec().await.unwrap();
+  assert_eq!(db.profile().count(vec![]).exec().await.unwrap(), 0);
+  let history = db.name_history().find_many(vec![]).exec().await.unwrap();
+  assert_eq!(history.len(), 1);
+  assert!(history[0].profile_id.is_none());
+
+  // 已是最新时不会重复应用
+  db::migrate(&db).await.unwrap();
+  assert!(db::pending_migrations(&db).await.unwrap().is_empty());
+}
@@ -0,0 +1,143 @@
+//! 角色的创建、改名与删除, 以及 Minecraft Services 风格的角色接口
+//! 每个测试分别在内存存储与 SQLite 存储上运行
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::{TestApp, TestUser, PASSWORD};
+use mc_auth::utils;
+use serde_json::json;
+
+common::backend_tests! {
+  rename_records_name_history,
+  create_and_delete_profile,
+  services_profile_and_name_change,
+  profile_certificates,
+}
+
+fn new_name() -> String {
+  format!("n_{}", &utils::gen_uuid()[..10])
+}
+
+async fn rename_records_name_history(app: TestApp) {
+  let user = app.create_user().await;
+  let other = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let name = new_name();
+  let uri = format!("/api/user/profiles/{}/name", user.profile_uuid);
+
+  // 不能改为其他角色正在使用的名称, 默认不区分大小写
+  let resp =
+    app.call(Method::PUT, &uri, &access_token, Some(json!({ "name": other.profile_name.to_uppercase() }))).await;
+  resp.assert_error(StatusCode::CONFLICT, "IllegalArgumentException");
+
+  let resp = app.call(Method::PUT, &uri, &access_token, Some(json!({ "name": name }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["name"], name);
+
+  // 冷却时间内不能再次改名, 改名后旧令牌失效, 需要重新登录
+  let (access_token, _) = app.login(&TestUser { profile_name: name.clone(), ..user.clone() }).await;
+  let resp = app.call(Method::PUT, &uri, &access_token, Some(json!({ "name": new_name() }))).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+
+  let resp = app.get(&format!("/api/profile/{}/names", user.profile_uuid)).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  let history = resp.json();
+  let history = history.as_array().unwrap();
+  assert_eq!(history.len(), 2);
+  assert_eq!(history[0]["name"], user.profile_name);
+  assert!(history[0]["changedToAt"].is_null());
+  assert_eq!(history[1]["name"], name);
+  assert!(history[1]["changedToAt"].is_i64());
+
+  let resp = app.get(&format!("/users/profiles/minecraft/{}", name)).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.json()["id"], user.profile_uuid);
+  let resp = app.get(&format!("/users/profiles/minecraft/{}", user.profile_name)).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let resp = app.get(&format!("/api/user/profile/{}", user.profile_uuid)).await;
+  assert_eq!(resp.json()["name"], name);
+}
+
+async fn create_and_delete_profile(app: TestApp) {
+  let user = app.create_user().await;
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.email, "password": PASSWORD })).await;
+  let access_token = resp.json()["accessToken"].as_str().unwrap().to_owned();
+
+  // 普通玩家默认只能拥有一个角色
+  let resp = app.call(Method::POST, "/api/user/profiles", &access_token, Some(json!({ "name": new_name() }))).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+
+  let uri = format!("/api/user/profiles/{}", user.profile_uuid);
+  let resp = app.call(Method::DELETE, &uri, &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT, "{:?}", resp.body);
+  let resp = app.get(&format!("/api/user/profile/{}", user.profile_uuid)).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+  // 删除角色释放的名称在保留期内不能被使用
+  let body = json!({ "name": user.profile_name });
+  let resp = app.call(Method::POST, "/api/user/profiles", &access_token, Some(body)).await;
+  resp.assert_error(StatusCode::CONFLICT, "IllegalArgumentException");
+
+  let name = new_name();
+  let resp = app.call(Method::POST, "/api/user/profiles", &access_token, Some(json!({ "name": name }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let profile = resp.json();
+  assert_eq!(profile["name"], name);
+  let resp = app.post("/api/profiles/minecraft", json!([name])).await;
+  assert_eq!(resp.json()[0]["id"], profile["id"]);
+
+  // 两次删除之间需要等待冷却时间
+  let uri = format!("/api/user/profiles/{}", profile["id"].as_str().unwrap());
+  let resp = app.call(Method::DELETE, &uri, &access_token, None).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+}
+
+async fn services_profile_and_name_change(app: TestApp) {
+  let user = app.create_user().await;
+  let other = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+
+  let resp = app.call(Method::GET, "/minecraftservices/minecraft/profile", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  assert_eq!(body["id"], user.profile_uuid);
+  assert_eq!(body["skins"], json!([]));
+
+  let resp = app.call(Method::GET, "/minecraftservices/minecraft/profile/namechange", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.json()["nameChangeAllowed"], true);
+
+  let available = |name: &str| format!("/minecraftservices/minecraft/profile/name/{}/available", name);
+  let resp = app.call(Method::GET, &available(&other.profile_name.to_uppercase()), &access_token, None).await;
+  assert_eq!(resp.json()["status"], "DUPLICATE");
+  let resp = app.call(Method::GET, &available("x"), &access_token, None).await;
+  assert_eq!(resp.json()["status"], "NOT_ALLOWED");
+  let name = new_name();
+  let resp = app.call(Method::GET, &available(&name), &access_token, None).await;
+  assert_eq!(resp.json()["status"], "AVAILABLE");
+
+  let resp =
+    app.call(Method::PUT, &format!("/minecraftservices/minecraft/profile/name/{}", name), &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["name"], name);
+  let resp = app.get(&format!("/api/user/profile/{}", user.profile_uuid)).await;
+  assert_eq!(resp.json()["name"], name);
+}
+
+async fn profile_certificates(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let resp = app.call(Method::POST, "/minecraftservices/player/certificates", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let first = resp.json();
+  assert!(first["keyPair"]["privateKey"].as_str().unwrap().contains("PRIVATE KEY"));
+
+  // 未过期前返回同一个密钥对
+  let resp = app.call(Method::POST, "/minecraftservices/player/certificates", &access_token, None).await;
+  assert_eq!(resp.json()["keyPair"], first["keyPair"]);
+
+  let resp = app.get("/minecraftservices/publickeys").await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert!(resp.json()["profilePropertyKeys"].is_array());
+}
@@ -0,0 +1,218 @@
+//! SQLite 存储特有的行为, 以及在 SQLite 存储上运行管理工具
+//! 各个接口在 SQLite 存储上的测试见 backend_tests! 生成的 sqlite 模块
+
+mod common;
+
+use std::{
+  path::Path,
+  process::{Command, Output},
+};
+
+use axum::http::StatusCode;
+use common::{fake_png, TestApp, PASSWORD};
+use mc_auth::{
+  repo::{sqlite, NameRules, RepoError, Repos},
+  utils,
+};
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn display_name_is_unique() {
+  let app = TestApp::sqlite();
+  let user = app.create_user().await;
+  let err = app
+    .repos
+    .profiles
+    .create(
+      utils::string_to_uuid_vec(utils::gen_uuid()),
+      user.profile_name.clone(),
+      user.user_id,
+      &NameRules::exact(),
+      None,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(err, RepoError::Conflict("displayName")), "{:?}", err);
+}
+
+#[tokio::test]
+async fn case_insensitive_names() {
+  let app = TestApp::sqlite();
+  let user = app.create_user().await;
+  let name = user.profile_name.to_uppercase();
+  let rules = NameRules { case_insensitive: true, reserved_since: None };
+  let uuid = || utils::string_to_uuid_vec(utils::gen_uuid());
+  let err = app.repos.profiles.create(uuid(), name.clone(), user.user_id, &rules, None).await.unwrap_err();
+  assert!(matches!(err, RepoError::Conflict("displayName")), "{:?}", err);
+  assert!(app.repos.profiles.find_by_name(&name, true).await.unwrap().is_some());
+  assert!(app.repos.profiles.find_by_name(&name, false).await.unwrap().is_none());
+
+  // 区分大小写时可以使用
+  let profile = app.repos.profiles.create(uuid(), name.clone(), user.user_id, &NameRules::exact(), None).await.unwrap();
+  assert_eq!(profile.display_name, name);
+}
+
+#[tokio::test]
+async fn data_survives_reopen() {
+  let path = std::env::temp_dir().join(format!("mc-auth-test-{}.db", utils::gen_uuid()));
+  let path = path.to_str().unwrap().to_owned();
+  let user = TestApp::with_repos(Repos::sqlite(&path).unwrap()).create_user().await;
+
+  let app = TestApp::with_repos(Repos::sqlite(&path).unwrap());
+  let resp = app
+    .post(
+      "/authserver/authenticate",
+      json!({ "username": user.email, "password": PASSWORD, "agent": { "name": "Minecraft", "version": 1 } }),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  assert_eq!(resp.json()["availableProfiles"][0]["name"], user.profile_name);
+  drop(app);
+  std::fs::remove_file(&path).ok();
+}
+
+#[tokio::test]
+async fn versioned_migrations() {
+  let path = std::env::temp_dir().join(format!("mc-auth-test-{}.db", utils::gen_uuid()));
+  let path = path.to_str().unwrap().to_owned();
+  let set_version = |version: i64| {
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.pragma_update(None, "user_version", version).unwrap();
+  };
+  // 检查不会创建文件
+  assert_eq!(sqlite::pending_migrations(&path).unwrap(), vec!["1_init"]);
+  assert!(!Path::new(&path).exists());
+  let user = TestApp::with_repos(Repos::sqlite(&path).unwrap()).create_user().await;
+  assert!(sqlite::pending_migrations(&path).unwrap().is_empty());
+
+  // 引入版本号之前创建的数据库可以直接升级, 数据保留
+  set_version(0);
+  assert_eq!(sqlite::pending_migrations(&path).unwrap(), vec!["1_init"]);
+  let repos = Repos::sqlite(&path).unwrap();
+  assert!(repos.users.find_by_email(&user.email).await.unwrap().is_some());
+  assert!(sqlite::pending_migrations(&path).unwrap().is_empty());
+  drop(repos);
+
+  // 不打开由更新的版本创建的数据库
+  set_version(99);
+  assert!(matches!(sqlite::pending_migrations(&path), Err(RepoError::UnknownVersion(99))));
+  assert!(matches!(Repos::sqlite(&path), Err(RepoError::UnknownVersion(99))));
+  std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn migration_flags() {
+  let dir = std::env::temp_dir().join(format!("mc-auth-test-{}", utils::gen_uuid()));
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(
+    dir.join("Settings.toml"),
+    "[token]\n[signature]\n[webserver]\n[textures]\n[database]\nbackend = \"sqlite\"\n",
+  )
+  .unwrap();
+  let server = |flag: &str| Command::new(env!("CARGO_BIN_EXE_mc-auth")).arg(flag).current_dir(&dir).output().unwrap();
+  assert!(!server("--check-migrations").status.success());
+  assert!(server("--migrate-only").status.success());
+  assert!(server("--check-migrations").status.success());
+  std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn blocked_servers() {
+  let app = TestApp::sqlite();
+  app.repos.blocked_servers.create("blocked-hash".to_owned(), None, None).await.unwrap();
+  let resp = app.get("/blockedservers").await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.body, "blocked-hash");
+}
+
+/// 以 JSON 输出运行管理工具
+/// 全局参数放在子命令之后, 同时检查它们在任意位置都能使用
+fn admin_command(config: &Path, args: &[&str]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_mc-auth-admin"))
+    .args(args)
+    .arg("--config")
+    .arg(config)
+    .arg("--json")
+    .output()
+    .unwrap()
+}
+
+/// 运行管理工具并要求成功, 返回解析后的输出
+fn run_admin(config: &Path, args: &[&str]) -> Value {
+  let output = admin_command(config, args);
+  assert!(output.status.success(), "{:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+  serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn admin_cli() {
+  let dir = std::env::temp_dir().join(format!("mc-auth-test-{}", utils::gen_uuid()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let config = dir.join("Settings.toml");
+  let path = |name: &str| dir.join(name).display().to_string().replace('\\', "/");
+  std::fs::write(
+    &config,
+    format!(
+      "[token]\n[signature]\n[webserver]\n[textures]\ndir = \"{}\"\n\
+       [database]\nbackend = \"sqlite\"\nsqlite-path = \"{}\"\n",
+      path("textures"),
+      path("mc-auth.db"),
+    ),
+  )
+  .unwrap();
+  std::fs::write(dir.join("skin.png"), fake_png(64, 64)).unwrap();
+  std::fs::write(dir.join("cape.png"), fake_png(64, 32)).unwrap();
+
+  let user = run_admin(&config, &["user", "create", "cli@example.com", "cli", PASSWORD]);
+  assert_eq!(user["email"], "cli@example.com");
+  assert_eq!(run_admin(&config, &["user", "list"]).as_array().unwrap().len(), 1);
+  assert_eq!(run_admin(&config, &["user", "disable", "cli@example.com"])["ok"], true);
+  assert_eq!(run_admin(&config, &["user", "list"])[0]["disabled"], true);
+  assert_eq!(run_admin(&config, &["user", "enable", "cli@example.com"])["affected"], 1);
+  assert_eq!(run_admin(&config, &["user", "enable", "cli@example.com"])["affected"], 0);
+  run_admin(&config, &["user", "reset-password", "cli@example.com", "another password"]);
+
+  // 角色名需要符合配置中的规则, 指定 --force 时跳过检查
+  assert!(!admin_command(&config, &["profile", "create", "cli@example.com", "x"]).status.success());
+  let forced = run_admin(&config, &["profile", "create", "cli@example.com", "x", "--force"]);
+  assert_eq!(forced["name"], "x");
+  run_admin(&config, &["profile", "delete", "x"]);
+  let profile = run_admin(&config, &["profile", "create", "cli@example.com", "cli_player"]);
+  assert_eq!(profile["ownerId"], user["id"]);
+  let profiles = run_admin(&config, &["profile", "list", "--email", "cli@example.com"]);
+  assert_eq!(profiles[0]["name"], "cli_player");
+  assert!(!admin_command(&config, &["profile", "rename", "cli_player", "bad name"]).status.success());
+  let renamed = run_admin(&config, &["profile", "rename", "cli_player", "cli_renamed"]);
+  assert_eq!(renamed["name"], "cli_renamed");
+  // 与玩家改名相同, 旧名称记入改名记录
+  let repos = Repos::sqlite(&path("mc-auth.db")).unwrap();
+  let history =
+    tokio::runtime::Runtime::new().unwrap().block_on(repos.profiles.name_history(renamed["id"].as_i64().unwrap()));
+  assert_eq!(history.unwrap()[0].name, "cli_player");
+  drop(repos);
+  run_admin(&config, &["profile", "set-texture", "cli_renamed", "skin", &path("skin.png"), "--slim"]);
+  assert!(run_admin(&config, &["profile", "list"])[0]["skinId"].is_i64());
+  assert_eq!(run_admin(&config, &["profile", "clear-texture", "cli_renamed", "skin"])["affected"], 1);
+  assert!(run_admin(&config, &["profile", "list"])[0]["skinId"].is_null());
+  assert_eq!(run_admin(&config, &["profile", "clear-texture", "cli_renamed", "skin"])["affected"], 0);
+
+  let cape = run_admin(&config, &["cape", "create", "Founder", &path("cape.png")]);
+  let cape_id = cape["id"].to_string();
+  assert_eq!(run_admin(&config, &["cape", "list"])[0]["name"], "Founder");
+  let grant = ["cape", "grant", "cli@example.com", cape_id.as_str(), "--reason", "early supporter"];
+  assert_eq!(run_admin(&config, &grant)["affected"], 1);
+  assert_eq!(run_admin(&config, &grant)["affected"], 0);
+  assert_eq!(run_admin(&config, &["cape", "revoke", "cli@example.com", &cape_id])["affected"], 1);
+  assert!(!admin_command(&config, &["cape", "revoke", "cli@example.com", &cape_id]).status.success());
+  // 只能授予官方披风
+  let output = admin_command(&config, &["cape", "grant", "cli@example.com", "9999"]);
+  assert!(!output.status.success());
+
+  assert_eq!(run_admin(&config, &["token", "list", "cli@example.com", "--all"]), json!([]));
+  assert_eq!(run_admin(&config, &["token", "revoke", "unknown-token"])["affected"], 0);
+  run_admin(&config, &["token", "revoke-all", "cli@example.com"]);
+
+  run_admin(&config, &["profile", "delete", "cli_renamed"]);
+  assert_eq!(run_admin(&config, &["profile", "list"]), json!([]));
+  std::fs::remove_dir_all(&dir).ok();
+}
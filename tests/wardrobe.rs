@@ -0,0 +1,144 @@
+//! 材质库、公开材质库与官方披风
+//! 每个测试分别在内存存储与 SQLite 存储上运行
+
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use common::{decode_textures, fake_png, TestApp, ADMIN_KEY};
+use serde_json::json;
+
+common::backend_tests! {
+  library_and_gallery,
+  entitled_capes,
+}
+
+async fn library_and_gallery(app: TestApp) {
+  let user = app.create_user().await;
+  let other = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let (other_token, _) = app.login(&other).await;
+  let bearer = format!("Bearer {}", access_token);
+  let skin_uri = format!("/api/user/profile/{}/skin", user.profile_uuid);
+  // 重复上传同一材质只在材质库中保留一条记录
+  for _ in 0..2 {
+    let resp = app
+      .upload(
+        Method::PUT,
+        &skin_uri,
+        (header::AUTHORIZATION.as_str(), bearer.as_str()),
+        &[("model", "slim")],
+        &fake_png(64, 64),
+      )
+      .await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT, "{:?}", resp.body);
+  }
+
+  let resp = app.call(Method::GET, "/api/user/textures", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let textures = resp.json()["textures"].as_array().unwrap().clone();
+  assert_eq!(textures.len(), 1);
+  assert_eq!(textures[0]["type"], "skin");
+  assert_eq!(textures[0]["model"], "slim");
+  let texture_id = textures[0]["id"].as_i64().unwrap();
+
+  let uri = format!("/api/user/textures/{}", texture_id);
+  let resp = app.call(Method::PATCH, &uri, &access_token, Some(json!({ "name": "Red Hoodie" }))).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  // 其他用户看不到这个材质
+  let resp = app.call(Method::PATCH, &uri, &other_token, Some(json!({ "name": "Mine" }))).await;
+  assert_eq!(resp.status, StatusCode::NOT_FOUND);
+  let resp = app.call(Method::GET, "/api/user/textures", &access_token, None).await;
+  assert_eq!(resp.json()["textures"][0]["name"], "Red Hoodie");
+
+  let body = json!({ "textureId": texture_id, "title": "Red Hoodie", "tags": ["Red", "hoodie"] });
+  let resp = app.call(Method::POST, "/api/gallery", &access_token, Some(body)).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let item_id = resp.json()["id"].as_i64().unwrap();
+
+  let resp = app.get("/api/gallery?tag=red&q=hoodie").await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.json()["total"], 1);
+  assert_eq!(resp.json()["items"][0]["id"], item_id);
+  let resp = app.get("/api/gallery?type=cape").await;
+  assert_eq!(resp.json()["total"], 0);
+
+  // 重复点赞只计一次
+  let like_uri = format!("/api/gallery/{}/like", item_id);
+  for _ in 0..2 {
+    let resp = app.call(Method::PUT, &like_uri, &other_token, None).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  }
+  assert_eq!(app.get(&format!("/api/gallery/{}", item_id)).await.json()["likes"], 1);
+  let resp = app.call(Method::DELETE, &like_uri, &other_token, None).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.get(&format!("/api/gallery/{}", item_id)).await.json()["likes"], 0);
+
+  // 不允许上传材质的角色也不能从公开材质库中换上皮肤
+  let resp = app.admin(Method::GET, &format!("/profiles?ownerId={}", other.user_id), None).await;
+  let profile_uri = format!("/profiles/{}", resp.json()["items"][0]["id"]);
+  let resp = app.admin(Method::PATCH, &profile_uri, Some(json!({ "uploadableTextures": "None" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let uri = format!("/api/gallery/{}/apply/{}", item_id, other.profile_uuid);
+  let resp = app.call(Method::PUT, &uri, &other_token, None).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app.admin(Method::PATCH, &profile_uri, Some(json!({ "uploadableTextures": "SkinOnly" }))).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let resp = app.call(Method::PUT, &uri, &other_token, None).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let profile = app.get(&format!("/sessionserver/session/minecraft/profile/{}", other.profile_uuid)).await.json();
+  assert_eq!(decode_textures(&profile)["textures"]["SKIN"]["metadata"]["model"], "slim");
+
+  // 只有上传者可以取消发布
+  let uri = format!("/api/gallery/{}", item_id);
+  assert_eq!(app.call(Method::DELETE, &uri, &other_token, None).await.status, StatusCode::NOT_FOUND);
+  assert_eq!(app.call(Method::DELETE, &uri, &access_token, None).await.status, StatusCode::NO_CONTENT);
+  assert_eq!(app.get(&uri).await.status, StatusCode::NOT_FOUND);
+
+  // 清除皮肤后从材质库中换回
+  assert_eq!(app.call(Method::DELETE, &skin_uri, &access_token, None).await.status, StatusCode::NO_CONTENT);
+  let uri = format!("/api/user/wardrobe/{}", user.profile_uuid);
+  let resp = app.call(Method::PUT, &uri, &access_token, Some(json!({ "textureId": texture_id }))).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let profile = app.get(&format!("/sessionserver/session/minecraft/profile/{}", user.profile_uuid)).await.json();
+  assert!(decode_textures(&profile)["textures"].get("SKIN").is_some());
+
+  let uri = format!("/api/user/textures/{}", texture_id);
+  assert_eq!(app.call(Method::DELETE, &uri, &access_token, None).await.status, StatusCode::NO_CONTENT);
+  let resp = app.call(Method::GET, "/api/user/textures", &access_token, None).await;
+  assert_eq!(resp.json()["textures"], json!([]));
+}
+
+async fn entitled_capes(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let resp = app
+    .upload(Method::POST, "/admin/api/capes", ("X-Api-Key", ADMIN_KEY), &[("name", "Founder")], &fake_png(64, 32))
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let cape = resp.json();
+  assert_eq!(cape["official"], true);
+  let cape_id = cape["id"].as_i64().unwrap();
+
+  // 未获得授权时不能使用
+  let apply_uri = format!("/api/user/capes/{}/apply/{}", cape_id, user.profile_uuid);
+  assert_eq!(app.call(Method::PUT, &apply_uri, &access_token, None).await.status, StatusCode::NOT_FOUND);
+
+  let body = json!({ "userId": user.user_id, "reason": "early supporter" });
+  let resp = app.admin(Method::POST, &format!("/capes/{}/grants", cape_id), Some(body)).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT, "{:?}", resp.body);
+  let resp = app.call(Method::GET, "/api/user/capes", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.json()["capes"][0]["name"], "Founder");
+  assert_eq!(resp.json()["capes"][0]["reason"], "early supporter");
+
+  assert_eq!(app.call(Method::PUT, &apply_uri, &access_token, None).await.status, StatusCode::NO_CONTENT);
+  let resp = app.call(Method::GET, "/minecraftservices/minecraft/profile", &access_token, None).await;
+  assert_eq!(resp.json()["capes"][0]["state"], "ACTIVE");
+  assert_eq!(resp.json()["capes"][0]["alias"], "Founder");
+
+  // 撤销授权后同时取下披风
+  let resp = app.admin(Method::DELETE, &format!("/capes/{}/grants/{}", cape_id, user.user_id), None).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let resp = app.call(Method::GET, "/minecraftservices/minecraft/profile", &access_token, None).await;
+  assert_eq!(resp.json()["capes"], json!([]));
+}
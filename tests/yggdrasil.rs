@@ -0,0 +1,370 @@
+//! 按 authlib-injector 的 Yggdrasil 服务端技术规范检查各个接口的状态码与响应
+//! 每个测试分别在内存存储与 SQLite 存储上运行
+
+mod common;
+
+use axum::{
+  body::Body,
+  http::{header, Method, Request, StatusCode},
+};
+use common::{decode_textures, fake_png, verify_property, TestApp, PASSWORD};
+use serde_json::json;
+
+common::backend_tests! {
+  meta_exposes_public_key,
+  authenticate_with_email,
+  authenticate_with_profile_name_selects_profile,
+  authenticate_rejects_wrong_password,
+  refresh_issues_new_token,
+  refresh_selects_profile,
+  refresh_rejects_unknown_token,
+  validate_checks_client_token,
+  invalidate_revokes_token,
+  signout_revokes_all_tokens,
+  join_and_has_joined,
+  join_rejects_other_profile,
+  join_rejects_expired_token,
+  session_profile_signatures,
+  batch_lookup,
+  upload_and_clear_skin,
+}
+
+async fn meta_exposes_public_key(app: TestApp) {
+  let resp = app.get("/").await;
+  assert_eq!(resp.status, StatusCode::OK);
+  let body = resp.json();
+  assert!(body["meta"]["serverName"].is_string());
+  // 角色名登录默认关闭, 测试配置中开启
+  assert_eq!(body["meta"]["feature.non_email_login"], true);
+  assert!(!mc_auth::settings::Features::default().non_email_login);
+  assert!(body["skinDomains"].is_array());
+  assert!(body["signaturePublickey"].as_str().unwrap().starts_with("-----BEGIN PUBLIC KEY-----"));
+}
+
+async fn authenticate_with_email(app: TestApp) {
+  let user = app.create_user().await;
+  let resp = app
+    .post(
+      "/authserver/authenticate",
+      json!({ "username": user.email, "password": PASSWORD, "clientToken": "my-client", "requestUser": true }),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  assert_eq!(body["clientToken"], "my-client");
+  assert!(!body["accessToken"].as_str().unwrap().is_empty());
+  let profiles = body["availableProfiles"].as_array().unwrap();
+  assert_eq!(profiles.len(), 1);
+  assert_eq!(profiles[0]["id"], user.profile_uuid);
+  assert_eq!(profiles[0]["name"], user.profile_name);
+  assert!(body["user"]["id"].is_string());
+}
+
+async fn authenticate_with_profile_name_selects_profile(app: TestApp) {
+  let user = app.create_user().await;
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.profile_name, "password": PASSWORD })).await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  assert_eq!(body["selectedProfile"]["id"], user.profile_uuid);
+  // 未请求用户信息时不返回 user
+  assert!(body["user"].is_null());
+}
+
+async fn authenticate_rejects_wrong_password(app: TestApp) {
+  let user = app.create_user().await;
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.email, "password": "wrong" })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+}
+
+async fn refresh_issues_new_token(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, client_token) = app.login(&user).await;
+  let resp = app
+    .post(
+      "/authserver/refresh",
+      json!({ "accessToken": access_token, "clientToken": client_token, "requestUser": true }),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  let new_token = body["accessToken"].as_str().unwrap().to_owned();
+  assert_ne!(new_token, access_token);
+  assert_eq!(body["clientToken"], client_token);
+  assert_eq!(body["selectedProfile"]["id"], user.profile_uuid);
+  assert!(body["user"]["id"].is_string());
+
+  // 旧令牌失效, 新令牌可用
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app.post("/authserver/validate", json!({ "accessToken": new_token })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+async fn refresh_selects_profile(app: TestApp) {
+  let user = app.create_user().await;
+  let resp = app.post("/authserver/authenticate", json!({ "username": user.email, "password": PASSWORD })).await;
+  let body = resp.json();
+  let access_token = body["accessToken"].as_str().unwrap();
+  let resp = app
+    .post(
+      "/authserver/refresh",
+      json!({
+        "accessToken": access_token,
+        "selectedProfile": { "id": user.profile_uuid, "name": user.profile_name },
+      }),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  assert_eq!(body["selectedProfile"]["id"], user.profile_uuid);
+
+  // 已绑定角色的令牌不能再次选择角色
+  let resp = app
+    .post(
+      "/authserver/refresh",
+      json!({
+        "accessToken": body["accessToken"],
+        "selectedProfile": { "id": user.profile_uuid, "name": user.profile_name },
+      }),
+    )
+    .await;
+  resp.assert_error(StatusCode::BAD_REQUEST, "IllegalArgumentException");
+}
+
+async fn refresh_rejects_unknown_token(app: TestApp) {
+  let resp = app.post("/authserver/refresh", json!({ "accessToken": "not-a-token" })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+}
+
+async fn validate_checks_client_token(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, client_token) = app.login(&user).await;
+  let resp =
+    app.post("/authserver/validate", json!({ "accessToken": access_token, "clientToken": client_token })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  assert!(resp.body.is_empty());
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token, "clientToken": "other" })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+}
+
+async fn invalidate_revokes_token(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let resp = app.post("/authserver/invalidate", json!({ "accessToken": access_token })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let resp = app.post("/authserver/validate", json!({ "accessToken": access_token })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  // 令牌不存在时同样成功
+  let resp = app.post("/authserver/invalidate", json!({ "accessToken": access_token })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+async fn signout_revokes_all_tokens(app: TestApp) {
+  let user = app.create_user().await;
+  let (first, _) = app.login(&user).await;
+  let (second, _) = app.login(&user).await;
+  let resp = app.post("/authserver/signout", json!({ "username": user.email, "password": "wrong" })).await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app.post("/authserver/signout", json!({ "username": user.email, "password": PASSWORD })).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  for token in [first, second] {
+    let resp = app.post("/authserver/validate", json!({ "accessToken": token })).await;
+    resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  }
+}
+
+async fn join_and_has_joined(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let server_id = format!("server-{}", user.profile_uuid);
+  let resp = app
+    .post(
+      "/sessionserver/session/minecraft/join",
+      json!({ "accessToken": access_token, "selectedProfile": user.profile_uuid, "serverId": server_id }),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT, "{:?}", resp.body);
+
+  let resp = app
+    .get(&format!(
+      "/sessionserver/session/minecraft/hasJoined?username={}&serverId={}&ip=127.0.0.1",
+      user.profile_name, server_id
+    ))
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let body = resp.json();
+  assert_eq!(body["id"], user.profile_uuid);
+  assert_eq!(body["name"], user.profile_name);
+  // hasJoined 返回的属性必须带有签名
+  let key = app.public_key().await;
+  let properties = body["properties"].as_array().unwrap();
+  assert!(properties.iter().any(|x| x["name"] == "textures"));
+  for property in properties {
+    assert!(verify_property(&key, property), "签名无效: {}", property);
+  }
+
+  // 角色名或 IP 不匹配时视为未加入
+  let resp =
+    app.get(&format!("/sessionserver/session/minecraft/hasJoined?username=someone_else&serverId={}", server_id)).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let resp = app
+    .get(&format!(
+      "/sessionserver/session/minecraft/hasJoined?username={}&serverId={}&ip=10.0.0.1",
+      user.profile_name, server_id
+    ))
+    .await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+async fn join_rejects_other_profile(app: TestApp) {
+  let user = app.create_user().await;
+  let other = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let resp = app
+    .post(
+      "/sessionserver/session/minecraft/join",
+      json!({ "accessToken": access_token, "selectedProfile": other.profile_uuid, "serverId": "whatever" }),
+    )
+    .await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app
+    .post(
+      "/sessionserver/session/minecraft/join",
+      json!({ "accessToken": "not-a-token", "selectedProfile": user.profile_uuid, "serverId": "whatever" }),
+    )
+    .await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+}
+
+async fn join_rejects_expired_token(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  // 刷新期限为负数时, 已签发的令牌都早于期限, 加入服务器前应先检查出令牌已暂时失效
+  let resp = app
+    .admin(Method::PUT, &format!("/settings/{}", user.user_id), Some(json!({ "tokenNeedRefreshDuration": -1 })))
+    .await;
+  assert_eq!(resp.status, StatusCode::OK, "{:?}", resp.body);
+  let resp = app
+    .post(
+      "/sessionserver/session/minecraft/join",
+      json!({ "accessToken": access_token, "selectedProfile": user.profile_uuid, "serverId": "whatever" }),
+    )
+    .await;
+  resp.assert_error(StatusCode::FORBIDDEN, "ForbiddenOperationException");
+  let resp = app.call(Method::GET, "/api/user/textures", &access_token, None).await;
+  assert_eq!(resp.status, StatusCode::UNAUTHORIZED, "{:?}", resp.body);
+}
+
+async fn session_profile_signatures(app: TestApp) {
+  let user = app.create_user().await;
+  let key = app.public_key().await;
+
+  // 默认不返回签名
+  let resp = app.get(&format!("/sessionserver/session/minecraft/profile/{}", user.profile_uuid)).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  let body = resp.json();
+  assert_eq!(body["name"], user.profile_name);
+  for property in body["properties"].as_array().unwrap() {
+    assert!(property.get("signature").map_or(true, |x| x.is_null()));
+  }
+  let textures = decode_textures(&body);
+  assert_eq!(textures["profileId"], user.profile_uuid);
+  assert_eq!(textures["profileName"], user.profile_name);
+  assert!(textures["timestamp"].is_u64());
+
+  let resp = app.get(&format!("/sessionserver/session/minecraft/profile/{}?unsigned=false", user.profile_uuid)).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  for property in resp.json()["properties"].as_array().unwrap() {
+    assert!(verify_property(&key, property), "签名无效: {}", property);
+  }
+
+  let resp = app.get(&format!("/sessionserver/session/minecraft/profile/{}", mc_auth::utils::gen_uuid())).await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+async fn batch_lookup(app: TestApp) {
+  let user = app.create_user().await;
+  let other = app.create_user().await;
+  let resp = app
+    .post("/api/profiles/minecraft", json!([user.profile_name, other.profile_name.to_uppercase(), "nobody_here"]))
+    .await;
+  assert_eq!(resp.status, StatusCode::OK);
+  let mut found: Vec<String> =
+    resp.json().as_array().unwrap().iter().map(|x| x["id"].as_str().unwrap().to_owned()).collect();
+  found.sort();
+  let mut expected = vec![user.profile_uuid.clone(), other.profile_uuid.clone()];
+  expected.sort();
+  assert_eq!(found, expected);
+
+  // 超过单次查询上限
+  let names: Vec<String> = (0..=mc_auth::models::profile::BATCH_LOOKUP_LIMIT).map(|x| format!("name_{}", x)).collect();
+  let resp = app.post("/api/profiles/minecraft", json!(names)).await;
+  resp.assert_error(StatusCode::BAD_REQUEST, "IllegalArgumentException");
+}
+
+async fn upload_and_clear_skin(app: TestApp) {
+  let user = app.create_user().await;
+  let (access_token, _) = app.login(&user).await;
+  let png = fake_png(64, 64);
+
+  let boundary = "mc-auth-test-boundary";
+  let mut body = format!(
+    "--{b}\r\nContent-Disposition: form-data; name=\"model\"\r\n\r\nslim\r\n--{b}\r\nContent-Disposition: form-data; \
+     name=\"file\"; filename=\"skin.png\"\r\nContent-Type: image/png\r\n\r\n",
+    b = boundary
+  )
+  .into_bytes();
+  body.extend_from_slice(&png);
+  body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+  let uri = format!("/api/user/profile/{}/skin", user.profile_uuid);
+
+  // 未认证时拒绝上传
+  let resp = app
+    .send(
+      Request::builder()
+        .method(Method::PUT)
+        .uri(&uri)
+        .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={}", boundary))
+        .body(Body::from(body.clone()))
+        .unwrap(),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::UNAUTHORIZED);
+
+  let resp = app
+    .send(
+      Request::builder()
+        .method(Method::PUT)
+        .uri(&uri)
+        .header(header::AUTHORIZATION, format!("Bearer {}", access_token))
+        .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={}", boundary))
+        .body(Body::from(body))
+        .unwrap(),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT, "{:?}", resp.body);
+
+  let profile = app.get(&format!("/sessionserver/session/minecraft/profile/{}", user.profile_uuid)).await.json();
+  let textures = decode_textures(&profile);
+  let skin = &textures["textures"]["SKIN"];
+  assert_eq!(skin["metadata"]["model"], "slim");
+  let url = skin["url"].as_str().unwrap();
+  let hash = url.strip_prefix(&app.settings.textures.base).unwrap();
+  let resp = app.get(&format!("/textures/{}", hash)).await;
+  assert_eq!(resp.status, StatusCode::OK);
+  assert_eq!(resp.body.as_ref(), png.as_slice());
+
+  let resp = app
+    .send(
+      Request::builder()
+        .method(Method::DELETE)
+        .uri(&uri)
+        .header(header::AUTHORIZATION, format!("Bearer {}", access_token))
+        .body(Body::empty())
+        .unwrap(),
+    )
+    .await;
+  assert_eq!(resp.status, StatusCode::NO_CONTENT);
+  let profile = app.get(&format!("/sessionserver/session/minecraft/profile/{}", user.profile_uuid)).await.json();
+  assert!(decode_textures(&profile)["textures"].get("SKIN").is_none());
+}